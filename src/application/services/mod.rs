@@ -1 +1,2 @@
+pub mod report_differ;
 pub mod report_generator;
@@ -0,0 +1,118 @@
+use crate::domain::repositories::report_repository::ReportRepository;
+use crate::domain::value_objects::report_diff::{DiffSide, ReportDiff};
+use anyhow::Result;
+use std::path::Path;
+
+/// Service for comparing two previously generated reports
+pub struct ReportDiffer<R>
+where
+    R: ReportRepository,
+{
+    report_repository: R,
+}
+
+impl<R> ReportDiffer<R>
+where
+    R: ReportRepository,
+{
+    /// Creates a new ReportDiffer instance
+    pub fn new(report_repository: R) -> Self {
+        Self { report_repository }
+    }
+
+    /// Loads both reports and compares the right one against the left one
+    pub fn diff(&self, left_path: &Path, right_path: &Path) -> Result<ReportDiff> {
+        let left = self.report_repository.load(left_path)?;
+        let right = self.report_repository.load(right_path)?;
+        Ok(ReportDiff::between(
+            DiffSide::new(Self::source(left_path), &left),
+            &left,
+            DiffSide::new(Self::source(right_path), &right),
+            &right,
+        ))
+    }
+
+    /// Names a report by its file name, which tells apart people, years and departments
+    fn source(path: &Path) -> String {
+        path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::entities::report::Report;
+    use crate::domain::value_objects::commit_theme::CommitTheme;
+    use anyhow::anyhow;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+
+    struct MockReportRepository {
+        reports: HashMap<String, Report>,
+    }
+
+    impl ReportRepository for MockReportRepository {
+        fn load(&self, path: &Path) -> Result<Report> {
+            self.reports
+                .get(path.to_string_lossy().as_ref())
+                .cloned()
+                .ok_or_else(|| anyhow!("Failed to read report file: {}", path.display()))
+        }
+    }
+
+    fn report(year: u32, commits: u32, themes: Vec<(CommitTheme, u32)>) -> Report {
+        Report::new(
+            year,
+            "Personal".to_string(),
+            NaiveDate::from_ymd_opt(year as i32, 4, 1).unwrap(),
+            NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).unwrap(),
+            GitHubActivity::new(commits, 4, 1, 2),
+            vec![],
+            themes.into_iter().collect(),
+        )
+    }
+
+    #[test]
+    fn diffs_two_loaded_reports() {
+        let differ = ReportDiffer::new(MockReportRepository {
+            reports: HashMap::from([
+                (
+                    "reports/alice-2023.json".to_string(),
+                    report(2023, 50, vec![(CommitTheme::Feat, 5)]),
+                ),
+                (
+                    "reports/alice-2024.json".to_string(),
+                    report(2024, 75, vec![(CommitTheme::Feat, 8)]),
+                ),
+            ]),
+        });
+
+        let diff = differ
+            .diff(
+                Path::new("reports/alice-2023.json"),
+                Path::new("reports/alice-2024.json"),
+            )
+            .expect("Failed to diff reports");
+
+        assert_eq!(diff.left().source(), "alice-2023.json");
+        assert_eq!(diff.left().period_label(), "FY2023");
+        assert_eq!(diff.right().period_label(), "FY2024");
+        assert_eq!(diff.metrics()[0].change(), 25);
+        assert_eq!(diff.themes()[0].change(), 3);
+    }
+
+    #[test]
+    fn returns_error_when_a_report_cannot_be_loaded() {
+        let differ = ReportDiffer::new(MockReportRepository {
+            reports: HashMap::from([("a.json".to_string(), report(2024, 1, vec![]))]),
+        });
+
+        let result = differ.diff(Path::new("a.json"), Path::new("missing.json"));
+
+        assert!(result.is_err());
+    }
+}
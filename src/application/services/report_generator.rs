@@ -1,16 +1,49 @@
 use crate::domain::entities::commit::Commit;
+use crate::domain::entities::config::Config;
+use crate::domain::entities::department::Department;
 use crate::domain::entities::github_activity::GitHubActivity;
+use crate::domain::entities::release::Release;
 use crate::domain::entities::report::Report;
+use crate::domain::entities::trend_report::TrendReport;
 use crate::domain::repositories::config_repository::ConfigRepository;
 use crate::domain::repositories::document_repository::DocumentRepository;
 use crate::domain::repositories::github_repository::GitHubRepository;
 use crate::domain::repositories::output_repository::OutputRepository;
+use crate::domain::services::fiscal_calendar::FiscalCalendar;
+use crate::domain::services::progress_reporter::{NoOpProgressReporter, ProgressReporter};
+use crate::domain::value_objects::activity_breakdown::ActivityBreakdown;
+use crate::domain::value_objects::activity_timeline::ActivityTimeline;
+use crate::domain::value_objects::classification_summary::ClassificationSummary;
+use crate::domain::value_objects::commit_classifier::CommitClassifier;
 use crate::domain::value_objects::commit_theme::CommitTheme;
+use crate::domain::value_objects::commit_time_distribution::CommitTimeDistribution;
+use crate::domain::value_objects::comparison::Comparison;
+use crate::domain::value_objects::contribution_calendar::ContributionCalendar;
+use crate::domain::value_objects::conventional_commit::{BreakingChange, ScopeCount};
+use crate::domain::value_objects::fiscal_year_selector::FiscalYearSelector;
+use crate::domain::value_objects::issue_summary::IssueSummary;
+use crate::domain::value_objects::language_share::LanguageShare;
+use crate::domain::value_objects::period::Period;
+use crate::domain::value_objects::period_kind::PeriodKind;
+use crate::domain::value_objects::pull_request_summary::PullRequestSummary;
+use crate::domain::value_objects::repository_mirror::RepositoryMirror;
+use crate::domain::value_objects::revert::Revert;
+use crate::domain::value_objects::review_summary::ReviewSummary;
+use crate::domain::value_objects::source_mode::SourceMode;
 use anyhow::{Context, Result};
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Utc};
 use std::collections::HashMap;
 use std::path::Path;
 
+/// Which sections of a report are built
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportDetail {
+    /// Every section, for reports that are written out
+    Full,
+    /// Only the activity totals, pull requests and commit themes a comparison reads
+    ComparisonOnly,
+}
+
 /// Service for generating reports
 #[allow(dead_code)]
 pub struct ReportGenerator<C, G, D, O>
@@ -24,6 +57,8 @@ where
     github_repository: G,
     document_repository: D,
     output_repository: O,
+    progress_reporter: Box<dyn ProgressReporter>,
+    compare_with_previous_year: bool,
 }
 
 impl<C, G, D, O> ReportGenerator<C, G, D, O>
@@ -46,19 +81,156 @@ where
             github_repository,
             document_repository,
             output_repository,
+            progress_reporter: Box::new(NoOpProgressReporter::new()),
+            compare_with_previous_year: false,
         }
     }
 
+    /// Sets where the resolved period of each report is announced
+    #[allow(dead_code)]
+    pub fn with_progress_reporter(mut self, reporter: impl ProgressReporter + 'static) -> Self {
+        self.progress_reporter = Box::new(reporter);
+        self
+    }
+
+    /// Sets whether reports include a comparison with the same period of the previous fiscal year
+    #[allow(dead_code)]
+    pub fn with_comparison(mut self, compare_with_previous_year: bool) -> Self {
+        self.compare_with_previous_year = compare_with_previous_year;
+        self
+    }
+
     /// Generates reports for all departments or a specific department
     #[allow(dead_code)]
     pub fn generate(
         &self,
         config_path: &Path,
-        year: Option<u32>,
+        year: FiscalYearSelector,
+        period_kind: PeriodKind,
+        department_filter: Option<&str>,
+        output_dir: &Path,
+        file_extension: &str,
+    ) -> Result<Vec<String>> {
+        let (config, departments, classifier) = self.load(config_path, department_filter)?;
+
+        let mut generated_files = Vec::new();
+
+        // Process each department
+        for department in departments {
+            let calendar = FiscalCalendar::for_department(&department);
+            let today = Self::today(&department);
+            let (period_kind, fiscal_year) =
+                period_kind.anchor(year.resolve(today, &calendar), today, &calendar);
+            let mut report = self.build_report(
+                &config,
+                &classifier,
+                &department,
+                period_kind,
+                fiscal_year,
+                today,
+                ReportDetail::Full,
+            )?;
+
+            // The previous period goes through the same repositories, so cached data is reused
+            if self.compare_with_previous_year {
+                let (previous_kind, previous_year) =
+                    period_kind.previous_year(fiscal_year, &calendar);
+                let previous = self.build_report(
+                    &config,
+                    &classifier,
+                    &department,
+                    previous_kind,
+                    previous_year,
+                    today,
+                    ReportDetail::ComparisonOnly,
+                )?;
+                let comparison = Comparison::between(&report, &previous);
+                report = report.with_comparison(comparison);
+            }
+
+            // Output report; full fiscal years keep their plain `report-{dept}-{year}` name
+            let file_period = match period_kind {
+                PeriodKind::FiscalYear => fiscal_year.to_string(),
+                _ => report.period_label().to_string(),
+            };
+            let output_filename = format!(
+                "report-{}-{}.{}",
+                department.name(),
+                file_period,
+                file_extension
+            );
+            let output_path = output_dir.join(&output_filename);
+            self.output_repository.output(&report, &output_path)?;
+
+            generated_files.push(output_filename);
+        }
+
+        Ok(generated_files)
+    }
+
+    /// Generates a trend report per department covering fiscal years `from_year` to `to_year`
+    ///
+    /// Each year is built as a full annual report, so cached years are not fetched again.
+    #[allow(dead_code)]
+    pub fn generate_trend(
+        &self,
+        config_path: &Path,
+        from_year: u32,
+        to_year: u32,
         department_filter: Option<&str>,
         output_dir: &Path,
         file_extension: &str,
     ) -> Result<Vec<String>> {
+        if from_year > to_year {
+            anyhow::bail!(
+                "--from-year ({}) must not be after --to-year ({})",
+                from_year,
+                to_year
+            );
+        }
+        let (config, departments, classifier) = self.load(config_path, department_filter)?;
+
+        let mut generated_files = Vec::new();
+
+        for department in departments {
+            let today = Self::today(&department);
+            let reports = (from_year..=to_year)
+                .map(|fiscal_year| {
+                    self.build_report(
+                        &config,
+                        &classifier,
+                        &department,
+                        PeriodKind::FiscalYear,
+                        fiscal_year,
+                        today,
+                        ReportDetail::Full,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let trend = TrendReport::from_reports(department.name().to_string(), &reports);
+
+            let output_filename = format!(
+                "trend-{}-{}-{}.{}",
+                department.name(),
+                from_year,
+                to_year,
+                file_extension
+            );
+            let output_path = output_dir.join(&output_filename);
+            self.output_repository.output_trend(&trend, &output_path)?;
+
+            generated_files.push(output_filename);
+        }
+
+        Ok(generated_files)
+    }
+
+    /// Loads the configuration, the departments to process and the commit classifier
+    fn load(
+        &self,
+        config_path: &Path,
+        department_filter: Option<&str>,
+    ) -> Result<(Config, Vec<Department>, CommitClassifier)> {
         // Load configuration
         let config = self
             .config_repository
@@ -81,112 +253,269 @@ where
             anyhow::bail!("No departments found");
         }
 
-        let mut generated_files = Vec::new();
+        let classifier = CommitClassifier::new(config.classification_rules())
+            .with_gitmoji(config.gitmoji_mappings())
+            .with_heuristics(config.heuristic_classification());
 
-        // Process each department
-        for department in departments {
-            let fiscal_year = year.unwrap_or(2024); // Default to 2024 if not specified
-            let fiscal_start_month = department.fiscal_year_start_month();
+        Ok((config, departments, classifier))
+    }
 
-            // Calculate period
-            let (period_from, period_to) = calculate_fiscal_period(fiscal_year, fiscal_start_month);
+    /// Returns today in the department's timezone, which relative years and periods resolve against
+    fn today(department: &Department) -> NaiveDate {
+        Utc::now()
+            .with_timezone(&department.timezone())
+            .date_naive()
+    }
+
+    /// Builds the report of one department for an anchored period
+    ///
+    /// With `ReportDetail::ComparisonOnly`, documents, languages, the contribution
+    /// calendar and releases are skipped, so only the data behind the activity
+    /// totals, pull request counts and commit themes is fetched.
+    #[allow(clippy::too_many_arguments)]
+    fn build_report(
+        &self,
+        config: &Config,
+        classifier: &CommitClassifier,
+        department: &Department,
+        period_kind: PeriodKind,
+        fiscal_year: u32,
+        today: NaiveDate,
+        detail: ReportDetail,
+    ) -> Result<Report> {
+        let full = detail == ReportDetail::Full;
+        let calendar = FiscalCalendar::for_department(department);
+        let period_label = period_kind.label(fiscal_year);
+
+        // Calculate period; its days start at midnight in the department's timezone
+        let (period_from, period_to) = period_kind.resolve(fiscal_year, &calendar);
+        let period = Period::new(period_from, period_to, department.timezone());
+        self.progress_reporter
+            .report_period(department.name(), &period_label, &period);
+
+        // Fetch GitHub activity
+        let filter = department.repository_filter();
+        let mut total_activity = GitHubActivity::new(0, 0, 0, 0);
+        for org in department.github_organizations() {
+            let activity = self.github_repository.fetch_activity(org, period, filter)?;
+            total_activity = total_activity.add(&activity);
+        }
+
+        // Merge contributions made to repositories outside the department's organizations
+        let mut contributions = Vec::new();
+        if department.source_mode() == SourceMode::Contributions {
+            let user = config
+                .target_github_user()
+                .context("target_github_user is required when source_mode is \"contributions\"")?;
+            contributions = self.github_repository.fetch_contributions(user, period)?;
+            for contribution in &contributions {
+                if contribution.is_external_to(department.github_organizations()) {
+                    total_activity = total_activity.add(&contribution.to_activity());
+                }
+            }
+        }
+
+        // Fetch documents
+        let documents = if full {
+            self.document_repository
+                .fetch_documents(department.local_documents())?
+        } else {
+            Vec::new()
+        };
+
+        // Fetch commits and build theme summary
+        let mut all_commits = Vec::new();
+        let author = config.target_github_user();
+        for org in department.github_organizations() {
+            let commits = self
+                .github_repository
+                .fetch_commits(org, period, author, filter)?;
+            all_commits.extend(commits);
+        }
+
+        // The same SHA can be reached through forks, mirrors or overlapping organizations
+        let (all_commits, duplicate_commits) = Commit::deduplicate(all_commits);
+        let repository_mirrors = RepositoryMirror::from_commits(&all_commits);
+
+        // Drop bot and merge commits that do not reflect the user's own work
+        let (all_commits, mut excluded_commits) = config.commit_filter().apply(all_commits);
 
-            // Fetch GitHub activity
-            let mut total_activity = GitHubActivity::new(0, 0, 0, 0);
+        // Link reverts to their targets; the net view drops both sides of each pair
+        let reverts = Revert::detect(&all_commits);
+        let all_commits = if config.commit_filter().exclude_reverts() {
+            let (kept, removed) = Revert::exclude_pairs(all_commits, &reverts);
+            excluded_commits = excluded_commits.with_reverts(removed);
+            kept
+        } else {
+            all_commits
+        };
+
+        let (theme_summary, classification_summary) =
+            Self::build_theme_summary(&all_commits, classifier);
+        let breaking_changes = BreakingChange::from_commits(&all_commits);
+        let scope_summary = ScopeCount::from_commits(&all_commits);
+        let commit_time_distribution = CommitTimeDistribution::from_commits(
+            &all_commits,
+            department.timezone(),
+            department.working_hours(),
+        );
+
+        // Weight repository languages by the commits made to each repository
+        let mut all_languages = Vec::new();
+        if full && !all_commits.is_empty() {
             for org in department.github_organizations() {
-                let activity =
-                    self.github_repository
-                        .fetch_activity(org, period_from, period_to)?;
-                total_activity = total_activity.add(&activity);
+                all_languages.extend(self.github_repository.fetch_languages(org, filter)?);
             }
+        }
+        let languages = LanguageShare::from_commits(&all_languages, &all_commits);
+
+        // Fetch pull requests and build lifecycle summary
+        let mut all_pull_requests = Vec::new();
+        for org in department.github_organizations() {
+            let pull_requests = self
+                .github_repository
+                .fetch_pull_requests(org, period, author)?;
+            all_pull_requests.extend(pull_requests);
+        }
+        let pull_request_summary =
+            PullRequestSummary::from_pull_requests(&all_pull_requests, period);
 
-            // Fetch documents
-            let documents = self
-                .document_repository
-                .fetch_documents(department.local_documents())?;
+        let organization_breakdown =
+            ActivityBreakdown::by_organization(&all_commits, &all_pull_requests, classifier);
+        let repository_breakdown =
+            ActivityBreakdown::by_repository(&all_commits, &all_pull_requests, classifier);
 
-            // Fetch commits and build theme summary
-            let mut all_commits = Vec::new();
-            let author = config.target_github_user();
+        // Fetch reviews submitted by the target user (reviews need a known reviewer)
+        let mut review_summary = None;
+        if let Some(reviewer) = author {
+            let mut all_reviews = Vec::new();
             for org in department.github_organizations() {
-                let commits =
-                    self.github_repository
-                        .fetch_commits(org, period_from, period_to, author)?;
-                all_commits.extend(commits);
+                let reviews = self
+                    .github_repository
+                    .fetch_reviews(org, period, reviewer)?;
+                all_reviews.extend(reviews);
             }
+            total_activity =
+                total_activity.add(&GitHubActivity::new(0, 0, 0, all_reviews.len() as u32));
+            review_summary = Some(ReviewSummary::from_reviews(&all_reviews));
+        }
 
-            let theme_summary = Self::build_theme_summary(&all_commits);
+        // Fetch issues opened, closed or commented on within the period
+        let mut all_issues = Vec::new();
+        for org in department.github_organizations() {
+            let issues = self.github_repository.fetch_issues(org, period, author)?;
+            all_issues.extend(issues);
+        }
+        let issue_summary = IssueSummary::from_issues(&all_issues, period, author);
+        total_activity =
+            total_activity.add(&GitHubActivity::new(0, 0, issue_summary.involved(), 0));
 
-            let report = Report::new(
-                fiscal_year,
-                department.name().to_string(),
-                period_from,
-                period_to,
-                total_activity,
-                documents,
-                theme_summary,
-            );
+        let timeline = ActivityTimeline::from_activity(
+            &all_commits,
+            &all_pull_requests,
+            &all_issues,
+            period,
+            &calendar,
+            author,
+        );
 
-            // Output report
-            let output_filename = format!(
-                "report-{}-{}.{}",
-                department.name(),
-                fiscal_year,
-                file_extension
-            );
-            let output_path = output_dir.join(&output_filename);
-            self.output_repository.output(&report, &output_path)?;
+        // GitHub's own calendar covers all of the user's contributions in contributions
+        // source mode; otherwise count the collected commits
+        let contribution_calendar = match (department.source_mode(), author) {
+            (SourceMode::Contributions, Some(user)) if full => {
+                let counts = self
+                    .github_repository
+                    .fetch_contribution_calendar(user, period)?;
+                ContributionCalendar::from_daily_counts(&counts, period, today)
+            }
+            _ => ContributionCalendar::from_commits(&all_commits, period, today),
+        };
 
-            generated_files.push(output_filename);
+        // Fetch releases and attach the themes of the commits they shipped
+        let mut all_releases = Vec::new();
+        if full {
+            for org in department.github_organizations() {
+                let releases = self.github_repository.fetch_releases(org, period, filter)?;
+                all_releases.extend(releases);
+            }
         }
+        let releases = Release::attach_themes(all_releases, &all_commits, classifier);
 
-        Ok(generated_files)
+        let mut report = Report::new(
+            fiscal_year,
+            department.name().to_string(),
+            period_from,
+            period_to,
+            total_activity,
+            documents,
+            theme_summary,
+        )
+        .with_contributions(contributions)
+        .with_pull_request_summary(pull_request_summary)
+        .with_issue_summary(issue_summary)
+        .with_releases(releases)
+        .with_languages(languages)
+        .with_excluded_commits(excluded_commits)
+        .with_duplicate_commits(duplicate_commits)
+        .with_repository_mirrors(repository_mirrors)
+        .with_breaking_changes(breaking_changes)
+        .with_scope_summary(scope_summary)
+        .with_classification_summary(classification_summary)
+        .with_reverts(reverts)
+        .with_breakdowns(organization_breakdown, repository_breakdown)
+        .with_timeline(timeline)
+        .with_contribution_calendar(contribution_calendar)
+        .with_commit_time_distribution(commit_time_distribution)
+        .with_timezone(period.timezone())
+        .with_period(period_kind, period_label);
+        if let Some(summary) = review_summary {
+            report = report.with_review_summary(summary);
+        }
+
+        Ok(report)
     }
 
-    /// Builds a theme summary from commit messages
-    fn build_theme_summary(commits: &[Commit]) -> HashMap<CommitTheme, u32> {
+    /// Builds a theme summary from commit messages, along with how they were classified
+    fn build_theme_summary(
+        commits: &[Commit],
+        classifier: &CommitClassifier,
+    ) -> (HashMap<CommitTheme, u32>, ClassificationSummary) {
         let mut theme_summary = HashMap::new();
 
-        for commit in commits {
-            let theme = CommitTheme::from_commit_message(commit.message());
-            *theme_summary.entry(theme).or_insert(0) += 1;
+        let classifications: Vec<_> = commits
+            .iter()
+            .map(|commit| classifier.classify_with_method(commit.message()))
+            .collect();
+        for classification in &classifications {
+            *theme_summary
+                .entry(classification.theme().clone())
+                .or_insert(0) += 1;
         }
 
-        theme_summary
+        (
+            theme_summary,
+            ClassificationSummary::from_classifications(&classifications),
+        )
     }
 }
 
-/// Calculates the fiscal period for a given year and start month
-fn calculate_fiscal_period(year: u32, start_month: u32) -> (NaiveDate, NaiveDate) {
-    let from = NaiveDate::from_ymd_opt(year as i32, start_month, 1).expect("Invalid date");
-
-    let (to_year, to_month) = if start_month == 1 {
-        (year as i32, 12)
-    } else {
-        (year as i32 + 1, start_month - 1)
-    };
-
-    // Get the last day of the to_month
-    let to = if to_month == 12 {
-        NaiveDate::from_ymd_opt(to_year, 12, 31).expect("Invalid date")
-    } else {
-        // Get the first day of the next month and subtract one day
-        NaiveDate::from_ymd_opt(to_year, to_month + 1, 1)
-            .expect("Invalid date")
-            .pred_opt()
-            .expect("Invalid date")
-    };
-
-    (from, to)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::entities::config::Config;
     use crate::domain::entities::department::Department;
     use crate::domain::entities::document_content::DocumentContent;
+    use crate::domain::entities::issue::Issue;
+    use crate::domain::entities::pull_request::PullRequest;
+    use crate::domain::entities::repository_contribution::RepositoryContribution;
+    use crate::domain::entities::repository_languages::{LanguageSize, RepositoryLanguages};
+    use crate::domain::entities::review::Review;
+    use crate::domain::value_objects::contribution_calendar::DailyContribution;
+    use crate::domain::value_objects::issue_state::IssueState;
     use crate::domain::value_objects::output_format::OutputFormat;
+    use crate::domain::value_objects::repository_filter::RepositoryFilter;
+    use crate::domain::value_objects::review_state::ReviewState;
+    use chrono::NaiveDate;
     use std::collections::HashMap;
     use std::sync::{Arc, Mutex};
 
@@ -201,16 +530,27 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
     struct MockGitHubRepository {
         responses: HashMap<String, GitHubActivity>,
+        contributions: Vec<RepositoryContribution>,
+        contribution_calendar: Vec<DailyContribution>,
+        pull_requests: Vec<PullRequest>,
+        reviews: Vec<Review>,
+        issues: Vec<Issue>,
+        releases: Vec<Release>,
+        commits: Vec<Commit>,
+        languages: Vec<RepositoryLanguages>,
+        /// Records "{method} {period start}" for the fetches a comparison can skip
+        calls: Arc<Mutex<Vec<String>>>,
     }
 
     impl GitHubRepository for MockGitHubRepository {
         fn fetch_activity(
             &self,
             org_or_user: &str,
-            _from: NaiveDate,
-            _to: NaiveDate,
+            _period: Period,
+            _filter: &RepositoryFilter,
         ) -> Result<GitHubActivity> {
             self.responses
                 .get(org_or_user)
@@ -221,12 +561,84 @@ mod tests {
         fn fetch_commits(
             &self,
             _org_or_user: &str,
-            _from: NaiveDate,
-            _to: NaiveDate,
+            _period: Period,
             _author: Option<&str>,
+            _filter: &RepositoryFilter,
         ) -> Result<Vec<Commit>> {
-            // Mock implementation returns empty commits for now
-            Ok(Vec::new())
+            Ok(self.commits.clone())
+        }
+
+        fn fetch_contributions(
+            &self,
+            _user: &str,
+            _period: Period,
+        ) -> Result<Vec<RepositoryContribution>> {
+            Ok(self.contributions.clone())
+        }
+
+        fn fetch_contribution_calendar(
+            &self,
+            _user: &str,
+            period: Period,
+        ) -> Result<Vec<DailyContribution>> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("contribution_calendar {}", period.from()));
+            Ok(self.contribution_calendar.clone())
+        }
+
+        fn fetch_pull_requests(
+            &self,
+            _org_or_user: &str,
+            period: Period,
+            _author: Option<&str>,
+        ) -> Result<Vec<PullRequest>> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("pull_requests {}", period.from()));
+            Ok(self.pull_requests.clone())
+        }
+
+        fn fetch_reviews(
+            &self,
+            _org_or_user: &str,
+            _period: Period,
+            _reviewer: &str,
+        ) -> Result<Vec<Review>> {
+            Ok(self.reviews.clone())
+        }
+
+        fn fetch_issues(
+            &self,
+            _org_or_user: &str,
+            _period: Period,
+            _user: Option<&str>,
+        ) -> Result<Vec<Issue>> {
+            Ok(self.issues.clone())
+        }
+
+        fn fetch_releases(
+            &self,
+            _org_or_user: &str,
+            period: Period,
+            _filter: &RepositoryFilter,
+        ) -> Result<Vec<Release>> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("releases {}", period.from()));
+            Ok(self.releases.clone())
+        }
+
+        fn fetch_languages(
+            &self,
+            _org_or_user: &str,
+            _filter: &RepositoryFilter,
+        ) -> Result<Vec<RepositoryLanguages>> {
+            self.calls.lock().unwrap().push("languages".to_string());
+            Ok(self.languages.clone())
         }
     }
 
@@ -257,6 +669,51 @@ mod tests {
                 .push((filename, report.department_name().to_string()));
             Ok(())
         }
+
+        fn output_trend(&self, trend: &TrendReport, path: &Path) -> Result<()> {
+            let filename = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("")
+                .to_string();
+            self.outputs
+                .lock()
+                .unwrap()
+                .push((filename, trend.department_name().to_string()));
+            Ok(())
+        }
+    }
+
+    /// Output repository that keeps the generated reports for inspection
+    struct CapturingOutputRepository {
+        reports: Arc<Mutex<Vec<Report>>>,
+    }
+
+    impl OutputRepository for CapturingOutputRepository {
+        fn output(&self, report: &Report, _path: &Path) -> Result<()> {
+            self.reports.lock().unwrap().push(report.clone());
+            Ok(())
+        }
+
+        fn output_trend(&self, _trend: &TrendReport, _path: &Path) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Output repository that keeps the generated trend reports for inspection
+    struct TrendCapturingOutputRepository {
+        trends: Arc<Mutex<Vec<TrendReport>>>,
+    }
+
+    impl OutputRepository for TrendCapturingOutputRepository {
+        fn output(&self, _report: &Report, _path: &Path) -> Result<()> {
+            Ok(())
+        }
+
+        fn output_trend(&self, trend: &TrendReport, _path: &Path) -> Result<()> {
+            self.trends.lock().unwrap().push(trend.clone());
+            Ok(())
+        }
     }
 
     #[test]
@@ -275,6 +732,7 @@ mod tests {
         let config_repo = MockConfigRepository { config };
         let github_repo = MockGitHubRepository {
             responses: github_responses,
+            ..Default::default()
         };
         let document_repo = MockDocumentRepository { documents: vec![] };
         let outputs = Arc::new(Mutex::new(Vec::new()));
@@ -287,7 +745,8 @@ mod tests {
         let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
         let result = generator.generate(
             Path::new("dummy.toml"),
-            Some(2024),
+            FiscalYearSelector::Year(2024),
+            PeriodKind::FiscalYear,
             None,
             temp_dir.path(),
             "md",
@@ -337,6 +796,7 @@ mod tests {
         let config_repo = MockConfigRepository { config };
         let github_repo = MockGitHubRepository {
             responses: github_responses,
+            ..Default::default()
         };
         let document_repo = MockDocumentRepository { documents: vec![] };
         let outputs = Arc::new(Mutex::new(Vec::new()));
@@ -349,7 +809,8 @@ mod tests {
         let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
         let result = generator.generate(
             Path::new("dummy.toml"),
-            Some(2024),
+            FiscalYearSelector::Year(2024),
+            PeriodKind::FiscalYear,
             None,
             temp_dir.path(),
             "md",
@@ -395,6 +856,7 @@ mod tests {
         let config_repo = MockConfigRepository { config };
         let github_repo = MockGitHubRepository {
             responses: github_responses,
+            ..Default::default()
         };
         let document_repo = MockDocumentRepository { documents: vec![] };
         let outputs = Arc::new(Mutex::new(Vec::new()));
@@ -407,7 +869,8 @@ mod tests {
         let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
         let result = generator.generate(
             Path::new("dummy.toml"),
-            Some(2024),
+            FiscalYearSelector::Year(2024),
+            PeriodKind::FiscalYear,
             Some("個人"),
             temp_dir.path(),
             "md",
@@ -424,16 +887,1146 @@ mod tests {
     }
 
     #[test]
-    fn calculates_fiscal_period_correctly() {
-        // Fiscal year starting in April
-        let (from, to) = calculate_fiscal_period(2024, 4);
-        assert_eq!(from, NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
-        assert_eq!(to, NaiveDate::from_ymd_opt(2025, 3, 31).unwrap());
+    fn generates_report_for_fiscal_quarter() {
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(10, 2, 1, 0));
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            MockGitHubRepository {
+                responses: github_responses,
+                ..Default::default()
+            },
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let files = generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::Quarter { quarter: 3 },
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        assert_eq!(files, vec!["report-個人-FY2024-Q3.md".to_string()]);
+        let reports = reports.lock().unwrap();
+        assert_eq!(
+            reports[0].period_from(),
+            NaiveDate::from_ymd_opt(2024, 10, 1).unwrap()
+        );
+        assert_eq!(
+            reports[0].period_to(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+        );
+        assert_eq!(reports[0].period_kind(), PeriodKind::Quarter { quarter: 3 });
+        assert_eq!(reports[0].period_label(), "FY2024-Q3");
+    }
+
+    #[test]
+    fn generates_trend_report_per_department_across_fiscal_years() {
+        use chrono::TimeZone;
+
+        let personal = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let team = Department::new("Team".to_string(), 1, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![personal, team],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(10, 2, 1, 0));
+        let commit = Commit::new(
+            "abc123".to_string(),
+            "feat: add export".to_string(),
+            "user".to_string(),
+            Utc::now(),
+            "test-org/app".to_string(),
+        )
+        .with_changes(30, 10);
+
+        let trends = Arc::new(Mutex::new(Vec::new()));
+        let periods = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            MockGitHubRepository {
+                responses: github_responses,
+                commits: vec![commit],
+                pull_requests: vec![PullRequest::new(
+                    1,
+                    "feat: add export".to_string(),
+                    "test-org/app".to_string(),
+                    "user".to_string(),
+                    Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap(),
+                )],
+                ..Default::default()
+            },
+            MockDocumentRepository { documents: vec![] },
+            TrendCapturingOutputRepository {
+                trends: trends.clone(),
+            },
+        )
+        .with_progress_reporter(PeriodRecordingReporter {
+            periods: periods.clone(),
+        });
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let files = generator
+            .generate_trend(
+                Path::new("dummy.toml"),
+                2022,
+                2024,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate trend report");
+
+        assert_eq!(
+            files,
+            vec![
+                "trend-個人-2022-2024.md".to_string(),
+                "trend-Team-2022-2024.md".to_string()
+            ]
+        );
+        assert_eq!(periods.lock().unwrap().len(), 6);
+        let trends = trends.lock().unwrap();
+        let years: Vec<u32> = trends[0].years().iter().map(|year| year.year()).collect();
+        assert_eq!(years, vec![2022, 2023, 2024]);
+        assert_eq!(
+            trends[0].years()[0].period_from(),
+            NaiveDate::from_ymd_opt(2022, 4, 1).unwrap()
+        );
+        assert_eq!(
+            trends[1].years()[2].period_to(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+        );
+        assert_eq!(trends[0].years()[1].commits(), 1);
+        // Pull requests are counted per fiscal year, not from the lifetime total
+        assert_eq!(trends[0].years()[0].pull_requests(), 0);
+        assert_eq!(trends[0].years()[1].pull_requests(), 1);
+        assert_eq!(trends[0].years()[1].additions(), 30);
+    }
+
+    #[test]
+    fn rejects_trend_with_reversed_years() {
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            MockGitHubRepository::default(),
+            MockDocumentRepository { documents: vec![] },
+            TrendCapturingOutputRepository {
+                trends: Arc::new(Mutex::new(Vec::new())),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let result = generator.generate_trend(
+            Path::new("dummy.toml"),
+            2025,
+            2021,
+            None,
+            temp_dir.path(),
+            "md",
+        );
+
+        assert!(result.is_err());
+    }
+
+    /// Progress reporter that records the reported periods
+    struct PeriodRecordingReporter {
+        periods: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ProgressReporter for PeriodRecordingReporter {
+        fn report_period(&self, department: &str, label: &str, period: &Period) {
+            self.periods.lock().unwrap().push(format!(
+                "{}: {} ({} to {})",
+                department,
+                label,
+                period.from(),
+                period.to()
+            ));
+        }
+        fn start_fetching_commits(&self, _org_or_user: &str) {}
+        fn report_commits_progress(&self, _org_or_user: &str, _fetched_count: usize) {}
+        fn finish_fetching_commits(&self, _org_or_user: &str, _total_count: usize) {}
+        fn report_excluded_repository(&self, _org_or_user: &str, _repository: &str, _reason: &str) {
+        }
+        fn report_error(&self, _error: &str) {}
+    }
+
+    #[test]
+    fn compares_with_previous_fiscal_period() {
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(10, 2, 1, 0));
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let periods = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            MockGitHubRepository {
+                responses: github_responses,
+                ..Default::default()
+            },
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        )
+        .with_progress_reporter(PeriodRecordingReporter {
+            periods: periods.clone(),
+        })
+        .with_comparison(true);
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let files = generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::Quarter { quarter: 3 },
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        // Only the current period is written; the previous one is fetched for comparison
+        assert_eq!(files, vec!["report-個人-FY2024-Q3.md".to_string()]);
+        assert_eq!(
+            *periods.lock().unwrap(),
+            vec![
+                "個人: FY2024-Q3 (2024-10-01 to 2024-12-31)".to_string(),
+                "個人: FY2023-Q3 (2023-10-01 to 2023-12-31)".to_string(),
+            ]
+        );
+        let reports = reports.lock().unwrap();
+        let comparison = reports[0].comparison().expect("Comparison is missing");
+        assert_eq!(comparison.previous_label(), "FY2023-Q3");
+        assert_eq!(comparison.metrics()[0].change(), 0);
+    }
+
+    #[test]
+    fn fetches_only_compared_data_for_previous_period() {
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![])
+            .with_source_mode(SourceMode::Contributions);
+        let config = Config::with_target_user(
+            Some("user".to_string()),
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(10, 2, 1, 0));
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            MockGitHubRepository {
+                responses: github_responses,
+                commits: vec![Commit::new(
+                    "abc123".to_string(),
+                    "feat: add export".to_string(),
+                    "user".to_string(),
+                    Utc::now(),
+                    "test-org/app".to_string(),
+                )],
+                calls: calls.clone(),
+                ..Default::default()
+            },
+            MockDocumentRepository { documents: vec![] },
+            MockOutputRepository {
+                outputs: Arc::new(Mutex::new(Vec::new())),
+            },
+        )
+        .with_comparison(true);
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        // The calendar, languages and releases are only fetched for FY2024
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "languages".to_string(),
+                "pull_requests 2024-04-01".to_string(),
+                "contribution_calendar 2024-04-01".to_string(),
+                "releases 2024-04-01".to_string(),
+                "pull_requests 2023-04-01".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_relative_year_from_today_and_reports_period() {
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(0, 0, 0, 0));
+
+        let periods = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            MockGitHubRepository {
+                responses: github_responses,
+                ..Default::default()
+            },
+            MockDocumentRepository { documents: vec![] },
+            MockOutputRepository {
+                outputs: Arc::new(Mutex::new(Vec::new())),
+            },
+        )
+        .with_progress_reporter(PeriodRecordingReporter {
+            periods: periods.clone(),
+        });
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let files = generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Last,
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let last_year = FiscalYearSelector::Last
+            .resolve(Utc::now().date_naive(), &FiscalCalendar::new(4, 1).unwrap());
+        assert_eq!(files, vec![format!("report-個人-{}.md", last_year)]);
+        assert_eq!(
+            *periods.lock().unwrap(),
+            vec![format!(
+                "個人: FY{} ({}-04-01 to {}-03-31)",
+                last_year,
+                last_year,
+                last_year + 1
+            )]
+        );
+    }
+
+    #[test]
+    fn merges_external_contributions_in_contributions_source_mode() {
+        let dept = Department::new(
+            "個人".to_string(),
+            4,
+            vec!["connect0459".to_string()],
+            vec![],
+        )
+        .with_source_mode(SourceMode::Contributions);
+        let config = Config::with_target_user(
+            Some("connect0459".to_string()),
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert(
+            "connect0459".to_string(),
+            GitHubActivity::new(100, 20, 15, 30),
+        );
+
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            contributions: vec![
+                RepositoryContribution::new("connect0459/nenpo".to_string(), 50, 5, 2, 0),
+                RepositoryContribution::new("rust-lang/rust".to_string(), 7, 2, 1, 3),
+            ],
+            contribution_calendar: vec![
+                DailyContribution::new(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(), 4),
+                DailyContribution::new(NaiveDate::from_ymd_opt(2024, 6, 2).unwrap(), 2),
+            ],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        // Only the external repository is added on top of the organization totals
+        assert_eq!(
+            reports[0].github_activity(),
+            &GitHubActivity::new(107, 22, 16, 33)
+        );
+        assert_eq!(reports[0].contributions().len(), 2);
+        // GitHub's contribution calendar is used instead of counting commits
+        let calendar = reports[0].contribution_calendar();
+        assert_eq!(calendar.total(), 6);
+        assert_eq!(calendar.longest_streak(), 2);
+    }
+
+    #[test]
+    fn fails_in_contributions_source_mode_without_target_user() {
+        let dept = Department::new(
+            "個人".to_string(),
+            4,
+            vec!["connect0459".to_string()],
+            vec![],
+        )
+        .with_source_mode(SourceMode::Contributions);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert(
+            "connect0459".to_string(),
+            GitHubActivity::new(100, 20, 15, 30),
+        );
+
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            MockGitHubRepository {
+                responses: github_responses,
+                ..Default::default()
+            },
+            MockDocumentRepository { documents: vec![] },
+            MockOutputRepository {
+                outputs: Arc::new(Mutex::new(Vec::new())),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let result = generator.generate(
+            Path::new("dummy.toml"),
+            FiscalYearSelector::Year(2024),
+            PeriodKind::FiscalYear,
+            None,
+            temp_dir.path(),
+            "md",
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn builds_pull_request_summary_from_all_organizations() {
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new(
+            "個人".to_string(),
+            4,
+            vec!["personal-org".to_string(), "company-org".to_string()],
+            vec![],
+        );
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("personal-org".to_string(), GitHubActivity::new(0, 0, 0, 0));
+        github_responses.insert("company-org".to_string(), GitHubActivity::new(0, 0, 0, 0));
+
+        let created_at = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            pull_requests: vec![PullRequest::new(
+                1,
+                "feat: add report".to_string(),
+                "personal-org/nenpo".to_string(),
+                "connect0459".to_string(),
+                created_at,
+            )
+            .with_lifecycle(Some(created_at + chrono::Duration::hours(6)), None)],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        let summary = reports[0]
+            .pull_request_summary()
+            .expect("Pull request summary should exist");
+        // The mock returns the same pull request for both organizations
+        assert_eq!(summary.authored(), 2);
+        assert_eq!(summary.merged(), 2);
+        assert_eq!(summary.median_cycle_time_hours(), Some(6.0));
+    }
+
+    #[test]
+    fn counts_reviews_of_target_user() {
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::with_target_user(
+            Some("connect0459".to_string()),
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(100, 20, 15, 0));
+
+        let submitted_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            reviews: vec![
+                Review::new(
+                    "test-org/repo".to_string(),
+                    1,
+                    "octocat".to_string(),
+                    ReviewState::Approved,
+                    submitted_at,
+                    2,
+                ),
+                Review::new(
+                    "test-org/repo".to_string(),
+                    2,
+                    "octocat".to_string(),
+                    ReviewState::Commented,
+                    submitted_at,
+                    5,
+                ),
+            ],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports[0].github_activity().reviews(), 2);
+        let summary = reports[0]
+            .review_summary()
+            .expect("Review summary should exist");
+        assert_eq!(summary.approved(), 1);
+        assert_eq!(summary.comments(), 7);
+        assert_eq!(summary.pull_requests_reviewed(), 2);
+    }
+
+    #[test]
+    fn counts_issues_of_target_user() {
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::with_target_user(
+            Some("connect0459".to_string()),
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(100, 20, 0, 0));
+
+        let created_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            issues: vec![
+                Issue::new(
+                    1,
+                    "Crash on startup".to_string(),
+                    "test-org/repo".to_string(),
+                    "connect0459".to_string(),
+                    IssueState::Open,
+                    created_at,
+                    None,
+                )
+                .with_labels(vec!["bug".to_string()]),
+                Issue::new(
+                    2,
+                    "Question".to_string(),
+                    "test-org/repo".to_string(),
+                    "octocat".to_string(),
+                    IssueState::Open,
+                    created_at,
+                    None,
+                ),
+            ],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        // Only the issue opened by the target user counts
+        assert_eq!(reports[0].github_activity().issues(), 1);
+        let summary = reports[0]
+            .issue_summary()
+            .expect("Issue summary should exist");
+        assert_eq!(summary.opened(), 1);
+        assert_eq!(summary.by_label()[0].name(), "bug");
+        // The timeline follows the fiscal year, so May is the second month
+        let months = reports[0].timeline().monthly();
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[1].label(), "2024-05");
+        assert_eq!(months[1].issues(), 1);
+    }
+
+    #[test]
+    fn attaches_commit_themes_to_releases_and_weights_languages() {
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(2, 0, 0, 0));
+
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            commits: vec![
+                Commit::new(
+                    "abc123".to_string(),
+                    "feat: add export".to_string(),
+                    "connect0459".to_string(),
+                    Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap(),
+                    "test-org/app".to_string(),
+                ),
+                Commit::new(
+                    "def456".to_string(),
+                    "fix: export encoding".to_string(),
+                    "connect0459".to_string(),
+                    Utc.with_ymd_and_hms(2024, 5, 2, 10, 0, 0).unwrap(),
+                    "test-org/app".to_string(),
+                ),
+            ],
+            releases: vec![Release::new(
+                "test-org/app".to_string(),
+                "v1.0.0".to_string(),
+                "v1.0.0".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 3, 10, 0, 0).unwrap(),
+                false,
+            )],
+            languages: vec![RepositoryLanguages::new(
+                "test-org/app".to_string(),
+                vec![
+                    LanguageSize::new("Rust".to_string(), 300),
+                    LanguageSize::new("TypeScript".to_string(), 100),
+                ],
+            )],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        let languages = reports[0].languages();
+        assert_eq!(languages[0].name(), "Rust");
+        assert_eq!(languages[0].percentage(), 75.0);
+        let releases = reports[0].releases();
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases[0].themes().get(&CommitTheme::Feat), Some(&1));
+        assert_eq!(releases[0].themes().get(&CommitTheme::Fix), Some(&1));
+    }
+
+    #[test]
+    fn drops_bot_and_merge_commits_from_theme_summary() {
+        use crate::domain::value_objects::commit_filter::CommitFilter;
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        )
+        .with_commit_filter(
+            CommitFilter::new(true, vec![], true).expect("Failed to create filter"),
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(3, 0, 0, 0));
+
+        let committed_date = Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap();
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            commits: vec![
+                Commit::new(
+                    "abc123".to_string(),
+                    "feat: add export".to_string(),
+                    "connect0459".to_string(),
+                    committed_date,
+                    "test-org/app".to_string(),
+                )
+                .with_parent_count(1),
+                Commit::new(
+                    "def456".to_string(),
+                    "chore(deps): bump serde".to_string(),
+                    "dependabot[bot]".to_string(),
+                    committed_date,
+                    "test-org/app".to_string(),
+                )
+                .with_author_login(Some("dependabot[bot]".to_string()))
+                .with_parent_count(1),
+                Commit::new(
+                    "ghi789".to_string(),
+                    "Merge pull request #1 from test-org/feature".to_string(),
+                    "connect0459".to_string(),
+                    committed_date,
+                    "test-org/app".to_string(),
+                )
+                .with_parent_count(2),
+            ],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports[0].theme_summary().get(&CommitTheme::Feat), Some(&1));
+        assert_eq!(reports[0].theme_summary().get(&CommitTheme::Other), None);
+        assert_eq!(reports[0].excluded_commits().bots(), 1);
+        assert_eq!(reports[0].excluded_commits().merges(), 1);
+    }
+
+    #[test]
+    fn collapses_commits_shared_across_organizations() {
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new(
+            "個人".to_string(),
+            4,
+            vec!["test-org".to_string(), "connect0459".to_string()],
+            vec![],
+        );
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(2, 0, 0, 0));
+        github_responses.insert("connect0459".to_string(), GitHubActivity::new(2, 0, 0, 0));
+
+        let committed_date = Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap();
+        // The mock returns the same commits for every organization, like a fork of the org repo
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            commits: vec![
+                Commit::new(
+                    "abc123".to_string(),
+                    "feat: add export".to_string(),
+                    "connect0459".to_string(),
+                    committed_date,
+                    "test-org/app".to_string(),
+                ),
+                Commit::new(
+                    "def456".to_string(),
+                    "fix: handle empty input".to_string(),
+                    "connect0459".to_string(),
+                    committed_date,
+                    "test-org/app".to_string(),
+                ),
+            ],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports[0].theme_summary().get(&CommitTheme::Feat), Some(&1));
+        assert_eq!(reports[0].theme_summary().get(&CommitTheme::Fix), Some(&1));
+        assert_eq!(reports[0].duplicate_commits(), 2);
+    }
+
+    #[test]
+    fn breaks_down_activity_by_organization_and_repository() {
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(3, 1, 0, 0));
+
+        let committed_date = Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap();
+        let commit = |sha: &str, message: &str, repository: &str| {
+            Commit::new(
+                sha.to_string(),
+                message.to_string(),
+                "connect0459".to_string(),
+                committed_date,
+                repository.to_string(),
+            )
+            .with_changes(10, 2)
+        };
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            commits: vec![
+                commit("abc123", "feat: add export", "test-org/app"),
+                commit("def456", "fix: handle empty input", "test-org/app"),
+                commit("ghi789", "docs: update README", "test-org/docs"),
+            ],
+            pull_requests: vec![PullRequest::new(
+                1,
+                "docs: update README".to_string(),
+                "test-org/docs".to_string(),
+                "connect0459".to_string(),
+                committed_date,
+            )],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        let organizations = reports[0].organization_breakdown();
+        assert_eq!(organizations.len(), 1);
+        assert_eq!(organizations[0].name(), "test-org");
+        assert_eq!(organizations[0].commits(), 3);
+        assert_eq!(organizations[0].pull_requests(), 1);
+        assert_eq!(organizations[0].churn(), 36);
+
+        let repositories = reports[0].repository_breakdown();
+        let names: Vec<_> = repositories.iter().map(|r| r.name()).collect();
+        // Both repositories have 2 activities; test-org/app has more churn
+        assert_eq!(names, vec!["test-org/app", "test-org/docs"]);
+        assert_eq!(repositories[0].themes().get(&CommitTheme::Feat), Some(&1));
+
+        // Outside contributions source mode the calendar counts the collected commits
+        let calendar = reports[0].contribution_calendar();
+        assert_eq!(calendar.days().len(), 365);
+        assert_eq!(calendar.most_active_day().map(|day| day.count()), Some(3));
+    }
+
+    #[test]
+    fn distributes_commit_times_in_department_timezone() {
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new("東京".to_string(), 4, vec!["test-org".to_string()], vec![])
+            .with_timezone(chrono_tz::Asia::Tokyo);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        );
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(1, 0, 0, 0));
+
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            // 01:00 UTC is 10:00 in Tokyo
+            commits: vec![Commit::new(
+                "abc123".to_string(),
+                "feat: add export".to_string(),
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 1, 1, 0, 0).unwrap(),
+                "test-org/app".to_string(),
+            )],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
+
+        let reports = reports.lock().unwrap();
+        let distribution = reports[0].commit_time_distribution();
+        assert_eq!(distribution.timezone(), "Asia/Tokyo");
+        assert_eq!(distribution.by_hour()[10], 1);
+        assert_eq!(distribution.outside_working_hours(), 0);
+    }
+
+    #[test]
+    fn excludes_reverted_pairs_in_net_view() {
+        use crate::domain::value_objects::commit_filter::CommitFilter;
+        use chrono::{TimeZone, Utc};
+
+        let dept = Department::new("個人".to_string(), 4, vec!["test-org".to_string()], vec![]);
+        let config = Config::new(
+            4,
+            OutputFormat::Markdown,
+            "./reports".to_string(),
+            vec![dept],
+        )
+        .with_commit_filter(CommitFilter::default().with_exclude_reverts(true));
+
+        let mut github_responses = HashMap::new();
+        github_responses.insert("test-org".to_string(), GitHubActivity::new(3, 0, 0, 0));
+
+        let commit = |sha: &str, message: &str, day: u32| {
+            Commit::new(
+                sha.to_string(),
+                message.to_string(),
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, day, 10, 0, 0).unwrap(),
+                "test-org/app".to_string(),
+            )
+        };
+        let github_repo = MockGitHubRepository {
+            responses: github_responses,
+            commits: vec![
+                commit("aaaaaaa1111", "feat: add export", 1),
+                commit(
+                    "bbbbbbb2222",
+                    "Revert \"feat: add export\"\n\nThis reverts commit aaaaaaa1111.",
+                    2,
+                ),
+                commit("ccccccc3333", "fix: handle empty input", 3),
+            ],
+            ..Default::default()
+        };
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let generator = ReportGenerator::new(
+            MockConfigRepository { config },
+            github_repo,
+            MockDocumentRepository { documents: vec![] },
+            CapturingOutputRepository {
+                reports: reports.clone(),
+            },
+        );
+
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        generator
+            .generate(
+                Path::new("dummy.toml"),
+                FiscalYearSelector::Year(2024),
+                PeriodKind::FiscalYear,
+                None,
+                temp_dir.path(),
+                "md",
+            )
+            .expect("Failed to generate report");
 
-        // Calendar year
-        let (from, to) = calculate_fiscal_period(2024, 1);
-        assert_eq!(from, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
-        assert_eq!(to, NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports[0].theme_summary().get(&CommitTheme::Feat), None);
+        assert_eq!(reports[0].theme_summary().get(&CommitTheme::Other), None);
+        assert_eq!(reports[0].theme_summary().get(&CommitTheme::Fix), Some(&1));
+        assert_eq!(reports[0].excluded_commits().reverts(), 2);
+        assert_eq!(reports[0].reverts().len(), 1);
+        assert_eq!(reports[0].reverts()[0].reverted_sha(), Some("aaaaaaa1111"));
     }
 
     #[test]
@@ -471,16 +2064,18 @@ mod tests {
             ),
         ];
 
-        let theme_summary = ReportGenerator::<
-            MockConfigRepository,
-            MockGitHubRepository,
-            MockDocumentRepository,
-            MockOutputRepository,
-        >::build_theme_summary(&commits);
+        let (theme_summary, classification_summary) =
+            ReportGenerator::<
+                MockConfigRepository,
+                MockGitHubRepository,
+                MockDocumentRepository,
+                MockOutputRepository,
+            >::build_theme_summary(&commits, &CommitClassifier::default());
 
         assert_eq!(theme_summary.get(&CommitTheme::Feat), Some(&2));
         assert_eq!(theme_summary.get(&CommitTheme::Fix), Some(&1));
         assert_eq!(theme_summary.get(&CommitTheme::Docs), Some(&1));
         assert_eq!(theme_summary.get(&CommitTheme::Refactor), None);
+        assert_eq!(classification_summary.convention(), 4);
     }
 }
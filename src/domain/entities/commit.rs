@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Represents a Git commit
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -9,6 +10,16 @@ pub struct Commit {
     author: String,
     committed_date: DateTime<Utc>,
     repository: String,
+    #[serde(default)]
+    author_login: Option<String>,
+    #[serde(default)]
+    parent_count: u32,
+    #[serde(default)]
+    mirrored_in: Vec<String>,
+    #[serde(default)]
+    additions: u32,
+    #[serde(default)]
+    deletions: u32,
 }
 
 impl Commit {
@@ -26,9 +37,33 @@ impl Commit {
             author,
             committed_date,
             repository,
+            author_login: None,
+            parent_count: 0,
+            mirrored_in: Vec::new(),
+            additions: 0,
+            deletions: 0,
         }
     }
 
+    /// Sets the GitHub login of the author (`None` if not linked to a GitHub user)
+    pub fn with_author_login(mut self, author_login: Option<String>) -> Self {
+        self.author_login = author_login;
+        self
+    }
+
+    /// Sets the number of parent commits
+    pub fn with_parent_count(mut self, parent_count: u32) -> Self {
+        self.parent_count = parent_count;
+        self
+    }
+
+    /// Sets the number of added and deleted lines
+    pub fn with_changes(mut self, additions: u32, deletions: u32) -> Self {
+        self.additions = additions;
+        self.deletions = deletions;
+        self
+    }
+
     /// Returns the SHA
     #[allow(dead_code)]
     pub fn sha(&self) -> &str {
@@ -57,6 +92,87 @@ impl Commit {
     pub fn repository(&self) -> &str {
         &self.repository
     }
+
+    /// Returns the GitHub login of the author
+    pub fn author_login(&self) -> Option<&str> {
+        self.author_login.as_deref()
+    }
+
+    /// Returns the number of parent commits (0 if unknown)
+    #[allow(dead_code)]
+    pub fn parent_count(&self) -> u32 {
+        self.parent_count
+    }
+
+    /// Returns the number of added lines (0 if unknown)
+    pub fn additions(&self) -> u32 {
+        self.additions
+    }
+
+    /// Returns the number of deleted lines (0 if unknown)
+    pub fn deletions(&self) -> u32 {
+        self.deletions
+    }
+
+    /// Returns whether this is a merge commit (more than one parent)
+    pub fn is_merge(&self) -> bool {
+        self.parent_count > 1
+    }
+
+    /// Returns every repository this commit appeared in, starting with the first one seen
+    pub fn repositories(&self) -> Vec<&str> {
+        std::iter::once(self.repository.as_str())
+            .chain(self.mirrored_in.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Collapses commits sharing a SHA (forks, mirrors, overlapping organizations)
+    ///
+    /// The first occurrence is kept in place and records the other repositories
+    /// it was seen in. Returns the unique commits and the number of duplicates removed.
+    pub fn deduplicate(commits: Vec<Commit>) -> (Vec<Commit>, u32) {
+        let mut unique: Vec<Commit> = Vec::with_capacity(commits.len());
+        let mut index_by_sha: HashMap<String, usize> = HashMap::new();
+        let mut duplicates = 0;
+
+        for commit in commits {
+            match index_by_sha.get(&commit.sha) {
+                Some(&index) => {
+                    duplicates += 1;
+                    let kept = &mut unique[index];
+                    if !kept.repositories().contains(&commit.repository.as_str()) {
+                        kept.mirrored_in.push(commit.repository);
+                    }
+                }
+                None => {
+                    index_by_sha.insert(commit.sha.clone(), unique.len());
+                    unique.push(commit);
+                }
+            }
+        }
+
+        (unique, duplicates)
+    }
+}
+
+#[cfg(test)]
+impl Commit {
+    /// Creates a commit by "John Doe" for tests, committed at an RFC 3339 timestamp
+    pub(crate) fn fixture(sha: &str, message: &str, repository: &str, committed_at: &str) -> Self {
+        Self::new(
+            sha.to_string(),
+            message.to_string(),
+            "John Doe".to_string(),
+            committed_at.parse().expect("Invalid timestamp"),
+            repository.to_string(),
+        )
+    }
+
+    /// Sets the author name of a test commit
+    pub(crate) fn with_author(mut self, author: &str) -> Self {
+        self.author = author.to_string();
+        self
+    }
 }
 
 #[cfg(test)]
@@ -97,6 +213,37 @@ mod tests {
         assert_eq!(commit, cloned);
     }
 
+    #[test]
+    fn deduplicates_commits_by_sha_and_records_repositories() {
+        let date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        let commit = |sha: &str, repository: &str| {
+            Commit::new(
+                sha.to_string(),
+                "feat: add export".to_string(),
+                "John Doe".to_string(),
+                date,
+                repository.to_string(),
+            )
+        };
+
+        let (unique, duplicates) = Commit::deduplicate(vec![
+            commit("abc123", "org/app"),
+            commit("def456", "org/app"),
+            commit("abc123", "user/app-fork"),
+            commit("abc123", "mirror/app"),
+            commit("abc123", "org/app"),
+        ]);
+
+        assert_eq!(unique.len(), 2);
+        assert_eq!(duplicates, 3);
+        assert_eq!(unique[0].sha(), "abc123");
+        assert_eq!(
+            unique[0].repositories(),
+            vec!["org/app", "user/app-fork", "mirror/app"]
+        );
+        assert_eq!(unique[1].repositories(), vec!["org/app"]);
+    }
+
     #[test]
     fn serializes_commit() {
         let date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
@@ -113,4 +260,36 @@ mod tests {
 
         assert_eq!(commit, deserialized);
     }
+
+    #[test]
+    fn detects_merge_commit_by_parent_count() {
+        let date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        let commit = Commit::new(
+            "abc123".to_string(),
+            "Merge pull request #1 from feature".to_string(),
+            "John Doe".to_string(),
+            date,
+            "test-repo".to_string(),
+        );
+
+        assert!(!commit.is_merge());
+        assert!(commit.clone().with_parent_count(2).is_merge());
+        assert!(!commit.with_parent_count(1).is_merge());
+    }
+
+    #[test]
+    fn deserializes_commit_without_login_and_parents() {
+        let json = r#"{
+            "sha": "abc123",
+            "message": "feat: add feature",
+            "author": "John Doe",
+            "committed_date": "2024-01-15T10:30:00Z",
+            "repository": "test-repo"
+        }"#;
+
+        let commit: Commit = serde_json::from_str(json).expect("Failed to deserialize");
+
+        assert_eq!(commit.author_login(), None);
+        assert_eq!(commit.parent_count(), 0);
+    }
 }
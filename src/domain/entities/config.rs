@@ -1,4 +1,7 @@
 use crate::domain::entities::department::Department;
+use crate::domain::value_objects::commit_classifier::ClassificationRule;
+use crate::domain::value_objects::commit_filter::CommitFilter;
+use crate::domain::value_objects::gitmoji::GitmojiMapping;
 use crate::domain::value_objects::output_format::OutputFormat;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +15,14 @@ pub struct Config {
     default_output_format: OutputFormat,
     output_directory: String,
     departments: Vec<Department>,
+    #[serde(default)]
+    commit_filter: CommitFilter,
+    #[serde(default)]
+    classification_rules: Vec<ClassificationRule>,
+    #[serde(default)]
+    gitmoji_mappings: Vec<GitmojiMapping>,
+    #[serde(default)]
+    heuristic_classification: bool,
 }
 
 impl Config {
@@ -29,6 +40,10 @@ impl Config {
             default_output_format,
             output_directory,
             departments,
+            commit_filter: CommitFilter::default(),
+            classification_rules: Vec::new(),
+            gitmoji_mappings: Vec::new(),
+            heuristic_classification: false,
         }
     }
 
@@ -47,9 +62,41 @@ impl Config {
             default_output_format,
             output_directory,
             departments,
+            commit_filter: CommitFilter::default(),
+            classification_rules: Vec::new(),
+            gitmoji_mappings: Vec::new(),
+            heuristic_classification: false,
         }
     }
 
+    /// Sets the rules for dropping bot and merge commits
+    #[allow(dead_code)]
+    pub fn with_commit_filter(mut self, commit_filter: CommitFilter) -> Self {
+        self.commit_filter = commit_filter;
+        self
+    }
+
+    /// Sets the user-defined commit classification rules
+    #[allow(dead_code)]
+    pub fn with_classification_rules(mut self, rules: Vec<ClassificationRule>) -> Self {
+        self.classification_rules = rules;
+        self
+    }
+
+    /// Sets the user-defined gitmoji mappings
+    #[allow(dead_code)]
+    pub fn with_gitmoji_mappings(mut self, mappings: Vec<GitmojiMapping>) -> Self {
+        self.gitmoji_mappings = mappings;
+        self
+    }
+
+    /// Sets whether non-conforming commit subjects are classified by keyword heuristics
+    #[allow(dead_code)]
+    pub fn with_heuristic_classification(mut self, enabled: bool) -> Self {
+        self.heuristic_classification = enabled;
+        self
+    }
+
     /// Returns the target GitHub user
     #[allow(dead_code)] // Temporarily allowed during TDD implementation
     pub fn target_github_user(&self) -> Option<&str> {
@@ -79,6 +126,26 @@ impl Config {
     pub fn departments(&self) -> &[Department] {
         &self.departments
     }
+
+    /// Returns the rules for dropping bot and merge commits
+    pub fn commit_filter(&self) -> &CommitFilter {
+        &self.commit_filter
+    }
+
+    /// Returns the user-defined commit classification rules, in evaluation order
+    pub fn classification_rules(&self) -> &[ClassificationRule] {
+        &self.classification_rules
+    }
+
+    /// Returns the user-defined gitmoji mappings, consulted before the built-in table
+    pub fn gitmoji_mappings(&self) -> &[GitmojiMapping] {
+        &self.gitmoji_mappings
+    }
+
+    /// Returns whether non-conforming commit subjects are classified by keyword heuristics
+    pub fn heuristic_classification(&self) -> bool {
+        self.heuristic_classification
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,8 @@
+use crate::domain::value_objects::repository_filter::RepositoryFilter;
+use crate::domain::value_objects::source_mode::SourceMode;
+use crate::domain::value_objects::week_layout::WeekLayout;
+use crate::domain::value_objects::working_hours::WorkingHours;
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
 /// Represents a department in the organization
@@ -8,6 +13,23 @@ pub struct Department {
     fiscal_year_start_month: u32,
     github_organizations: Vec<String>,
     local_documents: Vec<String>,
+    #[serde(default)]
+    source_mode: SourceMode,
+    #[serde(default)]
+    repository_filter: RepositoryFilter,
+    #[serde(default)]
+    timezone: Tz,
+    #[serde(default)]
+    working_hours: WorkingHours,
+    #[serde(default = "default_fiscal_year_start_day")]
+    fiscal_year_start_day: u32,
+    #[serde(default)]
+    week_layout: Option<WeekLayout>,
+}
+
+/// Fiscal years start on the 1st of the start month unless configured otherwise
+fn default_fiscal_year_start_day() -> u32 {
+    1
 }
 
 impl Department {
@@ -34,9 +56,66 @@ impl Department {
             fiscal_year_start_month,
             github_organizations,
             local_documents,
+            source_mode: SourceMode::default(),
+            repository_filter: RepositoryFilter::default(),
+            timezone: Tz::UTC,
+            working_hours: WorkingHours::default(),
+            fiscal_year_start_day: default_fiscal_year_start_day(),
+            week_layout: None,
         }
     }
 
+    /// Sets the source mode used to collect GitHub activity
+    #[allow(dead_code)]
+    pub fn with_source_mode(mut self, source_mode: SourceMode) -> Self {
+        self.source_mode = source_mode;
+        self
+    }
+
+    /// Sets the filter selecting which repositories are collected
+    #[allow(dead_code)]
+    pub fn with_repository_filter(mut self, repository_filter: RepositoryFilter) -> Self {
+        self.repository_filter = repository_filter;
+        self
+    }
+
+    /// Sets the IANA timezone the department works in
+    #[allow(dead_code)]
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Sets the department's working hours
+    #[allow(dead_code)]
+    pub fn with_working_hours(mut self, working_hours: WorkingHours) -> Self {
+        self.working_hours = working_hours;
+        self
+    }
+
+    /// Sets the day of the start month the fiscal year starts on
+    ///
+    /// # Panics
+    ///
+    /// Panics if fiscal_year_start_day is not between 1 and 28
+    #[allow(dead_code)]
+    pub fn with_fiscal_year_start_day(mut self, fiscal_year_start_day: u32) -> Self {
+        assert!(
+            (1..=28).contains(&fiscal_year_start_day),
+            "Fiscal year start day must be between 1 and 28, got {}",
+            fiscal_year_start_day
+        );
+        self.fiscal_year_start_day = fiscal_year_start_day;
+        self
+    }
+
+    /// Switches the department to a 52/53-week fiscal calendar
+    #[allow(dead_code)]
+    pub fn with_week_layout(mut self, week_layout: WeekLayout) -> Self {
+        self.week_layout = Some(week_layout);
+        self
+    }
+
     /// Returns the name of the department
     #[allow(dead_code)] // Temporarily allowed during TDD implementation
     pub fn name(&self) -> &str {
@@ -60,6 +139,36 @@ impl Department {
     pub fn local_documents(&self) -> &[String] {
         &self.local_documents
     }
+
+    /// Returns the source mode used to collect GitHub activity
+    pub fn source_mode(&self) -> SourceMode {
+        self.source_mode
+    }
+
+    /// Returns the filter selecting which repositories are collected
+    pub fn repository_filter(&self) -> &RepositoryFilter {
+        &self.repository_filter
+    }
+
+    /// Returns the IANA timezone the department works in (UTC by default)
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns the department's working hours
+    pub fn working_hours(&self) -> WorkingHours {
+        self.working_hours
+    }
+
+    /// Returns the day of the start month the fiscal year starts on (1 by default)
+    pub fn fiscal_year_start_day(&self) -> u32 {
+        self.fiscal_year_start_day
+    }
+
+    /// Returns the week layout for 52/53-week fiscal calendars, if any
+    pub fn week_layout(&self) -> Option<WeekLayout> {
+        self.week_layout
+    }
 }
 
 #[cfg(test)]
@@ -82,6 +191,24 @@ mod tests {
             &vec!["connect0459".to_string()]
         );
         assert_eq!(department.local_documents(), &Vec::<String>::new());
+        assert_eq!(department.source_mode(), SourceMode::Repositories);
+        assert_eq!(department.timezone(), Tz::UTC);
+        assert_eq!(department.working_hours(), WorkingHours::default());
+        assert_eq!(department.fiscal_year_start_day(), 1);
+        assert_eq!(department.week_layout(), None);
+    }
+
+    #[test]
+    fn creates_department_with_contributions_source_mode() {
+        let department = Department::new(
+            "Personal".to_string(),
+            4,
+            vec!["connect0459".to_string()],
+            vec![],
+        )
+        .with_source_mode(SourceMode::Contributions);
+
+        assert_eq!(department.source_mode(), SourceMode::Contributions);
     }
 
     #[test]
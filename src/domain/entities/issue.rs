@@ -0,0 +1,271 @@
+use crate::domain::value_objects::issue_state::IssueState;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Represents a change of an issue's state (closed or reopened)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueStateTransition {
+    state: IssueState,
+    actor: String,
+    at: DateTime<Utc>,
+}
+
+impl IssueStateTransition {
+    /// Creates a new IssueStateTransition instance
+    pub fn new(state: IssueState, actor: String, at: DateTime<Utc>) -> Self {
+        Self { state, actor, at }
+    }
+
+    /// Returns the state after the transition
+    pub fn state(&self) -> IssueState {
+        self.state
+    }
+
+    /// Returns the login of the user who changed the state
+    pub fn actor(&self) -> &str {
+        &self.actor
+    }
+
+    /// Returns the date of the transition
+    pub fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+}
+
+/// Represents a comment left on an issue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueComment {
+    author: String,
+    created_at: DateTime<Utc>,
+}
+
+impl IssueComment {
+    /// Creates a new IssueComment instance
+    pub fn new(author: String, created_at: DateTime<Utc>) -> Self {
+        Self { author, created_at }
+    }
+
+    /// Returns the comment author login
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    /// Returns the comment date
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// Represents a GitHub issue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Issue {
+    number: u32,
+    title: String,
+    repository: String,
+    author: String,
+    state: IssueState,
+    created_at: DateTime<Utc>,
+    closed_at: Option<DateTime<Utc>>,
+    labels: Vec<String>,
+    transitions: Vec<IssueStateTransition>,
+    comments: Vec<IssueComment>,
+}
+
+impl Issue {
+    /// Creates a new Issue instance without labels, transitions or comments
+    pub fn new(
+        number: u32,
+        title: String,
+        repository: String,
+        author: String,
+        state: IssueState,
+        created_at: DateTime<Utc>,
+        closed_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            number,
+            title,
+            repository,
+            author,
+            state,
+            created_at,
+            closed_at,
+            labels: Vec::new(),
+            transitions: Vec::new(),
+            comments: Vec::new(),
+        }
+    }
+
+    /// Sets the label names
+    pub fn with_labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the state transitions, ordered by date
+    pub fn with_transitions(mut self, mut transitions: Vec<IssueStateTransition>) -> Self {
+        transitions.sort_by_key(|transition| transition.at());
+        self.transitions = transitions;
+        self
+    }
+
+    /// Sets the comments
+    pub fn with_comments(mut self, comments: Vec<IssueComment>) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Returns the issue number
+    #[allow(dead_code)]
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Returns the title
+    #[allow(dead_code)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the repository name with owner
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the author login
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    /// Returns the current state
+    #[allow(dead_code)]
+    pub fn state(&self) -> IssueState {
+        self.state
+    }
+
+    /// Returns the creation date
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the close date, if currently closed
+    #[allow(dead_code)]
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.closed_at
+    }
+
+    /// Returns the label names
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Returns the state transitions ordered by date
+    pub fn transitions(&self) -> &[IssueStateTransition] {
+        &self.transitions
+    }
+
+    /// Returns the comments
+    pub fn comments(&self) -> &[IssueComment] {
+        &self.comments
+    }
+
+    /// Returns the state of the issue at the given point in time
+    ///
+    /// Falls back to the current state and close date when no transitions are known.
+    pub fn state_at(&self, at: DateTime<Utc>) -> IssueState {
+        if self.transitions.is_empty() {
+            return match self.closed_at {
+                Some(closed_at) if closed_at <= at => IssueState::Closed,
+                _ => IssueState::Open,
+            };
+        }
+
+        self.transitions
+            .iter()
+            .take_while(|transition| transition.at() <= at)
+            .last()
+            .map(|transition| transition.state())
+            .unwrap_or(IssueState::Open)
+    }
+
+    /// Returns the time from creation to close, if currently closed
+    pub fn time_to_close(&self) -> Option<Duration> {
+        match self.state {
+            IssueState::Closed => self.closed_at.map(|closed_at| closed_at - self.created_at),
+            IssueState::Open => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn issue() -> Issue {
+        Issue::new(
+            7,
+            "Crash on startup".to_string(),
+            "connect0459/nenpo".to_string(),
+            "octocat".to_string(),
+            IssueState::Closed,
+            Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2024, 5, 10, 0, 0, 0).unwrap()),
+        )
+    }
+
+    #[test]
+    fn creates_issue() {
+        let issue = issue().with_labels(vec!["bug".to_string()]);
+
+        assert_eq!(issue.number(), 7);
+        assert_eq!(issue.title(), "Crash on startup");
+        assert_eq!(issue.repository(), "connect0459/nenpo");
+        assert_eq!(issue.author(), "octocat");
+        assert_eq!(issue.state(), IssueState::Closed);
+        assert_eq!(issue.labels(), &["bug".to_string()]);
+        assert_eq!(issue.time_to_close(), Some(Duration::days(9)));
+    }
+
+    #[test]
+    fn replays_state_transitions() {
+        let issue = issue().with_transitions(vec![
+            IssueStateTransition::new(
+                IssueState::Closed,
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 10, 0, 0, 0).unwrap(),
+            ),
+            IssueStateTransition::new(
+                IssueState::Open,
+                "octocat".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 5, 0, 0, 0).unwrap(),
+            ),
+            IssueStateTransition::new(
+                IssueState::Closed,
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 3, 0, 0, 0).unwrap(),
+            ),
+        ]);
+
+        let at = |day| Utc.with_ymd_and_hms(2024, 5, day, 12, 0, 0).unwrap();
+        assert_eq!(issue.state_at(at(2)), IssueState::Open);
+        assert_eq!(issue.state_at(at(4)), IssueState::Closed);
+        assert_eq!(issue.state_at(at(6)), IssueState::Open);
+        assert_eq!(issue.state_at(at(11)), IssueState::Closed);
+        assert_eq!(issue.transitions()[0].actor(), "connect0459");
+    }
+
+    #[test]
+    fn falls_back_to_close_date_without_transitions() {
+        let issue = issue();
+
+        assert_eq!(
+            issue.state_at(Utc.with_ymd_and_hms(2024, 5, 9, 0, 0, 0).unwrap()),
+            IssueState::Open
+        );
+        assert_eq!(
+            issue.state_at(Utc.with_ymd_and_hms(2024, 5, 10, 0, 0, 0).unwrap()),
+            IssueState::Closed
+        );
+    }
+}
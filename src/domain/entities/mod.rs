@@ -3,4 +3,11 @@ pub mod config;
 pub mod department;
 pub mod document_content;
 pub mod github_activity;
+pub mod issue;
+pub mod pull_request;
+pub mod release;
 pub mod report;
+pub mod repository_contribution;
+pub mod repository_languages;
+pub mod review;
+pub mod trend_report;
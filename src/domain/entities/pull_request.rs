@@ -0,0 +1,214 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Represents a GitHub pull request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequest {
+    number: u32,
+    title: String,
+    repository: String,
+    author: String,
+    created_at: DateTime<Utc>,
+    merged_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    additions: u32,
+    deletions: u32,
+    labels: Vec<String>,
+    review_count: u32,
+    first_review_at: Option<DateTime<Utc>>,
+}
+
+impl PullRequest {
+    /// Creates a new open PullRequest instance without changes, labels or reviews
+    pub fn new(
+        number: u32,
+        title: String,
+        repository: String,
+        author: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            number,
+            title,
+            repository,
+            author,
+            created_at,
+            merged_at: None,
+            closed_at: None,
+            additions: 0,
+            deletions: 0,
+            labels: Vec::new(),
+            review_count: 0,
+            first_review_at: None,
+        }
+    }
+
+    /// Sets the merged and closed dates
+    pub fn with_lifecycle(
+        mut self,
+        merged_at: Option<DateTime<Utc>>,
+        closed_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.merged_at = merged_at;
+        self.closed_at = closed_at;
+        self
+    }
+
+    /// Sets the number of added and deleted lines
+    pub fn with_changes(mut self, additions: u32, deletions: u32) -> Self {
+        self.additions = additions;
+        self.deletions = deletions;
+        self
+    }
+
+    /// Sets the label names
+    pub fn with_labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the review count and the date of the first review
+    pub fn with_reviews(
+        mut self,
+        review_count: u32,
+        first_review_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.review_count = review_count;
+        self.first_review_at = first_review_at;
+        self
+    }
+
+    /// Returns the pull request number
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Returns the title
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the repository name with owner
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the author login
+    #[allow(dead_code)]
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    /// Returns the creation date
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the merge date, if merged
+    pub fn merged_at(&self) -> Option<DateTime<Utc>> {
+        self.merged_at
+    }
+
+    /// Returns the close date, if closed
+    #[allow(dead_code)]
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.closed_at
+    }
+
+    /// Returns the number of added lines
+    pub fn additions(&self) -> u32 {
+        self.additions
+    }
+
+    /// Returns the number of deleted lines
+    pub fn deletions(&self) -> u32 {
+        self.deletions
+    }
+
+    /// Returns the label names
+    #[allow(dead_code)]
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Returns the number of reviews
+    #[allow(dead_code)]
+    pub fn review_count(&self) -> u32 {
+        self.review_count
+    }
+
+    /// Returns the date of the first review, if reviewed
+    #[allow(dead_code)]
+    pub fn first_review_at(&self) -> Option<DateTime<Utc>> {
+        self.first_review_at
+    }
+
+    /// Returns the total number of changed lines
+    pub fn size(&self) -> u32 {
+        self.additions + self.deletions
+    }
+
+    /// Returns the time from creation to merge, if merged
+    pub fn cycle_time(&self) -> Option<Duration> {
+        self.merged_at.map(|merged_at| merged_at - self.created_at)
+    }
+
+    /// Returns the time from creation to the first review, if reviewed
+    pub fn time_to_first_review(&self) -> Option<Duration> {
+        self.first_review_at
+            .map(|first_review_at| first_review_at - self.created_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn creates_pull_request() {
+        let created_at = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        let pull_request = PullRequest::new(
+            42,
+            "feat: add report".to_string(),
+            "connect0459/nenpo".to_string(),
+            "connect0459".to_string(),
+            created_at,
+        )
+        .with_changes(120, 30)
+        .with_labels(vec!["enhancement".to_string()]);
+
+        assert_eq!(pull_request.number(), 42);
+        assert_eq!(pull_request.title(), "feat: add report");
+        assert_eq!(pull_request.repository(), "connect0459/nenpo");
+        assert_eq!(pull_request.author(), "connect0459");
+        assert_eq!(pull_request.created_at(), created_at);
+        assert_eq!(pull_request.size(), 150);
+        assert_eq!(pull_request.labels(), &["enhancement".to_string()]);
+        assert_eq!(pull_request.merged_at(), None);
+        assert_eq!(pull_request.cycle_time(), None);
+    }
+
+    #[test]
+    fn calculates_cycle_time_and_time_to_first_review() {
+        let created_at = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        let reviewed_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let merged_at = Utc.with_ymd_and_hms(2024, 5, 2, 9, 0, 0).unwrap();
+        let pull_request = PullRequest::new(
+            42,
+            "feat: add report".to_string(),
+            "connect0459/nenpo".to_string(),
+            "connect0459".to_string(),
+            created_at,
+        )
+        .with_lifecycle(Some(merged_at), Some(merged_at))
+        .with_reviews(2, Some(reviewed_at));
+
+        assert_eq!(pull_request.cycle_time(), Some(Duration::hours(24)));
+        assert_eq!(
+            pull_request.time_to_first_review(),
+            Some(Duration::hours(3))
+        );
+        assert_eq!(pull_request.review_count(), 2);
+    }
+}
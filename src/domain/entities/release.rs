@@ -0,0 +1,162 @@
+use crate::domain::entities::commit::Commit;
+use crate::domain::value_objects::commit_classifier::CommitClassifier;
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Represents a GitHub release or a tag without a release
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Release {
+    repository: String,
+    name: String,
+    tag_name: String,
+    published_at: DateTime<Utc>,
+    is_prerelease: bool,
+    #[serde(default)]
+    themes: HashMap<CommitTheme, u32>,
+}
+
+impl Release {
+    /// Creates a new Release instance without commit themes
+    pub fn new(
+        repository: String,
+        name: String,
+        tag_name: String,
+        published_at: DateTime<Utc>,
+        is_prerelease: bool,
+    ) -> Self {
+        Self {
+            repository,
+            name,
+            tag_name,
+            published_at,
+            is_prerelease,
+            themes: HashMap::new(),
+        }
+    }
+
+    /// Sets the themes of the commits shipped in this release
+    pub fn with_themes(mut self, themes: HashMap<CommitTheme, u32>) -> Self {
+        self.themes = themes;
+        self
+    }
+
+    /// Returns the repository name with owner (e.g., "owner/repo")
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the release name (the tag name for tags without a release)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the tag name
+    pub fn tag_name(&self) -> &str {
+        &self.tag_name
+    }
+
+    /// Returns the publication date (the tag date for tags without a release)
+    pub fn published_at(&self) -> DateTime<Utc> {
+        self.published_at
+    }
+
+    /// Returns whether the release is marked as a prerelease
+    pub fn is_prerelease(&self) -> bool {
+        self.is_prerelease
+    }
+
+    /// Returns the themes of the commits shipped in this release
+    pub fn themes(&self) -> &HashMap<CommitTheme, u32> {
+        &self.themes
+    }
+
+    /// Attaches commit themes to each release
+    ///
+    /// A release ships the commits of its repository made after the previous
+    /// release and up to its own publication date. The first release of a
+    /// repository ships all earlier commits in `commits`.
+    pub fn attach_themes(
+        mut releases: Vec<Release>,
+        commits: &[Commit],
+        classifier: &CommitClassifier,
+    ) -> Vec<Release> {
+        releases.sort_by(|a, b| {
+            a.repository
+                .to_lowercase()
+                .cmp(&b.repository.to_lowercase())
+                .then(a.published_at.cmp(&b.published_at))
+        });
+
+        let mut previous: Option<(String, DateTime<Utc>)> = None;
+        releases
+            .into_iter()
+            .map(|release| {
+                let repository = release.repository.to_lowercase();
+                let since = previous
+                    .as_ref()
+                    .filter(|(prev_repository, _)| *prev_repository == repository)
+                    .map(|(_, at)| *at);
+
+                let mut themes = HashMap::new();
+                for commit in commits {
+                    let date = commit.committed_date();
+                    if commit
+                        .repository()
+                        .eq_ignore_ascii_case(&release.repository)
+                        && since.is_none_or(|since| date > since)
+                        && date <= release.published_at
+                    {
+                        let theme = classifier.classify(commit.message());
+                        *themes.entry(theme).or_insert(0) += 1;
+                    }
+                }
+
+                previous = Some((repository, release.published_at));
+                release.with_themes(themes)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn release(repository: &str, tag: &str, day: u32) -> Release {
+        Release::new(
+            repository.to_string(),
+            tag.to_string(),
+            tag.to_string(),
+            Utc.with_ymd_and_hms(2024, 5, day, 18, 0, 0).unwrap(),
+            false,
+        )
+    }
+
+    #[test]
+    fn attaches_themes_of_commits_between_consecutive_releases() {
+        let commits = vec![
+            Commit::fixture("a", "feat: add login", "org/app", "2024-05-01T12:00:00Z"),
+            Commit::fixture("b", "fix: login crash", "org/app", "2024-05-02T12:00:00Z"),
+            Commit::fixture("c", "feat: add logout", "org/app", "2024-05-03T12:00:00Z"),
+            Commit::fixture("d", "docs: readme", "org/lib", "2024-05-03T12:00:00Z"),
+            Commit::fixture("e", "feat: unreleased", "org/app", "2024-05-10T12:00:00Z"),
+        ];
+        let releases = vec![
+            release("org/app", "v1.1.0", 5),
+            release("org/app", "v1.0.0", 2),
+        ];
+
+        let releases = Release::attach_themes(releases, &commits, &CommitClassifier::default());
+
+        assert_eq!(releases[0].tag_name(), "v1.0.0");
+        assert_eq!(releases[0].themes().get(&CommitTheme::Feat), Some(&1));
+        assert_eq!(releases[0].themes().get(&CommitTheme::Fix), Some(&1));
+        assert_eq!(releases[1].tag_name(), "v1.1.0");
+        assert_eq!(releases[1].themes().get(&CommitTheme::Feat), Some(&1));
+        assert_eq!(releases[1].themes().get(&CommitTheme::Fix), None);
+        assert_eq!(releases[1].themes().get(&CommitTheme::Docs), None);
+    }
+}
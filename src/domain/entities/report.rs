@@ -1,7 +1,25 @@
 use crate::domain::entities::document_content::DocumentContent;
 use crate::domain::entities::github_activity::GitHubActivity;
-use crate::domain::value_objects::commit_theme::CommitTheme;
-use chrono::NaiveDate;
+use crate::domain::entities::release::Release;
+use crate::domain::entities::repository_contribution::RepositoryContribution;
+use crate::domain::value_objects::activity_breakdown::ActivityBreakdown;
+use crate::domain::value_objects::activity_timeline::ActivityTimeline;
+use crate::domain::value_objects::classification_summary::ClassificationSummary;
+use crate::domain::value_objects::commit_filter::ExcludedCommits;
+use crate::domain::value_objects::commit_theme::{CommitTheme, CustomTheme};
+use crate::domain::value_objects::commit_time_distribution::CommitTimeDistribution;
+use crate::domain::value_objects::comparison::Comparison;
+use crate::domain::value_objects::contribution_calendar::ContributionCalendar;
+use crate::domain::value_objects::conventional_commit::{BreakingChange, ScopeCount};
+use crate::domain::value_objects::issue_summary::IssueSummary;
+use crate::domain::value_objects::language_share::LanguageShare;
+use crate::domain::value_objects::period_kind::PeriodKind;
+use crate::domain::value_objects::pull_request_summary::PullRequestSummary;
+use crate::domain::value_objects::repository_mirror::RepositoryMirror;
+use crate::domain::value_objects::revert::Revert;
+use crate::domain::value_objects::review_summary::ReviewSummary;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -16,6 +34,53 @@ pub struct Report {
     github_activity: GitHubActivity,
     documents: Vec<DocumentContent>,
     theme_summary: HashMap<CommitTheme, u32>,
+    #[serde(default)]
+    contributions: Vec<RepositoryContribution>,
+    #[serde(default)]
+    pull_request_summary: Option<PullRequestSummary>,
+    #[serde(default)]
+    review_summary: Option<ReviewSummary>,
+    #[serde(default)]
+    issue_summary: Option<IssueSummary>,
+    #[serde(default)]
+    releases: Vec<Release>,
+    #[serde(default)]
+    languages: Vec<LanguageShare>,
+    #[serde(default)]
+    excluded_commits: ExcludedCommits,
+    #[serde(default)]
+    duplicate_commits: u32,
+    #[serde(default)]
+    repository_mirrors: Vec<RepositoryMirror>,
+    #[serde(default)]
+    breaking_changes: Vec<BreakingChange>,
+    #[serde(default)]
+    scope_summary: Vec<ScopeCount>,
+    #[serde(default)]
+    classification_summary: ClassificationSummary,
+    #[serde(default)]
+    reverts: Vec<Revert>,
+    #[serde(default)]
+    organization_breakdown: Vec<ActivityBreakdown>,
+    #[serde(default)]
+    repository_breakdown: Vec<ActivityBreakdown>,
+    #[serde(default)]
+    timeline: ActivityTimeline,
+    #[serde(default)]
+    contribution_calendar: ContributionCalendar,
+    #[serde(default)]
+    commit_time_distribution: CommitTimeDistribution,
+    #[serde(default)]
+    timezone: Tz,
+    #[serde(default)]
+    period_kind: PeriodKind,
+    #[serde(default)]
+    period_label: String,
+    #[serde(default)]
+    comparison: Option<Comparison>,
+    /// Custom categories in the theme summary, whose map keys only keep the key
+    #[serde(default)]
+    custom_themes: Vec<CustomTheme>,
 }
 
 impl Report {
@@ -30,6 +95,14 @@ impl Report {
         documents: Vec<DocumentContent>,
         theme_summary: HashMap<CommitTheme, u32>,
     ) -> Self {
+        let mut custom_themes: Vec<CustomTheme> = theme_summary
+            .keys()
+            .filter_map(|theme| match theme {
+                CommitTheme::Custom(custom) => Some(custom.clone()),
+                _ => None,
+            })
+            .collect();
+        custom_themes.sort_by_key(|custom| custom.order());
         Self {
             year,
             department_name,
@@ -38,9 +111,210 @@ impl Report {
             github_activity,
             documents,
             theme_summary,
+            contributions: Vec::new(),
+            pull_request_summary: None,
+            review_summary: None,
+            issue_summary: None,
+            releases: Vec::new(),
+            languages: Vec::new(),
+            excluded_commits: ExcludedCommits::default(),
+            duplicate_commits: 0,
+            repository_mirrors: Vec::new(),
+            breaking_changes: Vec::new(),
+            scope_summary: Vec::new(),
+            classification_summary: ClassificationSummary::default(),
+            reverts: Vec::new(),
+            organization_breakdown: Vec::new(),
+            repository_breakdown: Vec::new(),
+            timeline: ActivityTimeline::default(),
+            contribution_calendar: ContributionCalendar::default(),
+            commit_time_distribution: CommitTimeDistribution::default(),
+            timezone: Tz::UTC,
+            period_kind: PeriodKind::FiscalYear,
+            period_label: PeriodKind::FiscalYear.label(year),
+            comparison: None,
+            custom_themes,
         }
     }
 
+    /// Restores the display names and order of custom categories after loading from JSON
+    ///
+    /// Theme summary keys are written as bare keys, so they load as custom
+    /// categories named after the key and ordered last; the report's
+    /// `custom_themes` list puts the configured names and order back.
+    pub fn restore_custom_themes(mut self) -> Self {
+        self.theme_summary = self
+            .theme_summary
+            .into_iter()
+            .map(|(theme, count)| {
+                let theme = match theme {
+                    CommitTheme::Custom(custom) => CommitTheme::Custom(
+                        self.custom_themes
+                            .iter()
+                            .find(|known| known.key() == custom.key())
+                            .cloned()
+                            .unwrap_or(custom),
+                    ),
+                    theme => theme,
+                };
+                (theme, count)
+            })
+            .collect();
+        self
+    }
+
+    /// Sets the per-repository contribution breakdown
+    #[allow(dead_code)]
+    pub fn with_contributions(mut self, contributions: Vec<RepositoryContribution>) -> Self {
+        self.contributions = contributions;
+        self
+    }
+
+    /// Sets the pull request lifecycle summary
+    #[allow(dead_code)]
+    pub fn with_pull_request_summary(mut self, summary: PullRequestSummary) -> Self {
+        self.pull_request_summary = Some(summary);
+        self
+    }
+
+    /// Sets the code review summary
+    #[allow(dead_code)]
+    pub fn with_review_summary(mut self, summary: ReviewSummary) -> Self {
+        self.review_summary = Some(summary);
+        self
+    }
+
+    /// Sets the issue summary
+    #[allow(dead_code)]
+    pub fn with_issue_summary(mut self, summary: IssueSummary) -> Self {
+        self.issue_summary = Some(summary);
+        self
+    }
+
+    /// Sets the releases shipped within the period
+    #[allow(dead_code)]
+    pub fn with_releases(mut self, releases: Vec<Release>) -> Self {
+        self.releases = releases;
+        self
+    }
+
+    /// Sets the languages the user's commits were written in
+    #[allow(dead_code)]
+    pub fn with_languages(mut self, languages: Vec<LanguageShare>) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    /// Sets the counts of commits dropped by the commit filter
+    #[allow(dead_code)]
+    pub fn with_excluded_commits(mut self, excluded_commits: ExcludedCommits) -> Self {
+        self.excluded_commits = excluded_commits;
+        self
+    }
+
+    /// Sets the number of duplicate commits collapsed by SHA
+    #[allow(dead_code)]
+    pub fn with_duplicate_commits(mut self, duplicate_commits: u32) -> Self {
+        self.duplicate_commits = duplicate_commits;
+        self
+    }
+
+    /// Sets the repositories whose commits were also found in forks or mirrors
+    #[allow(dead_code)]
+    pub fn with_repository_mirrors(mut self, repository_mirrors: Vec<RepositoryMirror>) -> Self {
+        self.repository_mirrors = repository_mirrors;
+        self
+    }
+
+    /// Sets the breaking changes introduced by the user's commits
+    #[allow(dead_code)]
+    pub fn with_breaking_changes(mut self, breaking_changes: Vec<BreakingChange>) -> Self {
+        self.breaking_changes = breaking_changes;
+        self
+    }
+
+    /// Sets the per-scope commit counts
+    #[allow(dead_code)]
+    pub fn with_scope_summary(mut self, scope_summary: Vec<ScopeCount>) -> Self {
+        self.scope_summary = scope_summary;
+        self
+    }
+
+    /// Sets how the commits were classified into themes
+    #[allow(dead_code)]
+    pub fn with_classification_summary(mut self, summary: ClassificationSummary) -> Self {
+        self.classification_summary = summary;
+        self
+    }
+
+    /// Sets the revert commits made within the period
+    #[allow(dead_code)]
+    pub fn with_reverts(mut self, reverts: Vec<Revert>) -> Self {
+        self.reverts = reverts;
+        self
+    }
+
+    /// Sets the per-organization and per-repository activity breakdowns
+    #[allow(dead_code)]
+    pub fn with_breakdowns(
+        mut self,
+        organization_breakdown: Vec<ActivityBreakdown>,
+        repository_breakdown: Vec<ActivityBreakdown>,
+    ) -> Self {
+        self.organization_breakdown = organization_breakdown;
+        self.repository_breakdown = repository_breakdown;
+        self
+    }
+
+    /// Sets the monthly and weekly activity timeline
+    #[allow(dead_code)]
+    pub fn with_timeline(mut self, timeline: ActivityTimeline) -> Self {
+        self.timeline = timeline;
+        self
+    }
+
+    /// Sets the per-day contribution calendar
+    #[allow(dead_code)]
+    pub fn with_contribution_calendar(
+        mut self,
+        contribution_calendar: ContributionCalendar,
+    ) -> Self {
+        self.contribution_calendar = contribution_calendar;
+        self
+    }
+
+    /// Sets the hour-of-day and weekday distribution of commit times
+    #[allow(dead_code)]
+    pub fn with_commit_time_distribution(
+        mut self,
+        commit_time_distribution: CommitTimeDistribution,
+    ) -> Self {
+        self.commit_time_distribution = commit_time_distribution;
+        self
+    }
+
+    /// Sets which part of the fiscal year the report covers, with its label (e.g., "FY2024-Q3")
+    #[allow(dead_code)]
+    pub fn with_period(mut self, period_kind: PeriodKind, period_label: String) -> Self {
+        self.period_kind = period_kind;
+        self.period_label = period_label;
+        self
+    }
+
+    /// Sets the timezone the period and dates are reported in
+    #[allow(dead_code)]
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Sets the comparison with the same period of the previous fiscal year
+    #[allow(dead_code)]
+    pub fn with_comparison(mut self, comparison: Comparison) -> Self {
+        self.comparison = Some(comparison);
+        self
+    }
+
     /// Returns the year
     #[allow(dead_code)] // Temporarily allowed during TDD implementation
     pub fn year(&self) -> u32 {
@@ -82,6 +356,121 @@ impl Report {
     pub fn theme_summary(&self) -> &HashMap<CommitTheme, u32> {
         &self.theme_summary
     }
+
+    /// Returns the per-repository contribution breakdown
+    pub fn contributions(&self) -> &[RepositoryContribution] {
+        &self.contributions
+    }
+
+    /// Returns the pull request lifecycle summary
+    pub fn pull_request_summary(&self) -> Option<&PullRequestSummary> {
+        self.pull_request_summary.as_ref()
+    }
+
+    /// Returns the code review summary
+    pub fn review_summary(&self) -> Option<&ReviewSummary> {
+        self.review_summary.as_ref()
+    }
+
+    /// Returns the issue summary
+    pub fn issue_summary(&self) -> Option<&IssueSummary> {
+        self.issue_summary.as_ref()
+    }
+
+    /// Returns the releases shipped within the period
+    pub fn releases(&self) -> &[Release] {
+        &self.releases
+    }
+
+    /// Returns the languages the user's commits were written in, largest share first
+    pub fn languages(&self) -> &[LanguageShare] {
+        &self.languages
+    }
+
+    /// Returns the counts of commits dropped by the commit filter
+    pub fn excluded_commits(&self) -> ExcludedCommits {
+        self.excluded_commits
+    }
+
+    /// Returns the number of duplicate commits collapsed by SHA
+    pub fn duplicate_commits(&self) -> u32 {
+        self.duplicate_commits
+    }
+
+    /// Returns the repositories whose commits were also found in forks or mirrors
+    pub fn repository_mirrors(&self) -> &[RepositoryMirror] {
+        &self.repository_mirrors
+    }
+
+    /// Returns the breaking changes introduced by the user's commits, oldest first
+    pub fn breaking_changes(&self) -> &[BreakingChange] {
+        &self.breaking_changes
+    }
+
+    /// Returns the per-scope commit counts, most frequent first
+    pub fn scope_summary(&self) -> &[ScopeCount] {
+        &self.scope_summary
+    }
+
+    /// Returns how the commits were classified into themes
+    pub fn classification_summary(&self) -> &ClassificationSummary {
+        &self.classification_summary
+    }
+
+    /// Returns the revert commits made within the period, oldest first
+    pub fn reverts(&self) -> &[Revert] {
+        &self.reverts
+    }
+
+    /// Returns the activity per organization, most active first
+    pub fn organization_breakdown(&self) -> &[ActivityBreakdown] {
+        &self.organization_breakdown
+    }
+
+    /// Returns the activity per repository, most active first
+    pub fn repository_breakdown(&self) -> &[ActivityBreakdown] {
+        &self.repository_breakdown
+    }
+
+    /// Returns the activity per fiscal month and ISO week
+    pub fn timeline(&self) -> &ActivityTimeline {
+        &self.timeline
+    }
+
+    /// Returns the contributions per day with streaks
+    pub fn contribution_calendar(&self) -> &ContributionCalendar {
+        &self.contribution_calendar
+    }
+
+    /// Returns when commits were made, in the department's timezone
+    pub fn commit_time_distribution(&self) -> &CommitTimeDistribution {
+        &self.commit_time_distribution
+    }
+
+    /// Returns the timezone the period and dates are reported in
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns which part of the fiscal year the report covers
+    pub fn period_kind(&self) -> PeriodKind {
+        self.period_kind
+    }
+
+    /// Returns the period label (e.g., "FY2024" or "FY2024-Q3")
+    pub fn period_label(&self) -> &str {
+        &self.period_label
+    }
+
+    /// Returns the comparison with the previous fiscal year, if requested
+    pub fn comparison(&self) -> Option<&Comparison> {
+        self.comparison.as_ref()
+    }
+
+    /// Returns the day an instant falls on in the report's timezone
+    pub fn local_date(&self, at: DateTime<Utc>) -> NaiveDate {
+        at.with_timezone(&self.timezone).date_naive()
+    }
 }
 
 #[cfg(test)]
@@ -112,6 +501,39 @@ mod tests {
         assert_eq!(report.documents().len(), 0);
     }
 
+    #[test]
+    fn restores_custom_themes_after_json_round_trip() {
+        let ticket = CustomTheme::new("ticket".to_string(), "Ticket Work".to_string(), 1);
+        let report = Report::new(
+            2024,
+            "Personal".to_string(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date"),
+            NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date"),
+            GitHubActivity::new(0, 0, 0, 0),
+            vec![],
+            HashMap::from([(CommitTheme::Custom(ticket), 3), (CommitTheme::Feat, 2)]),
+        );
+
+        let json = serde_json::to_string(&report).expect("Failed to serialize");
+        let loaded: Report = serde_json::from_str(&json).expect("Failed to deserialize");
+        let raw = loaded
+            .theme_summary()
+            .keys()
+            .find(|t| t.short_name() == "ticket");
+        assert_eq!(raw.map(CommitTheme::label), Some("ticket"));
+
+        let restored = loaded.restore_custom_themes();
+        let theme = restored
+            .theme_summary()
+            .keys()
+            .find(|t| t.short_name() == "ticket")
+            .expect("Missing custom theme");
+        assert_eq!(theme.label(), "Ticket Work");
+        assert_eq!(theme.sort_order(), (1, 1));
+        assert_eq!(restored.theme_summary().get(theme), Some(&3));
+        assert_eq!(restored.theme_summary().get(&CommitTheme::Feat), Some(&2));
+    }
+
     #[test]
     fn creates_report_with_documents() {
         let activity = GitHubActivity::new(100, 20, 15, 30);
@@ -137,4 +559,31 @@ mod tests {
         assert_eq!(report.documents()[0].file_path(), "doc1.md");
         assert_eq!(report.documents()[1].file_path(), "doc2.md");
     }
+
+    #[test]
+    fn creates_report_with_contributions() {
+        let activity = GitHubActivity::new(100, 20, 15, 30);
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+
+        let report = Report::new(
+            2024,
+            "Personal".to_string(),
+            from,
+            to,
+            activity,
+            vec![],
+            HashMap::new(),
+        )
+        .with_contributions(vec![RepositoryContribution::new(
+            "rust-lang/rust".to_string(),
+            5,
+            1,
+            0,
+            2,
+        )]);
+
+        assert_eq!(report.contributions().len(), 1);
+        assert_eq!(report.contributions()[0].repository(), "rust-lang/rust");
+    }
 }
@@ -0,0 +1,110 @@
+use crate::domain::entities::github_activity::GitHubActivity;
+use serde::{Deserialize, Serialize};
+
+/// Represents a user's contributions to a single repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryContribution {
+    repository: String,
+    commits: u32,
+    pull_requests: u32,
+    issues: u32,
+    reviews: u32,
+}
+
+impl RepositoryContribution {
+    /// Creates a new RepositoryContribution instance
+    ///
+    /// `repository` is the full name with owner (e.g. `rust-lang/rust`)
+    pub fn new(
+        repository: String,
+        commits: u32,
+        pull_requests: u32,
+        issues: u32,
+        reviews: u32,
+    ) -> Self {
+        Self {
+            repository,
+            commits,
+            pull_requests,
+            issues,
+            reviews,
+        }
+    }
+
+    /// Returns the repository name with owner
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the owner part of the repository name
+    pub fn owner(&self) -> &str {
+        self.repository
+            .split_once('/')
+            .map(|(owner, _)| owner)
+            .unwrap_or(&self.repository)
+    }
+
+    /// Returns the number of commits
+    pub fn commits(&self) -> u32 {
+        self.commits
+    }
+
+    /// Returns the number of pull requests
+    pub fn pull_requests(&self) -> u32 {
+        self.pull_requests
+    }
+
+    /// Returns the number of issues
+    pub fn issues(&self) -> u32 {
+        self.issues
+    }
+
+    /// Returns the number of reviews
+    pub fn reviews(&self) -> u32 {
+        self.reviews
+    }
+
+    /// Returns whether the repository is owned by none of the given organizations/users
+    pub fn is_external_to(&self, owners: &[String]) -> bool {
+        !owners
+            .iter()
+            .any(|owner| owner.eq_ignore_ascii_case(self.owner()))
+    }
+
+    /// Converts the contribution into GitHubActivity statistics
+    pub fn to_activity(&self) -> GitHubActivity {
+        GitHubActivity::new(self.commits, self.pull_requests, self.issues, self.reviews)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_repository_contribution() {
+        let contribution = RepositoryContribution::new("rust-lang/rust".to_string(), 12, 3, 1, 4);
+
+        assert_eq!(contribution.repository(), "rust-lang/rust");
+        assert_eq!(contribution.owner(), "rust-lang");
+        assert_eq!(contribution.commits(), 12);
+        assert_eq!(contribution.pull_requests(), 3);
+        assert_eq!(contribution.issues(), 1);
+        assert_eq!(contribution.reviews(), 4);
+    }
+
+    #[test]
+    fn detects_external_repositories() {
+        let contribution = RepositoryContribution::new("rust-lang/rust".to_string(), 12, 3, 1, 4);
+
+        assert!(contribution.is_external_to(&["connect0459".to_string()]));
+        assert!(!contribution.is_external_to(&["Rust-Lang".to_string()]));
+    }
+
+    #[test]
+    fn converts_to_activity() {
+        let contribution = RepositoryContribution::new("rust-lang/rust".to_string(), 12, 3, 1, 4);
+
+        assert_eq!(contribution.to_activity(), GitHubActivity::new(12, 3, 1, 4));
+    }
+}
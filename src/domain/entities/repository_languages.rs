@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the size of code written in a language within a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageSize {
+    name: String,
+    size: u64,
+}
+
+impl LanguageSize {
+    /// Creates a new LanguageSize instance
+    pub fn new(name: String, size: u64) -> Self {
+        Self { name, size }
+    }
+
+    /// Returns the language name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the size of code in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Represents the languages used in a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryLanguages {
+    repository: String,
+    languages: Vec<LanguageSize>,
+}
+
+impl RepositoryLanguages {
+    /// Creates a new RepositoryLanguages instance
+    pub fn new(repository: String, languages: Vec<LanguageSize>) -> Self {
+        Self {
+            repository,
+            languages,
+        }
+    }
+
+    /// Returns the repository name with owner (e.g., "owner/repo")
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the languages with their code size
+    #[allow(dead_code)]
+    pub fn languages(&self) -> &[LanguageSize] {
+        &self.languages
+    }
+
+    /// Returns the share (0.0 - 1.0) of each language in the repository
+    pub fn shares(&self) -> Vec<(&str, f64)> {
+        let total: u64 = self.languages.iter().map(LanguageSize::size).sum();
+        if total == 0 {
+            return Vec::new();
+        }
+        self.languages
+            .iter()
+            .map(|language| (language.name(), language.size() as f64 / total as f64))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_language_shares() {
+        let languages = RepositoryLanguages::new(
+            "connect0459/nenpo".to_string(),
+            vec![
+                LanguageSize::new("Rust".to_string(), 750),
+                LanguageSize::new("Shell".to_string(), 250),
+            ],
+        );
+
+        assert_eq!(languages.shares(), vec![("Rust", 0.75), ("Shell", 0.25)]);
+    }
+
+    #[test]
+    fn returns_no_shares_for_repository_without_code() {
+        let languages = RepositoryLanguages::new("connect0459/empty".to_string(), vec![]);
+
+        assert!(languages.shares().is_empty());
+    }
+}
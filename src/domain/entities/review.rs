@@ -0,0 +1,130 @@
+use crate::domain::value_objects::review_state::ReviewState;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Represents a pull request review submitted by a user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Review {
+    repository: String,
+    pull_request_number: u32,
+    pull_request_author: String,
+    state: ReviewState,
+    submitted_at: DateTime<Utc>,
+    comments_count: u32,
+    requested_at: Option<DateTime<Utc>>,
+}
+
+impl Review {
+    /// Creates a new Review instance
+    pub fn new(
+        repository: String,
+        pull_request_number: u32,
+        pull_request_author: String,
+        state: ReviewState,
+        submitted_at: DateTime<Utc>,
+        comments_count: u32,
+    ) -> Self {
+        Self {
+            repository,
+            pull_request_number,
+            pull_request_author,
+            state,
+            submitted_at,
+            comments_count,
+            requested_at: None,
+        }
+    }
+
+    /// Sets the date the review was requested from the reviewer
+    pub fn with_requested_at(mut self, requested_at: Option<DateTime<Utc>>) -> Self {
+        self.requested_at = requested_at;
+        self
+    }
+
+    /// Returns the repository name with owner
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the number of the reviewed pull request
+    pub fn pull_request_number(&self) -> u32 {
+        self.pull_request_number
+    }
+
+    /// Returns the author login of the reviewed pull request
+    pub fn pull_request_author(&self) -> &str {
+        &self.pull_request_author
+    }
+
+    /// Returns the review state
+    pub fn state(&self) -> ReviewState {
+        self.state
+    }
+
+    /// Returns the submission date
+    pub fn submitted_at(&self) -> DateTime<Utc> {
+        self.submitted_at
+    }
+
+    /// Returns the number of review comments
+    pub fn comments_count(&self) -> u32 {
+        self.comments_count
+    }
+
+    /// Returns the date the review was requested, if known
+    #[allow(dead_code)]
+    pub fn requested_at(&self) -> Option<DateTime<Utc>> {
+        self.requested_at
+    }
+
+    /// Returns the time from review request to submission, if the request is known
+    pub fn turnaround(&self) -> Option<Duration> {
+        self.requested_at
+            .filter(|requested_at| *requested_at <= self.submitted_at)
+            .map(|requested_at| self.submitted_at - requested_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn creates_review() {
+        let submitted_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let review = Review::new(
+            "connect0459/nenpo".to_string(),
+            42,
+            "octocat".to_string(),
+            ReviewState::Approved,
+            submitted_at,
+            3,
+        );
+
+        assert_eq!(review.repository(), "connect0459/nenpo");
+        assert_eq!(review.pull_request_number(), 42);
+        assert_eq!(review.pull_request_author(), "octocat");
+        assert_eq!(review.state(), ReviewState::Approved);
+        assert_eq!(review.submitted_at(), submitted_at);
+        assert_eq!(review.comments_count(), 3);
+        assert_eq!(review.turnaround(), None);
+    }
+
+    #[test]
+    fn calculates_turnaround_from_request() {
+        let requested_at = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        let submitted_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let review = Review::new(
+            "connect0459/nenpo".to_string(),
+            42,
+            "octocat".to_string(),
+            ReviewState::Commented,
+            submitted_at,
+            0,
+        )
+        .with_requested_at(Some(requested_at));
+
+        assert_eq!(review.turnaround(), Some(Duration::hours(3)));
+    }
+}
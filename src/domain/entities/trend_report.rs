@@ -0,0 +1,278 @@
+use crate::domain::entities::report::Report;
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use crate::domain::value_objects::pull_request_summary::PullRequestSummary;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Represents the headline numbers of one fiscal year in a trend report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrendYear {
+    year: u32,
+    period_from: NaiveDate,
+    period_to: NaiveDate,
+    commits: u32,
+    pull_requests: u32,
+    merged_pull_requests: u32,
+    reviews: u32,
+    additions: u32,
+    deletions: u32,
+    themes: HashMap<CommitTheme, u32>,
+}
+
+impl TrendYear {
+    /// Summarizes an annual report
+    ///
+    /// Commits, themes and churn count the user's own commits; pull requests
+    /// are those authored and merged within the fiscal year, and reviews are
+    /// the total shown in the report's activity summary.
+    pub fn from_report(report: &Report) -> Self {
+        let (additions, deletions) =
+            report
+                .organization_breakdown()
+                .iter()
+                .fold((0, 0), |(additions, deletions), entry| {
+                    (additions + entry.additions(), deletions + entry.deletions())
+                });
+        Self {
+            year: report.year(),
+            period_from: report.period_from(),
+            period_to: report.period_to(),
+            commits: report.theme_summary().values().sum(),
+            pull_requests: report
+                .pull_request_summary()
+                .map_or(0, PullRequestSummary::authored),
+            merged_pull_requests: report
+                .pull_request_summary()
+                .map_or(0, PullRequestSummary::merged),
+            reviews: report.github_activity().reviews(),
+            additions,
+            deletions,
+            themes: report.theme_summary().clone(),
+        }
+    }
+
+    /// Returns the fiscal year
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Returns the first day of the fiscal year
+    pub fn period_from(&self) -> NaiveDate {
+        self.period_from
+    }
+
+    /// Returns the last day of the fiscal year
+    pub fn period_to(&self) -> NaiveDate {
+        self.period_to
+    }
+
+    /// Returns the number of the user's commits
+    pub fn commits(&self) -> u32 {
+        self.commits
+    }
+
+    /// Returns the number of pull requests created within the fiscal year
+    pub fn pull_requests(&self) -> u32 {
+        self.pull_requests
+    }
+
+    /// Returns the number of pull requests merged within the fiscal year
+    pub fn merged_pull_requests(&self) -> u32 {
+        self.merged_pull_requests
+    }
+
+    /// Returns the number of reviews
+    pub fn reviews(&self) -> u32 {
+        self.reviews
+    }
+
+    /// Returns the number of lines added by the user's commits
+    pub fn additions(&self) -> u32 {
+        self.additions
+    }
+
+    /// Returns the number of lines deleted by the user's commits
+    pub fn deletions(&self) -> u32 {
+        self.deletions
+    }
+
+    /// Returns the commit counts per theme
+    pub fn themes(&self) -> &HashMap<CommitTheme, u32> {
+        &self.themes
+    }
+}
+
+/// Represents a department's activity across consecutive fiscal years
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrendReport {
+    department_name: String,
+    years: Vec<TrendYear>,
+}
+
+impl TrendReport {
+    /// Creates a trend report from annual reports, ordered by fiscal year
+    pub fn from_reports(department_name: String, reports: &[Report]) -> Self {
+        let mut years: Vec<TrendYear> = reports.iter().map(TrendYear::from_report).collect();
+        years.sort_by_key(|year| year.year);
+        Self {
+            department_name,
+            years,
+        }
+    }
+
+    /// Returns the name of the department
+    pub fn department_name(&self) -> &str {
+        &self.department_name
+    }
+
+    /// Returns the summary of each fiscal year, oldest first
+    pub fn years(&self) -> &[TrendYear] {
+        &self.years
+    }
+
+    /// Returns the first fiscal year covered
+    pub fn first_year(&self) -> Option<u32> {
+        self.years.first().map(TrendYear::year)
+    }
+
+    /// Returns the last fiscal year covered
+    pub fn last_year(&self) -> Option<u32> {
+        self.years.last().map(TrendYear::year)
+    }
+
+    /// Returns every theme that appears in any year, in theme order
+    pub fn themes(&self) -> Vec<CommitTheme> {
+        let mut themes: Vec<CommitTheme> = Vec::new();
+        for year in &self.years {
+            for theme in year.themes.keys() {
+                if !themes.contains(theme) {
+                    themes.push(theme.clone());
+                }
+            }
+        }
+        themes.sort_by_key(|theme| theme.sort_order());
+        themes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::commit::Commit;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::entities::pull_request::PullRequest;
+    use crate::domain::value_objects::activity_breakdown::ActivityBreakdown;
+    use crate::domain::value_objects::commit_classifier::CommitClassifier;
+    use crate::domain::value_objects::period::Period;
+    use chrono::{TimeZone, Utc};
+
+    fn report(year: u32, themes: &[(CommitTheme, u32)], changes: &[(u32, u32)]) -> Report {
+        let commits: Vec<Commit> = changes
+            .iter()
+            .enumerate()
+            .map(|(i, &(additions, deletions))| {
+                Commit::new(
+                    format!("sha{}", i),
+                    "feat: change".to_string(),
+                    "user".to_string(),
+                    Utc.with_ymd_and_hms(year as i32, 5, 1, 0, 0, 0).unwrap(),
+                    "org/app".to_string(),
+                )
+                .with_changes(additions, deletions)
+            })
+            .collect();
+        let classifier = CommitClassifier::default();
+        Report::new(
+            year,
+            "Personal".to_string(),
+            NaiveDate::from_ymd_opt(year as i32, 4, 1).unwrap(),
+            NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).unwrap(),
+            GitHubActivity::new(100, 10, 5, 3),
+            vec![],
+            themes.iter().cloned().collect(),
+        )
+        .with_breakdowns(
+            ActivityBreakdown::by_organization(&commits, &[], &classifier),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn summarizes_each_year_in_order() {
+        let reports = vec![
+            report(2024, &[(CommitTheme::Fix, 2)], &[(5, 1)]),
+            report(
+                2023,
+                &[(CommitTheme::Feat, 3), (CommitTheme::Fix, 1)],
+                &[(10, 2), (3, 4)],
+            ),
+        ];
+
+        let trend = TrendReport::from_reports("Personal".to_string(), &reports);
+
+        assert_eq!(trend.first_year(), Some(2023));
+        assert_eq!(trend.last_year(), Some(2024));
+        let first = &trend.years()[0];
+        assert_eq!(first.commits(), 4);
+        assert_eq!(first.reviews(), 3);
+        assert_eq!((first.additions(), first.deletions()), (13, 6));
+        assert_eq!(trend.themes(), vec![CommitTheme::Feat, CommitTheme::Fix]);
+    }
+
+    #[test]
+    fn counts_pull_requests_within_each_year() {
+        let pull_requests = vec![
+            PullRequest::new(
+                1,
+                "feat: add export".to_string(),
+                "org/app".to_string(),
+                "user".to_string(),
+                Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap(),
+            )
+            .with_lifecycle(
+                Some(Utc.with_ymd_and_hms(2024, 4, 2, 0, 0, 0).unwrap()),
+                None,
+            ),
+            PullRequest::new(
+                2,
+                "fix: typo".to_string(),
+                "org/app".to_string(),
+                "user".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap(),
+            ),
+            PullRequest::new(
+                3,
+                "docs: usage".to_string(),
+                "org/app".to_string(),
+                "user".to_string(),
+                Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap(),
+            ),
+        ];
+        // Both reports carry the same lifetime total in their activity summary
+        let reports: Vec<Report> = [2023, 2024]
+            .into_iter()
+            .map(|year| {
+                let period = Period::utc(
+                    NaiveDate::from_ymd_opt(year as i32, 4, 1).unwrap(),
+                    NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).unwrap(),
+                );
+                report(year, &[], &[]).with_pull_request_summary(
+                    PullRequestSummary::from_pull_requests(&pull_requests, period),
+                )
+            })
+            .collect();
+
+        let trend = TrendReport::from_reports("Personal".to_string(), &reports);
+
+        let years = trend.years();
+        assert_eq!(
+            (years[0].pull_requests(), years[0].merged_pull_requests()),
+            (1, 0)
+        );
+        assert_eq!(
+            (years[1].pull_requests(), years[1].merged_pull_requests()),
+            (2, 1)
+        );
+    }
+}
@@ -1,7 +1,15 @@
 use crate::domain::entities::commit::Commit;
 use crate::domain::entities::github_activity::GitHubActivity;
+use crate::domain::entities::issue::Issue;
+use crate::domain::entities::pull_request::PullRequest;
+use crate::domain::entities::release::Release;
+use crate::domain::entities::repository_contribution::RepositoryContribution;
+use crate::domain::entities::repository_languages::RepositoryLanguages;
+use crate::domain::entities::review::Review;
+use crate::domain::value_objects::contribution_calendar::DailyContribution;
+use crate::domain::value_objects::period::Period;
+use crate::domain::value_objects::repository_filter::RepositoryFilter;
 use anyhow::Result;
-use chrono::NaiveDate;
 
 /// Repository trait for fetching GitHub data
 #[allow(dead_code)] // Temporarily allowed during TDD implementation
@@ -11,13 +19,13 @@ pub trait GitHubRepository {
     /// # Arguments
     ///
     /// * `org_or_user` - GitHub organization or user name
-    /// * `from` - Start date (inclusive)
-    /// * `to` - End date (inclusive)
+    /// * `period` - Period of whole days in the department's timezone
+    /// * `filter` - Selects which repositories are counted
     fn fetch_activity(
         &self,
         org_or_user: &str,
-        from: NaiveDate,
-        to: NaiveDate,
+        period: Period,
+        filter: &RepositoryFilter,
     ) -> Result<GitHubActivity>;
 
     /// Fetches all commits for the specified organization/user within the given period
@@ -25,9 +33,9 @@ pub trait GitHubRepository {
     /// # Arguments
     ///
     /// * `org_or_user` - GitHub organization or user name
-    /// * `from` - Start date (inclusive)
-    /// * `to` - End date (inclusive)
+    /// * `period` - Period of whole days in the department's timezone
     /// * `author` - Optional GitHub user login to filter commits by author
+    /// * `filter` - Selects which repositories commits are fetched from
     ///
     /// # Returns
     ///
@@ -35,8 +43,111 @@ pub trait GitHubRepository {
     fn fetch_commits(
         &self,
         org_or_user: &str,
-        from: NaiveDate,
-        to: NaiveDate,
+        period: Period,
         author: Option<&str>,
+        filter: &RepositoryFilter,
     ) -> Result<Vec<Commit>>;
+
+    /// Fetches the contributions of a user broken down by repository
+    ///
+    /// Unlike `fetch_activity`, this also covers repositories owned by other
+    /// users and organizations (e.g. upstream OSS projects).
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - GitHub user login
+    /// * `period` - Period of whole days in the department's timezone
+    fn fetch_contributions(
+        &self,
+        user: &str,
+        period: Period,
+    ) -> Result<Vec<RepositoryContribution>>;
+
+    /// Fetches the user's contribution calendar (contributions per day), as shown on their profile
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - GitHub user login
+    /// * `period` - Period of whole days in the department's timezone
+    fn fetch_contribution_calendar(
+        &self,
+        user: &str,
+        period: Period,
+    ) -> Result<Vec<DailyContribution>>;
+
+    /// Fetches pull requests for the specified organization/user that were
+    /// created or merged within the given period
+    ///
+    /// # Arguments
+    ///
+    /// * `org_or_user` - GitHub organization or user name
+    /// * `period` - Period of whole days in the department's timezone
+    /// * `author` - Optional GitHub user login to filter pull requests by author
+    ///
+    /// # Returns
+    ///
+    /// A vector of all matching pull requests, using pagination to fetch all results
+    fn fetch_pull_requests(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        author: Option<&str>,
+    ) -> Result<Vec<PullRequest>>;
+
+    /// Fetches the pull request reviews a user submitted within the given period
+    /// to repositories of the specified organization/user
+    ///
+    /// # Arguments
+    ///
+    /// * `org_or_user` - GitHub organization or user name
+    /// * `period` - Period of whole days in the department's timezone
+    /// * `reviewer` - GitHub user login of the reviewer
+    fn fetch_reviews(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        reviewer: &str,
+    ) -> Result<Vec<Review>>;
+
+    /// Fetches issues of the specified organization/user that were updated
+    /// within the given period, including labels, state transitions and comments
+    ///
+    /// # Arguments
+    ///
+    /// * `org_or_user` - GitHub organization or user name
+    /// * `period` - Period of whole days in the department's timezone
+    /// * `user` - Optional GitHub user login to limit issues to those the user is involved in
+    fn fetch_issues(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        user: Option<&str>,
+    ) -> Result<Vec<Issue>>;
+
+    /// Fetches releases, and tags without a release, published within the given
+    /// period in repositories of the specified organization/user
+    ///
+    /// # Arguments
+    ///
+    /// * `org_or_user` - GitHub organization or user name
+    /// * `period` - Period of whole days in the department's timezone
+    /// * `filter` - Selects which repositories releases are listed from
+    fn fetch_releases(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        filter: &RepositoryFilter,
+    ) -> Result<Vec<Release>>;
+
+    /// Fetches the languages used in each collected repository of the specified organization/user
+    ///
+    /// # Arguments
+    ///
+    /// * `org_or_user` - GitHub organization or user name
+    /// * `filter` - Selects which repositories are included
+    fn fetch_languages(
+        &self,
+        org_or_user: &str,
+        filter: &RepositoryFilter,
+    ) -> Result<Vec<RepositoryLanguages>>;
 }
@@ -2,3 +2,4 @@ pub mod config_repository;
 pub mod document_repository;
 pub mod github_repository;
 pub mod output_repository;
+pub mod report_repository;
@@ -1,4 +1,5 @@
 use crate::domain::entities::report::Report;
+use crate::domain::entities::trend_report::TrendReport;
 use anyhow::Result;
 use std::path::Path;
 
@@ -7,4 +8,7 @@ use std::path::Path;
 pub trait OutputRepository {
     /// Outputs a report to the specified path
     fn output(&self, report: &Report, path: &Path) -> Result<()>;
+
+    /// Outputs a multi-year trend report to the specified path
+    fn output_trend(&self, trend: &TrendReport, path: &Path) -> Result<()>;
 }
@@ -0,0 +1,9 @@
+use crate::domain::entities::report::Report;
+use anyhow::Result;
+use std::path::Path;
+
+/// Repository trait for loading previously generated reports
+pub trait ReportRepository {
+    /// Loads a report from the specified path
+    fn load(&self, path: &Path) -> Result<Report>;
+}
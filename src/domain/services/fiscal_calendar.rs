@@ -0,0 +1,393 @@
+use crate::domain::entities::department::Department;
+use crate::domain::value_objects::week_layout::WeekLayout;
+use anyhow::{bail, Result};
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+/// Number of fiscal months in a fiscal year
+const MONTHS_PER_YEAR: usize = 12;
+
+/// Splits fiscal years into months, quarters and halves
+///
+/// Fiscal year `Y` starts on `start_day` of `start_month` in calendar year `Y`.
+/// Months run from that day to the day before the same day of the next month.
+/// With a week layout, the year instead starts on the week start nearest to
+/// that date and months are whole weeks following the quarter pattern; the
+/// 53rd week of a long year goes to the last month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    start_month: u32,
+    start_day: u32,
+    week_layout: Option<WeekLayout>,
+}
+
+impl FiscalCalendar {
+    /// Creates a month-based calendar starting on `start_day` of `start_month`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless `start_month` is 1-12 and `start_day` is 1-28,
+    /// so that every month has the start day
+    pub fn new(start_month: u32, start_day: u32) -> Result<Self> {
+        if !(1..=12).contains(&start_month) {
+            bail!(
+                "Fiscal year start month must be between 1 and 12, got {}",
+                start_month
+            );
+        }
+        if !(1..=28).contains(&start_day) {
+            bail!(
+                "Fiscal year start day must be between 1 and 28, got {}",
+                start_day
+            );
+        }
+        Ok(Self {
+            start_month,
+            start_day,
+            week_layout: None,
+        })
+    }
+
+    /// Builds the calendar configured for a department
+    pub fn for_department(department: &Department) -> Self {
+        Self {
+            start_month: department.fiscal_year_start_month(),
+            start_day: department.fiscal_year_start_day(),
+            week_layout: department.week_layout(),
+        }
+    }
+
+    /// Switches to a 52/53-week calendar with the given layout
+    #[allow(dead_code)]
+    pub fn with_week_layout(mut self, week_layout: WeekLayout) -> Self {
+        self.week_layout = Some(week_layout);
+        self
+    }
+
+    /// Returns the first and last day of the fiscal year
+    pub fn year(&self, fiscal_year: u32) -> (NaiveDate, NaiveDate) {
+        (
+            self.year_start(fiscal_year),
+            self.year_start(fiscal_year + 1) - Duration::days(1),
+        )
+    }
+
+    /// Returns the first and last day of each of the twelve fiscal months
+    pub fn months(&self, fiscal_year: u32) -> Vec<(NaiveDate, NaiveDate)> {
+        let start = self.year_start(fiscal_year);
+        let mut starts: Vec<NaiveDate> = match self.week_layout {
+            None => (0..MONTHS_PER_YEAR as u32)
+                .map(|offset| start + Months::new(offset))
+                .collect(),
+            Some(layout) => {
+                let mut month_start = start;
+                let mut starts = Vec::with_capacity(MONTHS_PER_YEAR);
+                for weeks in layout
+                    .pattern()
+                    .weeks()
+                    .iter()
+                    .cycle()
+                    .take(MONTHS_PER_YEAR)
+                {
+                    starts.push(month_start);
+                    month_start += Duration::weeks(*weeks as i64);
+                }
+                starts
+            }
+        };
+        // The last month runs until the next year starts, absorbing a 53rd week
+        starts.push(self.year_start(fiscal_year + 1));
+        starts
+            .windows(2)
+            .map(|pair| (pair[0], pair[1] - Duration::days(1)))
+            .collect()
+    }
+
+    /// Returns the calendar year and month a fiscal month (0-11) is named after
+    ///
+    /// Fiscal months are named after the month they nominally start in, so with
+    /// books closing on the 20th, "2024-06" runs from June 21st to July 20th.
+    pub fn month_name(&self, fiscal_year: u32, index: usize) -> (i32, u32) {
+        let nominal = NaiveDate::from_ymd_opt(fiscal_year as i32, self.start_month, 1)
+            .expect("Invalid date")
+            + Months::new(index as u32);
+        (nominal.year(), nominal.month())
+    }
+
+    /// Returns the first and last day of the fiscal month named after a calendar month
+    pub fn month(&self, year: i32, month: u32) -> (NaiveDate, NaiveDate) {
+        let (fiscal_year, index) = if month >= self.start_month {
+            (year as u32, month - self.start_month)
+        } else {
+            (year as u32 - 1, month + 12 - self.start_month)
+        };
+        self.months(fiscal_year)[index as usize]
+    }
+
+    /// Returns the first and last day of a fiscal quarter (1-4)
+    pub fn quarter(&self, fiscal_year: u32, quarter: u32) -> (NaiveDate, NaiveDate) {
+        self.span(fiscal_year, (quarter as usize - 1) * 3, 3)
+    }
+
+    /// Returns the first and last day of a fiscal half (1-2)
+    pub fn half(&self, fiscal_year: u32, half: u32) -> (NaiveDate, NaiveDate) {
+        self.span(fiscal_year, (half as usize - 1) * 6, 6)
+    }
+
+    /// Returns the number of weeks in the fiscal year for week-based calendars
+    #[allow(dead_code)]
+    pub fn weeks_in_year(&self, fiscal_year: u32) -> Option<i64> {
+        self.week_layout.map(|_| {
+            let (from, to) = self.year(fiscal_year);
+            ((to - from).num_days() + 1) / 7
+        })
+    }
+
+    /// Returns the fiscal year containing the date
+    pub fn fiscal_year_of(&self, date: NaiveDate) -> u32 {
+        let year = date.year() as u32;
+        [year + 1, year]
+            .into_iter()
+            .find(|&candidate| date >= self.year_start(candidate))
+            .unwrap_or(year - 1)
+    }
+
+    /// Returns the fiscal year and quarter (1-4) containing the date
+    pub fn quarter_of(&self, date: NaiveDate) -> (u32, u32) {
+        let fiscal_year = self.fiscal_year_of(date);
+        let quarter = (1..=4)
+            .find(|&quarter| date <= self.quarter(fiscal_year, quarter).1)
+            .unwrap_or(4);
+        (fiscal_year, quarter)
+    }
+
+    /// Returns the first and last day of `count` fiscal months starting at month index `first`
+    fn span(&self, fiscal_year: u32, first: usize, count: usize) -> (NaiveDate, NaiveDate) {
+        let months = self.months(fiscal_year);
+        (months[first].0, months[first + count - 1].1)
+    }
+
+    /// Returns the first day of the fiscal year
+    fn year_start(&self, fiscal_year: u32) -> NaiveDate {
+        let nominal = NaiveDate::from_ymd_opt(fiscal_year as i32, self.start_month, self.start_day)
+            .expect("Invalid date");
+        match self.week_layout {
+            None => nominal,
+            Some(layout) => {
+                // The week start day within three days either side of the nominal date
+                let ahead = (layout.week_start().num_days_from_monday() + 7
+                    - nominal.weekday().num_days_from_monday())
+                    % 7;
+                if ahead <= 3 {
+                    nominal + Duration::days(ahead as i64)
+                } else {
+                    nominal - Duration::days(7 - ahead as i64)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::week_layout::QuarterPattern;
+    use chrono::Weekday;
+    use proptest::prelude::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn retail(pattern: QuarterPattern) -> FiscalCalendar {
+        FiscalCalendar::new(2, 1)
+            .unwrap()
+            .with_week_layout(WeekLayout::new(pattern, Weekday::Sun))
+    }
+
+    #[test]
+    fn splits_month_based_year_at_custom_start_day() {
+        // Books close on the 20th
+        let calendar = FiscalCalendar::new(4, 21).unwrap();
+
+        assert_eq!(calendar.year(2024), (date(2024, 4, 21), date(2025, 4, 20)));
+        assert_eq!(
+            calendar.months(2024)[0],
+            (date(2024, 4, 21), date(2024, 5, 20))
+        );
+        assert_eq!(
+            calendar.quarter(2024, 4),
+            (date(2025, 1, 21), date(2025, 4, 20))
+        );
+        assert_eq!(calendar.fiscal_year_of(date(2024, 4, 20)), 2023);
+        assert_eq!(calendar.fiscal_year_of(date(2024, 4, 21)), 2024);
+    }
+
+    #[test]
+    fn keeps_first_of_month_calendar() {
+        let calendar = FiscalCalendar::new(4, 1).unwrap();
+
+        assert_eq!(calendar.year(2024), (date(2024, 4, 1), date(2025, 3, 31)));
+        assert_eq!(
+            calendar.half(2024, 2),
+            (date(2024, 10, 1), date(2025, 3, 31))
+        );
+        assert_eq!(calendar.weeks_in_year(2024), None);
+    }
+
+    #[test]
+    fn rejects_invalid_start() {
+        assert!(FiscalCalendar::new(0, 1).is_err());
+        assert!(FiscalCalendar::new(4, 29).is_err());
+        assert!(FiscalCalendar::new(4, 0).is_err());
+    }
+
+    #[test]
+    fn splits_week_based_year_by_quarter_pattern() {
+        let calendar = retail(QuarterPattern::FourFourFive);
+
+        // 2024-02-04 is the Sunday nearest to February 1st
+        let months = calendar.months(2024);
+        assert_eq!(months[0], (date(2024, 2, 4), date(2024, 3, 2)));
+        assert_eq!(months[2], (date(2024, 3, 31), date(2024, 5, 4)));
+        assert_eq!(
+            calendar.quarter(2024, 1),
+            (date(2024, 2, 4), date(2024, 5, 4))
+        );
+
+        let pattern = retail(QuarterPattern::FiveFourFour);
+        assert_eq!(
+            pattern.months(2024)[0],
+            (date(2024, 2, 4), date(2024, 3, 9))
+        );
+    }
+
+    #[test]
+    fn adds_53rd_week_to_last_month() {
+        let calendar = retail(QuarterPattern::FourFourFive);
+
+        // FY2022 starts on 2022-01-30 and FY2023 on 2023-01-29: 52 weeks
+        assert_eq!(calendar.weeks_in_year(2022), Some(52));
+        // FY2023 ends on 2024-02-03: 53 weeks, with a six-week last month
+        assert_eq!(calendar.weeks_in_year(2023), Some(53));
+        let last = calendar.months(2023)[11];
+        assert_eq!((last.1 - last.0).num_days() + 1, 42);
+    }
+
+    #[test]
+    fn finds_fiscal_month_by_name() {
+        let calendar = FiscalCalendar::new(4, 21).unwrap();
+        assert_eq!(calendar.month_name(2024, 0), (2024, 4));
+        assert_eq!(calendar.month_name(2024, 11), (2025, 3));
+        assert_eq!(
+            calendar.month(2024, 6),
+            (date(2024, 6, 21), date(2024, 7, 20))
+        );
+        assert_eq!(
+            calendar.month(2025, 3),
+            (date(2025, 3, 21), date(2025, 4, 20))
+        );
+
+        let retail = retail(QuarterPattern::FourFourFive);
+        assert_eq!(retail.month_name(2024, 0), (2024, 2));
+        assert_eq!(retail.month(2024, 2), (date(2024, 2, 4), date(2024, 3, 2)));
+        assert_eq!(retail.month(2025, 1), retail.months(2024)[11]);
+    }
+
+    #[test]
+    fn finds_quarter_of_date() {
+        let calendar = FiscalCalendar::new(4, 1).unwrap();
+
+        assert_eq!(calendar.quarter_of(date(2024, 11, 15)), (2024, 3));
+        assert_eq!(calendar.quarter_of(date(2025, 3, 31)), (2024, 4));
+        assert_eq!(
+            retail(QuarterPattern::FourFourFive).quarter_of(date(2024, 2, 3)),
+            (2023, 4)
+        );
+    }
+
+    fn calendars() -> impl Strategy<Value = FiscalCalendar> {
+        let patterns = prop_oneof![
+            Just(None),
+            Just(Some(QuarterPattern::FourFourFive)),
+            Just(Some(QuarterPattern::FourFiveFour)),
+            Just(Some(QuarterPattern::FiveFourFour)),
+        ];
+        (1u32..=12, 1u32..=28, patterns, 0u32..7).prop_map(|(month, day, pattern, week_start)| {
+            let calendar = FiscalCalendar::new(month, day).unwrap();
+            match pattern {
+                None => calendar,
+                Some(pattern) => calendar.with_week_layout(WeekLayout::new(
+                    pattern,
+                    Weekday::try_from(week_start as u8).unwrap(),
+                )),
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn months_are_contiguous_and_cover_the_year(
+            calendar in calendars(),
+            fiscal_year in 1990u32..2100,
+        ) {
+            let (from, to) = calendar.year(fiscal_year);
+            let months = calendar.months(fiscal_year);
+
+            prop_assert_eq!(months.len(), 12);
+            prop_assert_eq!(months[0].0, from);
+            prop_assert_eq!(months[11].1, to);
+            for month in &months {
+                prop_assert!(month.0 <= month.1);
+            }
+            for pair in months.windows(2) {
+                prop_assert_eq!(pair[0].1 + Duration::days(1), pair[1].0);
+            }
+            // Consecutive years neither overlap nor leave gaps
+            prop_assert_eq!(to + Duration::days(1), calendar.year(fiscal_year + 1).0);
+        }
+
+        #[test]
+        fn quarters_and_halves_partition_the_year(
+            calendar in calendars(),
+            fiscal_year in 1990u32..2100,
+        ) {
+            let (from, to) = calendar.year(fiscal_year);
+            let quarters: Vec<_> = (1..=4).map(|q| calendar.quarter(fiscal_year, q)).collect();
+
+            prop_assert_eq!(quarters[0].0, from);
+            prop_assert_eq!(quarters[3].1, to);
+            for pair in quarters.windows(2) {
+                prop_assert_eq!(pair[0].1 + Duration::days(1), pair[1].0);
+            }
+            prop_assert_eq!(calendar.half(fiscal_year, 1), (quarters[0].0, quarters[1].1));
+            prop_assert_eq!(calendar.half(fiscal_year, 2), (quarters[2].0, quarters[3].1));
+        }
+
+        #[test]
+        fn week_based_years_have_whole_weeks(
+            calendar in calendars(),
+            fiscal_year in 1990u32..2100,
+        ) {
+            if let Some(weeks) = calendar.weeks_in_year(fiscal_year) {
+                let (from, to) = calendar.year(fiscal_year);
+                prop_assert!(weeks == 52 || weeks == 53);
+                prop_assert_eq!((to - from).num_days() + 1, weeks * 7);
+            }
+        }
+
+        #[test]
+        fn every_date_belongs_to_the_quarter_that_contains_it(
+            calendar in calendars(),
+            days in 0i64..40_000,
+        ) {
+            let date = date(1991, 1, 1) + Duration::days(days);
+            let fiscal_year = calendar.fiscal_year_of(date);
+            let (from, to) = calendar.year(fiscal_year);
+            prop_assert!(from <= date && date <= to);
+
+            let (quarter_year, quarter) = calendar.quarter_of(date);
+            let (from, to) = calendar.quarter(quarter_year, quarter);
+            prop_assert!(from <= date && date <= to);
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod fiscal_calendar;
 pub mod progress_reporter;
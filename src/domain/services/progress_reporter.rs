@@ -1,5 +1,16 @@
+use crate::domain::value_objects::period::Period;
+
 /// Trait for reporting progress during long-running operations
 pub trait ProgressReporter {
+    /// Reports the period a department's report covers, before anything is fetched
+    ///
+    /// # Arguments
+    ///
+    /// * `department` - Department name
+    /// * `label` - Period label (e.g., "FY2024-Q3")
+    /// * `period` - Resolved days of the period in the department's timezone
+    fn report_period(&self, department: &str, label: &str, period: &Period);
+
     /// Reports the start of fetching commits for an organization/user
     fn start_fetching_commits(&self, org_or_user: &str);
 
@@ -19,21 +30,49 @@ pub trait ProgressReporter {
     /// * `total_count` - Total number of commits fetched
     fn finish_fetching_commits(&self, org_or_user: &str, total_count: usize);
 
+    /// Reports a repository skipped by the department's repository filter
+    ///
+    /// # Arguments
+    ///
+    /// * `org_or_user` - GitHub organization or user name
+    /// * `repository` - Name of the skipped repository
+    /// * `reason` - Why the repository was skipped
+    fn report_excluded_repository(&self, org_or_user: &str, repository: &str, reason: &str);
+
     /// Reports an error during operations
     #[allow(dead_code)]
     fn report_error(&self, error: &str);
 }
 
 /// Progress reporter that outputs to stdout
-pub struct StdoutProgressReporter;
+pub struct StdoutProgressReporter {
+    verbose: bool,
+}
 
 impl StdoutProgressReporter {
     pub fn new() -> Self {
-        Self
+        Self { verbose: false }
+    }
+
+    /// Enables details such as excluded repositories
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
     }
 }
 
 impl ProgressReporter for StdoutProgressReporter {
+    fn report_period(&self, department: &str, label: &str, period: &Period) {
+        eprintln!(
+            "{}: {} ({} to {}, {})",
+            department,
+            label,
+            period.from(),
+            period.to(),
+            period.timezone()
+        );
+    }
+
     fn start_fetching_commits(&self, org_or_user: &str) {
         eprintln!("Fetching commits for {}...", org_or_user);
     }
@@ -52,6 +91,12 @@ impl ProgressReporter for StdoutProgressReporter {
         );
     }
 
+    fn report_excluded_repository(&self, org_or_user: &str, repository: &str, reason: &str) {
+        if self.verbose {
+            eprintln!("  - Skipping {}/{} ({})", org_or_user, repository, reason);
+        }
+    }
+
     fn report_error(&self, error: &str) {
         eprintln!("✗ Error: {}", error);
     }
@@ -69,9 +114,11 @@ impl NoOpProgressReporter {
 }
 
 impl ProgressReporter for NoOpProgressReporter {
+    fn report_period(&self, _department: &str, _label: &str, _period: &Period) {}
     fn start_fetching_commits(&self, _org_or_user: &str) {}
     fn report_commits_progress(&self, _org_or_user: &str, _fetched_count: usize) {}
     fn finish_fetching_commits(&self, _org_or_user: &str, _total_count: usize) {}
+    fn report_excluded_repository(&self, _org_or_user: &str, _repository: &str, _reason: &str) {}
     fn report_error(&self, _error: &str) {}
 }
 
@@ -82,11 +129,17 @@ mod tests {
     #[test]
     fn noop_progress_reporter_outputs_nothing() {
         let reporter = NoOpProgressReporter::new();
+        let period = Period::utc(
+            chrono::NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+        );
 
         // These should not panic and do nothing
+        reporter.report_period("個人", "FY2024", &period);
         reporter.start_fetching_commits("test-org");
         reporter.report_commits_progress("test-org", 50);
         reporter.finish_fetching_commits("test-org", 100);
+        reporter.report_excluded_repository("test-org", "sandbox", "archived");
         reporter.report_error("test error");
     }
 
@@ -98,5 +151,8 @@ mod tests {
         reporter.start_fetching_commits("test-org");
         reporter.report_commits_progress("test-org", 50);
         reporter.finish_fetching_commits("test-org", 100);
+
+        let verbose_reporter = StdoutProgressReporter::new().with_verbose(true);
+        verbose_reporter.report_excluded_repository("test-org", "sandbox", "archived");
     }
 }
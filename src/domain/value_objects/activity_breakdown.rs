@@ -0,0 +1,225 @@
+use crate::domain::entities::commit::Commit;
+use crate::domain::entities::pull_request::PullRequest;
+use crate::domain::value_objects::commit_classifier::CommitClassifier;
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Represents the user's activity in one repository or organization
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityBreakdown {
+    name: String,
+    commits: u32,
+    pull_requests: u32,
+    additions: u32,
+    deletions: u32,
+    themes: HashMap<CommitTheme, u32>,
+}
+
+impl ActivityBreakdown {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            commits: 0,
+            pull_requests: 0,
+            additions: 0,
+            deletions: 0,
+            themes: HashMap::new(),
+        }
+    }
+
+    /// Groups commits and pull requests by repository (`owner/name`)
+    pub fn by_repository(
+        commits: &[Commit],
+        pull_requests: &[PullRequest],
+        classifier: &CommitClassifier,
+    ) -> Vec<Self> {
+        Self::group(commits, pull_requests, classifier, |repository| repository)
+    }
+
+    /// Groups commits and pull requests by the organization or user owning the repository
+    pub fn by_organization(
+        commits: &[Commit],
+        pull_requests: &[PullRequest],
+        classifier: &CommitClassifier,
+    ) -> Vec<Self> {
+        Self::group(commits, pull_requests, classifier, |repository| {
+            repository.split('/').next().unwrap_or(repository)
+        })
+    }
+
+    /// Groups activity by a key derived from the repository name, most active first
+    ///
+    /// Activity is commits plus pull requests; ties are broken by churn, then by name.
+    fn group(
+        commits: &[Commit],
+        pull_requests: &[PullRequest],
+        classifier: &CommitClassifier,
+        key: impl Fn(&str) -> &str,
+    ) -> Vec<Self> {
+        let mut groups: HashMap<String, Self> = HashMap::new();
+        fn entry<'a>(
+            groups: &'a mut HashMap<String, ActivityBreakdown>,
+            name: &str,
+        ) -> &'a mut ActivityBreakdown {
+            groups
+                .entry(name.to_lowercase())
+                .or_insert_with(|| ActivityBreakdown::new(name.to_string()))
+        }
+
+        for commit in commits {
+            let group = entry(&mut groups, key(commit.repository()));
+            group.commits += 1;
+            group.additions += commit.additions();
+            group.deletions += commit.deletions();
+            *group
+                .themes
+                .entry(classifier.classify(commit.message()))
+                .or_insert(0) += 1;
+        }
+        for pull_request in pull_requests {
+            entry(&mut groups, key(pull_request.repository())).pull_requests += 1;
+        }
+
+        let mut breakdown: Vec<Self> = groups.into_values().collect();
+        breakdown.sort_by(|a, b| {
+            b.activity()
+                .cmp(&a.activity())
+                .then(b.churn().cmp(&a.churn()))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        breakdown
+    }
+
+    /// Returns the repository or organization name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of commits
+    pub fn commits(&self) -> u32 {
+        self.commits
+    }
+
+    /// Returns the number of pull requests authored
+    pub fn pull_requests(&self) -> u32 {
+        self.pull_requests
+    }
+
+    /// Returns the number of lines added by the commits
+    pub fn additions(&self) -> u32 {
+        self.additions
+    }
+
+    /// Returns the number of lines deleted by the commits
+    pub fn deletions(&self) -> u32 {
+        self.deletions
+    }
+
+    /// Returns the number of lines changed by the commits
+    pub fn churn(&self) -> u32 {
+        self.additions + self.deletions
+    }
+
+    /// Returns the commit counts per theme
+    pub fn themes(&self) -> &HashMap<CommitTheme, u32> {
+        &self.themes
+    }
+
+    /// Returns the number of commits and pull requests
+    fn activity(&self) -> u32 {
+        self.commits + self.pull_requests
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn pull_request(repository: &str) -> PullRequest {
+        PullRequest::new(
+            1,
+            "Add export".to_string(),
+            repository.to_string(),
+            "johndoe".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap(),
+        )
+    }
+
+    fn commits() -> Vec<Commit> {
+        vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            )
+            .with_changes(100, 10),
+            Commit::fixture(
+                "abc123",
+                "fix: handle timeout",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            )
+            .with_changes(5, 5),
+            Commit::fixture(
+                "abc123",
+                "docs: update README",
+                "org/docs",
+                "2024-05-01T10:00:00Z",
+            )
+            .with_changes(20, 0),
+            Commit::fixture(
+                "abc123",
+                "feat: add login",
+                "johndoe/dotfiles",
+                "2024-05-01T10:00:00Z",
+            )
+            .with_changes(3, 1),
+        ]
+    }
+
+    #[test]
+    fn groups_by_repository_sorted_by_activity() {
+        let pull_requests = vec![pull_request("org/docs")];
+
+        let breakdown = ActivityBreakdown::by_repository(
+            &commits(),
+            &pull_requests,
+            &CommitClassifier::default(),
+        );
+
+        let names: Vec<_> = breakdown.iter().map(ActivityBreakdown::name).collect();
+        // org/app and org/docs both have 2 activities; org/app has more churn
+        assert_eq!(names, vec!["org/app", "org/docs", "johndoe/dotfiles"]);
+        assert_eq!(breakdown[0].commits(), 2);
+        assert_eq!(breakdown[0].pull_requests(), 0);
+        assert_eq!(breakdown[0].additions(), 105);
+        assert_eq!(breakdown[0].deletions(), 15);
+        assert_eq!(breakdown[0].churn(), 120);
+        assert_eq!(breakdown[0].themes().get(&CommitTheme::Feat), Some(&1));
+        assert_eq!(breakdown[0].themes().get(&CommitTheme::Fix), Some(&1));
+        assert_eq!(breakdown[1].pull_requests(), 1);
+    }
+
+    #[test]
+    fn groups_by_organization() {
+        let pull_requests = vec![pull_request("johndoe/dotfiles")];
+
+        let breakdown = ActivityBreakdown::by_organization(
+            &commits(),
+            &pull_requests,
+            &CommitClassifier::default(),
+        );
+
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(breakdown[0].name(), "org");
+        assert_eq!(breakdown[0].commits(), 3);
+        assert_eq!(breakdown[0].churn(), 140);
+        assert_eq!(breakdown[0].themes().get(&CommitTheme::Docs), Some(&1));
+        assert_eq!(breakdown[1].name(), "johndoe");
+        assert_eq!(breakdown[1].commits(), 1);
+        assert_eq!(breakdown[1].pull_requests(), 1);
+    }
+}
@@ -0,0 +1,448 @@
+use crate::domain::entities::commit::Commit;
+use crate::domain::entities::issue::Issue;
+use crate::domain::entities::pull_request::PullRequest;
+use crate::domain::services::fiscal_calendar::FiscalCalendar;
+use crate::domain::value_objects::period::Period;
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Represents the user's activity within one month or week of the period
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimelineBucket {
+    label: String,
+    from: NaiveDate,
+    to: NaiveDate,
+    commits: u32,
+    pull_requests: u32,
+    issues: u32,
+}
+
+impl TimelineBucket {
+    fn new(label: String, from: NaiveDate, to: NaiveDate) -> Self {
+        Self {
+            label,
+            from,
+            to,
+            commits: 0,
+            pull_requests: 0,
+            issues: 0,
+        }
+    }
+
+    /// Returns the label (e.g., "2024-04" or "2024-W14")
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the first day of the bucket within the period
+    #[allow(dead_code)]
+    pub fn from(&self) -> NaiveDate {
+        self.from
+    }
+
+    /// Returns the last day of the bucket within the period
+    #[allow(dead_code)]
+    pub fn to(&self) -> NaiveDate {
+        self.to
+    }
+
+    /// Returns the number of commits
+    pub fn commits(&self) -> u32 {
+        self.commits
+    }
+
+    /// Returns the number of pull requests created
+    pub fn pull_requests(&self) -> u32 {
+        self.pull_requests
+    }
+
+    /// Returns the number of issues opened
+    pub fn issues(&self) -> u32 {
+        self.issues
+    }
+}
+
+/// Represents the user's activity over the period, by fiscal month and ISO week
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ActivityTimeline {
+    monthly: Vec<TimelineBucket>,
+    weekly: Vec<TimelineBucket>,
+}
+
+impl ActivityTimeline {
+    /// Buckets commits, pull requests and issues by the dates they were made
+    ///
+    /// Months are the fiscal months of the calendar and weeks are ISO weeks
+    /// (Monday to Sunday), both clipped to the period at both ends. Dates are
+    /// local to the period's timezone. Pull requests and issues are counted by
+    /// their creation date; when `user` is given, only issues opened by that
+    /// user are counted.
+    pub fn from_activity(
+        commits: &[Commit],
+        pull_requests: &[PullRequest],
+        issues: &[Issue],
+        period: Period,
+        calendar: &FiscalCalendar,
+        user: Option<&str>,
+    ) -> Self {
+        let mut timeline = Self {
+            monthly: monthly_buckets(period.from(), period.to(), calendar),
+            weekly: weekly_buckets(period.from(), period.to()),
+        };
+
+        for date in commits
+            .iter()
+            .map(|c| period.local_date(c.committed_date()))
+        {
+            timeline.each_bucket(date, |bucket| bucket.commits += 1);
+        }
+        for date in pull_requests
+            .iter()
+            .map(|pr| period.local_date(pr.created_at()))
+        {
+            timeline.each_bucket(date, |bucket| bucket.pull_requests += 1);
+        }
+        let opened_issues = issues
+            .iter()
+            .filter(|issue| user.is_none_or(|user| user.eq_ignore_ascii_case(issue.author())));
+        for date in opened_issues.map(|issue| period.local_date(issue.created_at())) {
+            timeline.each_bucket(date, |bucket| bucket.issues += 1);
+        }
+        timeline
+    }
+
+    /// Applies `update` to the month and week containing the date, if within the period
+    fn each_bucket(&mut self, date: NaiveDate, update: impl Fn(&mut TimelineBucket)) {
+        for buckets in [&mut self.monthly, &mut self.weekly] {
+            if let Some(bucket) = buckets
+                .iter_mut()
+                .find(|bucket| bucket.from <= date && date <= bucket.to)
+            {
+                update(bucket);
+            }
+        }
+    }
+
+    /// Returns the activity per month, in fiscal order
+    pub fn monthly(&self) -> &[TimelineBucket] {
+        &self.monthly
+    }
+
+    /// Returns the activity per ISO week, in order
+    pub fn weekly(&self) -> &[TimelineBucket] {
+        &self.weekly
+    }
+
+    /// Returns true if the timeline has no buckets
+    pub fn is_empty(&self) -> bool {
+        self.monthly.is_empty() && self.weekly.is_empty()
+    }
+}
+
+/// Splits the period into the fiscal months it overlaps, clipping the first and last months
+fn monthly_buckets(
+    from: NaiveDate,
+    to: NaiveDate,
+    calendar: &FiscalCalendar,
+) -> Vec<TimelineBucket> {
+    let mut buckets = Vec::new();
+    for fiscal_year in calendar.fiscal_year_of(from)..=calendar.fiscal_year_of(to) {
+        for (index, (start, end)) in calendar.months(fiscal_year).into_iter().enumerate() {
+            if end < from || start > to {
+                continue;
+            }
+            let (year, month) = calendar.month_name(fiscal_year, index);
+            buckets.push(TimelineBucket::new(
+                format!("{}-{:02}", year, month),
+                start.max(from),
+                end.min(to),
+            ));
+        }
+    }
+    buckets
+}
+
+/// Splits the period into ISO weeks, clipping the first and last weeks to the period
+fn weekly_buckets(from: NaiveDate, to: NaiveDate) -> Vec<TimelineBucket> {
+    let mut buckets = Vec::new();
+    let mut start = from;
+    while start <= to {
+        let week = start.iso_week();
+        let sunday = start + Duration::days(6 - start.weekday().num_days_from_monday() as i64);
+        let end = sunday.min(to);
+        buckets.push(TimelineBucket::new(
+            format!("{}-W{:02}", week.year(), week.week()),
+            start,
+            end,
+        ));
+        start = sunday + Duration::days(1);
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn buckets_by_fiscal_month() {
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-01T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-30T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2025-03-31T10:00:00Z",
+            ),
+        ];
+        let pull_requests = vec![PullRequest::new(
+            1,
+            "Add export".to_string(),
+            "org/app".to_string(),
+            "johndoe".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 15, 10, 0, 0).unwrap(),
+        )];
+
+        let timeline = ActivityTimeline::from_activity(
+            &commits,
+            &pull_requests,
+            &[],
+            Period::utc(date(2024, 4, 1), date(2025, 3, 31)),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        );
+
+        let months = timeline.monthly();
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0].label(), "2024-04");
+        assert_eq!(months[0].commits(), 2);
+        assert_eq!(months[1].label(), "2024-05");
+        assert_eq!(months[1].pull_requests(), 1);
+        assert_eq!(months[11].label(), "2025-03");
+        assert_eq!(months[11].to(), date(2025, 3, 31));
+        assert_eq!(months[11].commits(), 1);
+    }
+
+    #[test]
+    fn buckets_by_fiscal_month_closing_on_20th() {
+        let calendar = FiscalCalendar::new(4, 21).unwrap();
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-20T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-21T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-20T10:00:00Z",
+            ),
+        ];
+
+        let timeline = ActivityTimeline::from_activity(
+            &commits,
+            &[],
+            &[],
+            Period::utc(date(2024, 4, 21), date(2025, 4, 20)),
+            &calendar,
+            None,
+        );
+
+        let months = timeline.monthly();
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0].label(), "2024-04");
+        assert_eq!(
+            (months[0].from(), months[0].to()),
+            (date(2024, 4, 21), date(2024, 5, 20))
+        );
+        assert_eq!(months[0].commits(), 2);
+        assert_eq!(months[11].label(), "2025-03");
+        assert_eq!(months[11].to(), date(2025, 4, 20));
+    }
+
+    #[test]
+    fn buckets_by_fiscal_month_of_4_4_5_calendar() {
+        use crate::domain::value_objects::week_layout::{QuarterPattern, WeekLayout};
+        use chrono::Weekday;
+
+        let calendar = FiscalCalendar::new(2, 1)
+            .unwrap()
+            .with_week_layout(WeekLayout::new(QuarterPattern::FourFourFive, Weekday::Sun));
+        // The third month of the first quarter is five weeks long
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-03-30T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-04T10:00:00Z",
+            ),
+        ];
+
+        let timeline = ActivityTimeline::from_activity(
+            &commits,
+            &[],
+            &[],
+            Period::utc(date(2024, 2, 4), date(2024, 5, 4)),
+            &calendar,
+            None,
+        );
+
+        let months = timeline.monthly();
+        assert_eq!(months.len(), 3);
+        assert_eq!(months[0].label(), "2024-02");
+        assert_eq!(
+            (months[0].from(), months[0].to()),
+            (date(2024, 2, 4), date(2024, 3, 2))
+        );
+        assert_eq!(months[1].commits(), 1);
+        assert_eq!(months[2].label(), "2024-04");
+        assert_eq!(
+            (months[2].from(), months[2].to()),
+            (date(2024, 3, 31), date(2024, 5, 4))
+        );
+        assert_eq!(months[2].commits(), 1);
+    }
+
+    #[test]
+    fn clips_fiscal_months_to_custom_period() {
+        let timeline = ActivityTimeline::from_activity(
+            &[],
+            &[],
+            &[],
+            Period::utc(date(2024, 5, 10), date(2024, 6, 5)),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        );
+
+        let months = timeline.monthly();
+        assert_eq!(months.len(), 2);
+        assert_eq!(
+            (months[0].label(), months[0].from()),
+            ("2024-05", date(2024, 5, 10))
+        );
+        assert_eq!(
+            (months[1].label(), months[1].to()),
+            ("2024-06", date(2024, 6, 5))
+        );
+    }
+
+    #[test]
+    fn buckets_by_iso_week_clipped_to_period() {
+        // 2024-04-01 is a Monday; 2025-03-31 is a Monday too
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-07T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-08T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2025-03-31T10:00:00Z",
+            ),
+        ];
+
+        let timeline = ActivityTimeline::from_activity(
+            &commits,
+            &[],
+            &[],
+            Period::utc(date(2024, 4, 1), date(2025, 3, 31)),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        );
+
+        let weeks = timeline.weekly();
+        assert_eq!(weeks[0].label(), "2024-W14");
+        assert_eq!(weeks[0].commits(), 1);
+        assert_eq!(weeks[1].label(), "2024-W15");
+        assert_eq!(weeks[1].commits(), 1);
+        let last = weeks.last().unwrap();
+        assert_eq!(last.label(), "2025-W14");
+        assert_eq!(last.from(), date(2025, 3, 31));
+        assert_eq!(last.to(), date(2025, 3, 31));
+        assert_eq!(last.commits(), 1);
+    }
+
+    #[test]
+    fn labels_weeks_by_iso_year_across_new_year() {
+        // 2024-12-30 (Monday) belongs to ISO week 1 of 2025
+        let timeline = ActivityTimeline::from_activity(
+            &[],
+            &[],
+            &[],
+            Period::utc(date(2024, 12, 25), date(2025, 1, 5)),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        );
+
+        let labels: Vec<_> = timeline
+            .weekly()
+            .iter()
+            .map(TimelineBucket::label)
+            .collect();
+        assert_eq!(labels, vec!["2024-W52", "2025-W01"]);
+        assert_eq!(timeline.weekly()[0].from(), date(2024, 12, 25));
+    }
+
+    #[test]
+    fn buckets_by_local_date_of_period_timezone() {
+        // 2024-04-30 20:00 UTC is already May 1st in Tokyo
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add export".to_string(),
+            "John Doe".to_string(),
+            Utc.with_ymd_and_hms(2024, 4, 30, 20, 0, 0).unwrap(),
+            "org/app".to_string(),
+        )];
+
+        let timeline = ActivityTimeline::from_activity(
+            &commits,
+            &[],
+            &[],
+            Period::new(date(2024, 4, 1), date(2025, 3, 31), chrono_tz::Asia::Tokyo),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        );
+
+        assert_eq!(timeline.monthly()[0].commits(), 0);
+        assert_eq!(timeline.monthly()[1].commits(), 1);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::domain::value_objects::commit_classifier::{Classification, ClassificationMethod};
+use serde::{Deserialize, Serialize};
+
+/// Represents how the user's commits were classified into themes
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ClassificationSummary {
+    convention: u32,
+    rule: u32,
+    gitmoji: u32,
+    heuristic: u32,
+    unclassified: u32,
+    heuristic_confidence: Option<f64>,
+}
+
+impl ClassificationSummary {
+    /// Builds a summary from commit classifications
+    pub fn from_classifications(classifications: &[Classification]) -> Self {
+        let count = |method: ClassificationMethod| {
+            classifications
+                .iter()
+                .filter(|classification| classification.method() == method)
+                .count() as u32
+        };
+
+        let heuristic = count(ClassificationMethod::Heuristic);
+        let heuristic_confidence = (heuristic > 0).then(|| {
+            classifications
+                .iter()
+                .filter(|classification| classification.method() == ClassificationMethod::Heuristic)
+                .map(Classification::confidence)
+                .sum::<f64>()
+                / heuristic as f64
+        });
+
+        Self {
+            convention: count(ClassificationMethod::Convention),
+            rule: count(ClassificationMethod::Rule),
+            gitmoji: count(ClassificationMethod::Gitmoji),
+            heuristic,
+            unclassified: count(ClassificationMethod::Unclassified),
+            heuristic_confidence,
+        }
+    }
+
+    /// Returns the number of commits classified by their Conventional Commits type
+    pub fn convention(&self) -> u32 {
+        self.convention
+    }
+
+    /// Returns the number of commits classified by a user-defined rule
+    pub fn rule(&self) -> u32 {
+        self.rule
+    }
+
+    /// Returns the number of commits classified by gitmoji
+    pub fn gitmoji(&self) -> u32 {
+        self.gitmoji
+    }
+
+    /// Returns the number of commits classified by the keyword heuristic
+    pub fn heuristic(&self) -> u32 {
+        self.heuristic
+    }
+
+    /// Returns the number of commits that could not be classified
+    pub fn unclassified(&self) -> u32 {
+        self.unclassified
+    }
+
+    /// Returns the mean confidence of heuristic classifications (0.0 - 1.0)
+    pub fn heuristic_confidence(&self) -> Option<f64> {
+        self.heuristic_confidence
+    }
+
+    /// Returns the total number of classified and unclassified commits
+    pub fn total(&self) -> u32 {
+        self.convention + self.rule + self.gitmoji + self.heuristic + self.unclassified
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::commit_classifier::CommitClassifier;
+
+    #[test]
+    fn counts_classifications_by_method() {
+        let classifier = CommitClassifier::default().with_heuristics(true);
+        let classifications: Vec<_> = [
+            "feat: add export",
+            "fix(api): handle timeout",
+            "🐛 fix crash",
+            "Fix typo",
+            "Update the form",
+            "Merge branch 'main'",
+        ]
+        .iter()
+        .map(|message| classifier.classify_with_method(message))
+        .collect();
+
+        let summary = ClassificationSummary::from_classifications(&classifications);
+
+        assert_eq!(summary.convention(), 2);
+        assert_eq!(summary.rule(), 0);
+        assert_eq!(summary.gitmoji(), 1);
+        assert_eq!(summary.heuristic(), 2);
+        assert_eq!(summary.unclassified(), 1);
+        assert_eq!(summary.total(), 6);
+        // (0.9 + 0.4) / 2
+        let confidence = summary.heuristic_confidence().unwrap();
+        assert!((confidence - 0.65).abs() < 1e-9);
+    }
+
+    #[test]
+    fn has_no_confidence_without_heuristic_classifications() {
+        let summary = ClassificationSummary::from_classifications(&[]);
+
+        assert_eq!(summary.total(), 0);
+        assert_eq!(summary.heuristic_confidence(), None);
+    }
+}
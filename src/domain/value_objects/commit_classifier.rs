@@ -0,0 +1,428 @@
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use crate::domain::value_objects::conventional_commit::ConventionalCommit;
+use crate::domain::value_objects::gitmoji::GitmojiMapping;
+use crate::domain::value_objects::heuristic_guess::HeuristicGuess;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Represents how a classification rule matches a commit subject (the first line)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleMatcher {
+    /// Subject starts with the text (leading whitespace ignored)
+    Prefix(String),
+    /// Subject matches the regular expression
+    Pattern(String),
+}
+
+/// Represents a user-defined rule mapping commit subjects to a theme
+///
+/// The pattern is compiled once here and shared with every classifier built
+/// from the rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "RuleDefinition", into = "RuleDefinition")]
+pub struct ClassificationRule {
+    matcher: RuleMatcher,
+    theme: CommitTheme,
+    compiled: CompiledMatcher,
+}
+
+/// Serialized form of a classification rule
+#[derive(Serialize, Deserialize)]
+struct RuleDefinition {
+    matcher: RuleMatcher,
+    theme: CommitTheme,
+}
+
+impl TryFrom<RuleDefinition> for ClassificationRule {
+    type Error = anyhow::Error;
+
+    fn try_from(definition: RuleDefinition) -> Result<Self> {
+        Self::new(definition.matcher, definition.theme)
+    }
+}
+
+impl From<ClassificationRule> for RuleDefinition {
+    fn from(rule: ClassificationRule) -> Self {
+        Self {
+            matcher: rule.matcher,
+            theme: rule.theme,
+        }
+    }
+}
+
+impl PartialEq for ClassificationRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.matcher == other.matcher && self.theme == other.theme
+    }
+}
+
+impl Eq for ClassificationRule {}
+
+impl ClassificationRule {
+    /// Creates a new ClassificationRule instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regular expression
+    pub fn new(matcher: RuleMatcher, theme: CommitTheme) -> Result<Self> {
+        let compiled = match &matcher {
+            RuleMatcher::Prefix(prefix) => CompiledMatcher::Prefix(prefix.clone()),
+            RuleMatcher::Pattern(pattern) => CompiledMatcher::Pattern(
+                Regex::new(pattern)
+                    .with_context(|| format!("Invalid commit rule pattern: {}", pattern))?,
+            ),
+        };
+        Ok(Self {
+            matcher,
+            theme,
+            compiled,
+        })
+    }
+
+    /// Returns how the rule matches commit subjects
+    #[allow(dead_code)]
+    pub fn matcher(&self) -> &RuleMatcher {
+        &self.matcher
+    }
+
+    /// Returns the theme assigned to matching commits
+    #[allow(dead_code)]
+    pub fn theme(&self) -> &CommitTheme {
+        &self.theme
+    }
+}
+
+/// Represents how a commit's theme was determined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassificationMethod {
+    /// Matched a user-defined rule
+    Rule,
+    /// Conventional Commits type
+    Convention,
+    /// Leading gitmoji
+    Gitmoji,
+    /// Guessed from the leading verb
+    Heuristic,
+    /// Nothing matched; counted as `CommitTheme::Other`
+    Unclassified,
+}
+
+/// Represents the theme of a commit and how it was determined
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classification {
+    theme: CommitTheme,
+    method: ClassificationMethod,
+    confidence: f64,
+}
+
+impl Classification {
+    fn certain(theme: CommitTheme, method: ClassificationMethod) -> Self {
+        Self {
+            theme,
+            method,
+            confidence: 1.0,
+        }
+    }
+
+    /// Returns the theme
+    pub fn theme(&self) -> &CommitTheme {
+        &self.theme
+    }
+
+    /// Returns how the theme was determined
+    pub fn method(&self) -> ClassificationMethod {
+        self.method
+    }
+
+    /// Returns the confidence (1.0 except for heuristic guesses, 0.0 when unclassified)
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+}
+
+/// A rule matcher with its pattern compiled
+#[derive(Debug, Clone)]
+enum CompiledMatcher {
+    Prefix(String),
+    Pattern(Regex),
+}
+
+/// Classifies commit messages into themes
+///
+/// User-defined rules are evaluated in order before the built-in
+/// Conventional Commits mapping; the first matching rule wins.
+/// Subjects that are not Conventional Commits fall back to gitmoji and,
+/// when enabled, to a keyword heuristic.
+#[derive(Debug)]
+pub struct CommitClassifier {
+    rules: Vec<(CompiledMatcher, CommitTheme)>,
+    gitmoji: Vec<GitmojiMapping>,
+    heuristics: bool,
+}
+
+impl Default for CommitClassifier {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            gitmoji: GitmojiMapping::built_in(),
+            heuristics: false,
+        }
+    }
+}
+
+impl CommitClassifier {
+    /// Creates a classifier from user-defined rules
+    ///
+    /// Rule patterns were compiled and validated when the rules were created.
+    pub fn new(rules: &[ClassificationRule]) -> Self {
+        let rules = rules
+            .iter()
+            .map(|rule| (rule.compiled.clone(), rule.theme.clone()))
+            .collect();
+
+        Self {
+            rules,
+            ..Self::default()
+        }
+    }
+
+    /// Adds user-defined gitmoji, taking precedence over the built-in table
+    pub fn with_gitmoji(mut self, mappings: &[GitmojiMapping]) -> Self {
+        self.gitmoji.splice(0..0, mappings.iter().cloned());
+        self
+    }
+
+    /// Enables guessing themes from the leading verb of non-conforming subjects
+    pub fn with_heuristics(mut self, enabled: bool) -> Self {
+        self.heuristics = enabled;
+        self
+    }
+
+    /// Returns the theme of a commit message
+    pub fn classify(&self, message: &str) -> CommitTheme {
+        self.classify_with_method(message).theme
+    }
+
+    /// Returns the theme of a commit message along with how it was determined
+    ///
+    /// Conventional Commits with an unknown type (e.g. `wip:`) fall through to
+    /// the later stages instead of being counted as `Other` right away.
+    pub fn classify_with_method(&self, message: &str) -> Classification {
+        let subject = message.lines().next().unwrap_or("").trim_start();
+
+        if let Some((_, theme)) = self.rules.iter().find(|(matcher, _)| match matcher {
+            CompiledMatcher::Prefix(prefix) => subject.starts_with(prefix.as_str()),
+            CompiledMatcher::Pattern(regex) => regex.is_match(subject),
+        }) {
+            return Classification::certain(theme.clone(), ClassificationMethod::Rule);
+        }
+
+        if let Some(theme) = ConventionalCommit::parse(message)
+            .map(|commit| commit.theme())
+            .filter(|theme| *theme != CommitTheme::Other)
+        {
+            return Classification::certain(theme, ClassificationMethod::Convention);
+        }
+
+        if let Some(theme) = GitmojiMapping::find_theme(subject, &self.gitmoji) {
+            return Classification::certain(theme.clone(), ClassificationMethod::Gitmoji);
+        }
+
+        if let Some(guess) = self
+            .heuristics
+            .then(|| HeuristicGuess::from_subject(subject))
+            .flatten()
+        {
+            return Classification {
+                theme: guess.theme().clone(),
+                method: ClassificationMethod::Heuristic,
+                confidence: guess.confidence(),
+            };
+        }
+
+        Classification {
+            theme: CommitTheme::Other,
+            method: ClassificationMethod::Unclassified,
+            confidence: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::commit_theme::CustomTheme;
+
+    fn ticket_theme() -> CommitTheme {
+        CommitTheme::Custom(CustomTheme::new(
+            "ticket".to_string(),
+            "Ticket Work".to_string(),
+            0,
+        ))
+    }
+
+    #[test]
+    fn falls_back_to_conventional_commits_without_rules() {
+        let classifier = CommitClassifier::default();
+
+        assert_eq!(
+            classifier.classify("feat(api): add endpoint"),
+            CommitTheme::Feat
+        );
+        assert_eq!(
+            classifier.classify("修正: ログイン不具合"),
+            CommitTheme::Other
+        );
+    }
+
+    #[test]
+    fn applies_prefix_and_pattern_rules_before_conventional_commits() {
+        let classifier = CommitClassifier::new(&[
+            ClassificationRule::new(RuleMatcher::Prefix("修正".to_string()), CommitTheme::Fix)
+                .unwrap(),
+            ClassificationRule::new(RuleMatcher::Prefix("追加:".to_string()), CommitTheme::Feat)
+                .unwrap(),
+            ClassificationRule::new(
+                RuleMatcher::Pattern(r"^\[?[A-Z]+-\d+\]?".to_string()),
+                ticket_theme(),
+            )
+            .unwrap(),
+            ClassificationRule::new(
+                RuleMatcher::Pattern("^chore\\(deps\\)".to_string()),
+                CommitTheme::Build,
+            )
+            .unwrap(),
+        ]);
+
+        assert_eq!(
+            classifier.classify("修正: ログイン不具合"),
+            CommitTheme::Fix
+        );
+        assert_eq!(classifier.classify("  追加: CSV出力"), CommitTheme::Feat);
+        assert_eq!(
+            classifier.classify("[PROJ-123] update form"),
+            ticket_theme()
+        );
+        assert_eq!(
+            classifier.classify("chore(deps): bump serde"),
+            CommitTheme::Build
+        );
+        assert_eq!(classifier.classify("chore: tidy"), CommitTheme::Chore);
+        // Rules only look at the subject line
+        assert_eq!(
+            classifier.classify("update form\n\nPROJ-123"),
+            CommitTheme::Other
+        );
+    }
+
+    #[test]
+    fn uses_first_matching_rule() {
+        let classifier = CommitClassifier::new(&[
+            ClassificationRule::new(RuleMatcher::Prefix("fix".to_string()), ticket_theme())
+                .unwrap(),
+            ClassificationRule::new(RuleMatcher::Prefix("fix".to_string()), CommitTheme::Fix)
+                .unwrap(),
+        ]);
+
+        assert_eq!(classifier.classify("fix: typo"), ticket_theme());
+    }
+
+    #[test]
+    fn falls_back_to_gitmoji_for_non_conventional_subjects() {
+        let classifier = CommitClassifier::default()
+            .with_gitmoji(&[GitmojiMapping::new(":alien:".to_string(), ticket_theme())]);
+
+        assert_eq!(classifier.classify("✨ add export"), CommitTheme::Feat);
+        assert_eq!(
+            classifier.classify(":bug: handle timeout"),
+            CommitTheme::Fix
+        );
+        assert_eq!(
+            classifier.classify(":alien: update API client"),
+            ticket_theme()
+        );
+        assert_eq!(classifier.classify("🛸 unknown emoji"), CommitTheme::Other);
+    }
+
+    #[test]
+    fn guesses_themes_only_when_heuristics_are_enabled() {
+        let classifier = CommitClassifier::default();
+        let classification = classifier.classify_with_method("Fix login redirect");
+        assert_eq!(classification.theme(), &CommitTheme::Other);
+        assert_eq!(classification.method(), ClassificationMethod::Unclassified);
+        assert_eq!(classification.confidence(), 0.0);
+
+        let classifier = CommitClassifier::default().with_heuristics(true);
+        let classification = classifier.classify_with_method("Fix login redirect");
+        assert_eq!(classification.theme(), &CommitTheme::Fix);
+        assert_eq!(classification.method(), ClassificationMethod::Heuristic);
+        assert_eq!(classification.confidence(), 0.9);
+    }
+
+    #[test]
+    fn reports_classification_method() {
+        let classifier = CommitClassifier::new(&[ClassificationRule::new(
+            RuleMatcher::Prefix("修正".to_string()),
+            CommitTheme::Fix,
+        )
+        .unwrap()])
+        .with_heuristics(true);
+
+        let method = |message: &str| classifier.classify_with_method(message).method();
+        assert_eq!(method("修正: 不具合"), ClassificationMethod::Rule);
+        assert_eq!(method("fix: typo"), ClassificationMethod::Convention);
+        assert_eq!(method("✨ add export"), ClassificationMethod::Gitmoji);
+        assert_eq!(method("Add export"), ClassificationMethod::Heuristic);
+        // Unknown Conventional Commits types fall through to the heuristic
+        assert_eq!(
+            method("update: bump serde"),
+            ClassificationMethod::Heuristic
+        );
+        assert_eq!(
+            method("wip: add export"),
+            ClassificationMethod::Unclassified
+        );
+        assert_eq!(
+            method("Merge branch 'main'"),
+            ClassificationMethod::Unclassified
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_pattern() {
+        let result = ClassificationRule::new(
+            RuleMatcher::Pattern("([unclosed".to_string()),
+            CommitTheme::Fix,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn keeps_compiled_rules_through_serialization() {
+        let rule = ClassificationRule::new(
+            RuleMatcher::Pattern("^chore\\(deps\\)".to_string()),
+            CommitTheme::Chore,
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&rule).expect("Failed to serialize");
+        let restored: ClassificationRule =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+
+        assert_eq!(restored, rule);
+        let classifier = CommitClassifier::new(&[restored]);
+        assert_eq!(
+            classifier
+                .classify_with_method("chore(deps): bump serde")
+                .method(),
+            ClassificationMethod::Rule
+        );
+        assert!(serde_json::from_str::<ClassificationRule>(
+            r#"{"matcher":{"pattern":"([unclosed"},"theme":"fix"}"#
+        )
+        .is_err());
+    }
+}
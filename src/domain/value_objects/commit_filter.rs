@@ -0,0 +1,264 @@
+use crate::domain::entities::commit::Commit;
+use anyhow::Result;
+use glob::{MatchOptions, Pattern};
+use serde::{Deserialize, Serialize};
+
+/// Represents rules for dropping commits that do not reflect the user's own work
+///
+/// Bot commits are recognized by a `[bot]` login suffix (e.g. `dependabot[bot]`)
+/// or by matching one of `bot_authors` (globs matched case-insensitively against
+/// the author login, or the author name for commits not linked to a GitHub user).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CommitFilter {
+    exclude_bots: bool,
+    #[serde(with = "bot_author_patterns")]
+    bot_authors: Vec<Pattern>,
+    exclude_merge_commits: bool,
+    #[serde(default)]
+    exclude_reverts: bool,
+}
+
+/// Represents the number of commits dropped by a CommitFilter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ExcludedCommits {
+    bots: u32,
+    merges: u32,
+    #[serde(default)]
+    reverts: u32,
+}
+
+impl ExcludedCommits {
+    /// Returns the number of commits dropped as bot commits
+    pub fn bots(&self) -> u32 {
+        self.bots
+    }
+
+    /// Returns the number of commits dropped as merge commits
+    pub fn merges(&self) -> u32 {
+        self.merges
+    }
+
+    /// Returns the number of commits dropped as reverts or reverted commits
+    pub fn reverts(&self) -> u32 {
+        self.reverts
+    }
+
+    /// Sets the number of commits dropped as reverts or reverted commits
+    pub fn with_reverts(mut self, reverts: u32) -> Self {
+        self.reverts = reverts;
+        self
+    }
+
+    /// Returns the total number of dropped commits
+    pub fn total(&self) -> u32 {
+        self.bots + self.merges + self.reverts
+    }
+}
+
+impl CommitFilter {
+    /// Creates a new CommitFilter instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any bot author pattern is not a valid glob
+    pub fn new(
+        exclude_bots: bool,
+        bot_authors: Vec<String>,
+        exclude_merge_commits: bool,
+    ) -> Result<Self> {
+        let bot_authors = bot_author_patterns::compile(bot_authors)?;
+
+        Ok(Self {
+            exclude_bots,
+            bot_authors,
+            exclude_merge_commits,
+            exclude_reverts: false,
+        })
+    }
+
+    /// Sets whether reverts and the commits they reverted are left out (net view)
+    pub fn with_exclude_reverts(mut self, exclude_reverts: bool) -> Self {
+        self.exclude_reverts = exclude_reverts;
+        self
+    }
+
+    /// Returns whether reverts and the commits they reverted are left out
+    pub fn exclude_reverts(&self) -> bool {
+        self.exclude_reverts
+    }
+
+    /// Returns whether the commit was made by a bot
+    pub fn is_bot(&self, commit: &Commit) -> bool {
+        let author = commit.author_login().unwrap_or(commit.author());
+        if author.to_lowercase().ends_with("[bot]") {
+            return true;
+        }
+        let options = MatchOptions {
+            case_sensitive: false,
+            ..MatchOptions::new()
+        };
+        self.bot_authors
+            .iter()
+            .any(|pattern| pattern.matches_with(author, options))
+    }
+
+    /// Splits commits into those kept and counts of those dropped
+    ///
+    /// A commit that is both a bot commit and a merge commit counts as a bot commit.
+    pub fn apply(&self, commits: Vec<Commit>) -> (Vec<Commit>, ExcludedCommits) {
+        let mut excluded = ExcludedCommits::default();
+        let kept = commits
+            .into_iter()
+            .filter(|commit| {
+                if self.exclude_bots && self.is_bot(commit) {
+                    excluded.bots += 1;
+                    false
+                } else if self.exclude_merge_commits && commit.is_merge() {
+                    excluded.merges += 1;
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect();
+
+        (kept, excluded)
+    }
+}
+
+/// Serializes bot author patterns as their glob strings, compiling them once when loaded
+mod bot_author_patterns {
+    use anyhow::{Context, Result};
+    use glob::Pattern;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Compiles the glob patterns
+    pub(super) fn compile(patterns: Vec<String>) -> Result<Vec<Pattern>> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                Pattern::new(pattern)
+                    .with_context(|| format!("Invalid bot author pattern: {}", pattern))
+            })
+            .collect()
+    }
+
+    pub(super) fn serialize<S: Serializer>(
+        patterns: &[Pattern],
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(patterns.iter().map(Pattern::as_str))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Vec<Pattern>, D::Error> {
+        compile(Vec::<String>::deserialize(deserializer)?)
+            .map_err(|e| serde::de::Error::custom(format!("{:#}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commits() -> Vec<Commit> {
+        vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add feature",
+                "org/repo",
+                "2024-01-15T10:30:00Z",
+            )
+            .with_author_login(Some("johndoe".to_string())),
+            Commit::fixture(
+                "abc123",
+                "chore(deps): bump serde",
+                "org/repo",
+                "2024-01-15T10:30:00Z",
+            )
+            .with_author("dependabot[bot]")
+            .with_author_login(Some("dependabot[bot]".to_string())),
+            Commit::fixture(
+                "abc123",
+                "chore: update lockfile",
+                "org/repo",
+                "2024-01-15T10:30:00Z",
+            )
+            .with_author("Renovate Bot"),
+            Commit::fixture(
+                "abc123",
+                "Merge pull request #1 from feature",
+                "org/repo",
+                "2024-01-15T10:30:00Z",
+            )
+            .with_author_login(Some("johndoe".to_string()))
+            .with_parent_count(2),
+        ]
+    }
+
+    #[test]
+    fn keeps_all_commits_by_default() {
+        let (kept, excluded) = CommitFilter::default().apply(commits());
+
+        assert_eq!(kept.len(), 4);
+        assert_eq!(excluded.total(), 0);
+    }
+
+    #[test]
+    fn drops_bot_and_merge_commits() {
+        let filter = CommitFilter::new(true, vec!["renovate*".to_string()], true)
+            .expect("Failed to create filter");
+
+        let (kept, excluded) = filter.apply(commits());
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].message(), "feat: add feature");
+        assert_eq!(excluded.bots(), 2);
+        assert_eq!(excluded.merges(), 1);
+    }
+
+    #[test]
+    fn matches_bot_author_patterns_regardless_of_case() {
+        let filter = CommitFilter::new(
+            true,
+            vec!["Dependabot*".to_string(), "RENOVATE*".to_string()],
+            false,
+        )
+        .expect("Failed to create filter");
+
+        assert!(filter.is_bot(
+            &Commit::fixture("abc123", "chore: bump", "org/repo", "2024-01-15T10:30:00Z")
+                .with_author("x")
+                .with_author_login(Some("dependabot-preview".to_string()))
+        ));
+        assert!(filter.is_bot(
+            &Commit::fixture("abc123", "chore: bump", "org/repo", "2024-01-15T10:30:00Z")
+                .with_author("Renovate Bot")
+        ));
+        assert!(!filter.is_bot(
+            &Commit::fixture("abc123", "feat: add", "org/repo", "2024-01-15T10:30:00Z")
+                .with_author_login(Some("johndoe".to_string()))
+        ));
+    }
+
+    #[test]
+    fn keeps_bot_author_patterns_through_serialization() {
+        let filter = CommitFilter::new(true, vec!["Renovate*".to_string()], false)
+            .expect("Failed to create filter");
+
+        let json = serde_json::to_string(&filter).expect("Failed to serialize filter");
+        assert!(json.contains("\"bot_authors\":[\"Renovate*\"]"));
+        let restored: CommitFilter = serde_json::from_str(&json).expect("Failed to deserialize");
+        assert_eq!(restored, filter);
+        assert!(restored.is_bot(
+            &Commit::fixture("abc123", "chore: bump", "org/repo", "2024-01-15T10:30:00Z")
+                .with_author("renovate-bot")
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_bot_author_patterns() {
+        assert!(CommitFilter::new(true, vec!["[".to_string()], false).is_err());
+    }
+}
@@ -1,8 +1,26 @@
-use serde::{Deserialize, Serialize};
+use crate::domain::value_objects::conventional_commit::ConventionalCommit;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::hash::{Hash, Hasher};
+
+/// Built-in themes in display order, used to resolve short names
+const BUILT_IN_THEMES: [CommitTheme; 11] = [
+    CommitTheme::Feat,
+    CommitTheme::Fix,
+    CommitTheme::Docs,
+    CommitTheme::Refactor,
+    CommitTheme::Test,
+    CommitTheme::Build,
+    CommitTheme::Ci,
+    CommitTheme::Perf,
+    CommitTheme::Style,
+    CommitTheme::Chore,
+    CommitTheme::Other,
+];
 
 /// Represents a commit theme based on Conventional Commits
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// Serialized as its short name (e.g. `"feat"`), so it can be used as a JSON map key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CommitTheme {
     /// New features
     Feat,
@@ -26,34 +44,95 @@ pub enum CommitTheme {
     Chore,
     /// Other/unknown commits
     Other,
+    /// User-defined category from the configuration
+    Custom(CustomTheme),
+}
+
+/// Represents a user-defined commit category
+///
+/// Categories are identified by their key; the display name and order only affect output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomTheme {
+    key: String,
+    display_name: String,
+    order: u32,
+}
+
+impl CustomTheme {
+    /// Creates a new CustomTheme instance
+    pub fn new(key: String, display_name: String, order: u32) -> Self {
+        Self {
+            key,
+            display_name,
+            order,
+        }
+    }
+
+    /// Returns the key used in configuration rules and JSON output
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the display name
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    /// Returns the display order among categories with the same commit count
+    pub fn order(&self) -> u32 {
+        self.order
+    }
+}
+
+impl PartialEq for CustomTheme {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for CustomTheme {}
+
+impl Hash for CustomTheme {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl Serialize for CommitTheme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.short_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for CommitTheme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(CommitTheme::from_short_name(&name)
+            .unwrap_or_else(|| CommitTheme::Custom(CustomTheme::new(name.clone(), name, u32::MAX))))
+    }
 }
 
 impl CommitTheme {
     /// Parses a commit message and extracts the theme
-    #[allow(dead_code)] // Will be used when implementing commit message fetching
+    ///
+    /// Only the Conventional Commits mapping; see `CommitClassifier` for rules and gitmoji.
+    #[allow(dead_code)]
     pub fn from_commit_message(message: &str) -> Self {
-        let message_lower = message.to_lowercase();
-        let prefix = message_lower.split(':').next().unwrap_or("");
-
-        match prefix.trim() {
-            "feat" => CommitTheme::Feat,
-            "fix" => CommitTheme::Fix,
-            "docs" => CommitTheme::Docs,
-            "refactor" => CommitTheme::Refactor,
-            "test" => CommitTheme::Test,
-            "build" => CommitTheme::Build,
-            "ci" => CommitTheme::Ci,
-            "perf" => CommitTheme::Perf,
-            "style" => CommitTheme::Style,
-            "chore" => CommitTheme::Chore,
-            _ => CommitTheme::Other,
-        }
+        ConventionalCommit::parse(message).map_or(CommitTheme::Other, |commit| commit.theme())
+    }
+
+    /// Returns the built-in theme with the given short name (e.g. `"feat"`)
+    pub fn from_short_name(name: &str) -> Option<Self> {
+        BUILT_IN_THEMES
+            .into_iter()
+            .find(|theme| theme.short_name() == name)
     }
 
     /// Returns the display name of the theme
     #[allow(dead_code)]
     pub fn display_name(&self) -> &str {
         match self {
+            CommitTheme::Custom(custom) => custom.display_name(),
             CommitTheme::Feat => "New Features",
             CommitTheme::Fix => "Bug Fixes",
             CommitTheme::Docs => "Documentation",
@@ -72,6 +151,7 @@ impl CommitTheme {
     #[allow(dead_code)] // Will be used when implementing commit message fetching
     pub fn short_name(&self) -> &str {
         match self {
+            CommitTheme::Custom(custom) => custom.key(),
             CommitTheme::Feat => "feat",
             CommitTheme::Fix => "fix",
             CommitTheme::Docs => "docs",
@@ -85,6 +165,30 @@ impl CommitTheme {
             CommitTheme::Other => "other",
         }
     }
+
+    /// Returns the label shown in reports: the short name, or the display name of custom categories
+    pub fn label(&self) -> &str {
+        match self {
+            CommitTheme::Custom(custom) => custom.display_name(),
+            _ => self.short_name(),
+        }
+    }
+
+    /// Returns the position used to order themes with the same commit count
+    ///
+    /// Built-in themes come first in declaration order, followed by custom categories.
+    pub fn sort_order(&self) -> (u32, u32) {
+        match self {
+            CommitTheme::Custom(custom) => (1, custom.order()),
+            _ => (
+                0,
+                BUILT_IN_THEMES
+                    .iter()
+                    .position(|theme| theme == self)
+                    .unwrap_or(0) as u32,
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -139,6 +243,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extracts_theme_from_scoped_and_breaking_commits() {
+        assert_eq!(
+            CommitTheme::from_commit_message("feat(api): add endpoint"),
+            CommitTheme::Feat
+        );
+        assert_eq!(
+            CommitTheme::from_commit_message("feat!: drop legacy output"),
+            CommitTheme::Feat
+        );
+        assert_eq!(
+            CommitTheme::from_commit_message("fix(parser)!: reject empty input"),
+            CommitTheme::Fix
+        );
+    }
+
     #[test]
     fn returns_display_name() {
         assert_eq!(CommitTheme::Feat.display_name(), "New Features");
@@ -146,6 +266,41 @@ mod tests {
         assert_eq!(CommitTheme::Docs.display_name(), "Documentation");
     }
 
+    #[test]
+    fn resolves_built_in_themes_by_short_name() {
+        assert_eq!(
+            CommitTheme::from_short_name("perf"),
+            Some(CommitTheme::Perf)
+        );
+        assert_eq!(
+            CommitTheme::from_short_name("other"),
+            Some(CommitTheme::Other)
+        );
+        assert_eq!(CommitTheme::from_short_name("ticket"), None);
+    }
+
+    #[test]
+    fn serializes_custom_theme_as_map_key() {
+        let custom = CommitTheme::Custom(CustomTheme::new(
+            "ticket".to_string(),
+            "Ticket Work".to_string(),
+            1,
+        ));
+        assert_eq!(custom.short_name(), "ticket");
+        assert_eq!(custom.label(), "Ticket Work");
+
+        let mut summary = std::collections::HashMap::new();
+        summary.insert(custom.clone(), 3);
+        summary.insert(CommitTheme::Feat, 2);
+
+        let json = serde_json::to_string(&summary).expect("Failed to serialize");
+        let deserialized: std::collections::HashMap<CommitTheme, u32> =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+
+        assert_eq!(deserialized.get(&custom), Some(&3));
+        assert_eq!(deserialized.get(&CommitTheme::Feat), Some(&2));
+    }
+
     #[test]
     fn returns_short_name() {
         assert_eq!(CommitTheme::Feat.short_name(), "feat");
@@ -0,0 +1,164 @@
+use crate::domain::entities::commit::Commit;
+use crate::domain::value_objects::working_hours::WorkingHours;
+use chrono::{Datelike, Timelike};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Weekday labels in the order of `by_weekday`
+pub const WEEKDAY_LABELS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Represents when the user's commits were made, in the department's local time
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CommitTimeDistribution {
+    timezone: String,
+    working_hours: WorkingHours,
+    by_hour: Vec<u32>,
+    by_weekday: Vec<u32>,
+    outside_working_hours: u32,
+}
+
+impl CommitTimeDistribution {
+    /// Builds hour-of-day and weekday histograms of commit times in the given timezone
+    pub fn from_commits(commits: &[Commit], timezone: Tz, working_hours: WorkingHours) -> Self {
+        let mut by_hour = vec![0; 24];
+        let mut by_weekday = vec![0; 7];
+        let mut outside_working_hours = 0;
+
+        for commit in commits {
+            let local = commit.committed_date().with_timezone(&timezone);
+            let weekday = local.weekday();
+            by_hour[local.hour() as usize] += 1;
+            by_weekday[weekday.num_days_from_monday() as usize] += 1;
+            if !working_hours.contains(weekday, local.hour()) {
+                outside_working_hours += 1;
+            }
+        }
+
+        Self {
+            timezone: timezone.name().to_string(),
+            working_hours,
+            by_hour,
+            by_weekday,
+            outside_working_hours,
+        }
+    }
+
+    /// Returns the IANA timezone the histograms are computed in
+    pub fn timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    /// Returns the working hours the outside share is measured against
+    pub fn working_hours(&self) -> WorkingHours {
+        self.working_hours
+    }
+
+    /// Returns the number of commits per local hour (index 0 is 00:00-00:59)
+    pub fn by_hour(&self) -> &[u32] {
+        &self.by_hour
+    }
+
+    /// Returns the number of commits per local weekday, Monday first
+    pub fn by_weekday(&self) -> &[u32] {
+        &self.by_weekday
+    }
+
+    /// Returns the number of commits made outside working hours, including weekends
+    pub fn outside_working_hours(&self) -> u32 {
+        self.outside_working_hours
+    }
+
+    /// Returns the share of commits made outside working hours (0-100), if there are commits
+    pub fn outside_working_hours_percentage(&self) -> Option<f64> {
+        let total = self.total();
+        (total > 0).then(|| self.outside_working_hours as f64 / total as f64 * 100.0)
+    }
+
+    /// Returns the number of commits in the histograms
+    pub fn total(&self) -> u32 {
+        self.by_hour.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_commit_times_in_local_timezone() {
+        // 2024-05-01 01:00 UTC is Wednesday 10:00 in Tokyo
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T01:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T02:00:00Z",
+            ),
+        ];
+
+        let distribution = CommitTimeDistribution::from_commits(
+            &commits,
+            chrono_tz::Asia::Tokyo,
+            WorkingHours::default(),
+        );
+
+        assert_eq!(distribution.timezone(), "Asia/Tokyo");
+        assert_eq!(distribution.by_hour()[10], 1);
+        assert_eq!(distribution.by_hour()[11], 1);
+        assert_eq!(distribution.by_hour()[1], 0);
+        assert_eq!(distribution.by_weekday()[2], 2);
+        assert_eq!(distribution.outside_working_hours(), 0);
+    }
+
+    #[test]
+    fn counts_evenings_and_weekends_as_outside_working_hours() {
+        // Tokyo: Wed 10:00, Wed 21:00, Sat 11:00 (2024-05-04 is a Saturday)
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T01:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T12:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-05-04T02:00:00Z",
+            ),
+        ];
+
+        let distribution = CommitTimeDistribution::from_commits(
+            &commits,
+            chrono_tz::Asia::Tokyo,
+            WorkingHours::default(),
+        );
+
+        assert_eq!(distribution.total(), 3);
+        assert_eq!(distribution.by_weekday()[5], 1);
+        assert_eq!(distribution.outside_working_hours(), 2);
+        let percentage = distribution.outside_working_hours_percentage().unwrap();
+        assert!((percentage - 66.666).abs() < 0.01);
+    }
+
+    #[test]
+    fn has_no_share_without_commits() {
+        let distribution =
+            CommitTimeDistribution::from_commits(&[], chrono_tz::UTC, WorkingHours::default());
+
+        assert_eq!(distribution.by_hour().len(), 24);
+        assert_eq!(distribution.outside_working_hours_percentage(), None);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::domain::entities::report::Report;
+use crate::domain::value_objects::pull_request_summary::PullRequestSummary;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Represents the change of one metric between the previous and current period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Delta {
+    label: String,
+    current: u32,
+    previous: u32,
+    change: i64,
+    /// `None` when the previous value is zero
+    percent_change: Option<f64>,
+}
+
+impl Delta {
+    /// Creates a new Delta instance
+    pub fn new(label: String, current: u32, previous: u32) -> Self {
+        let change = current as i64 - previous as i64;
+        let percent_change = (previous > 0).then(|| change as f64 / previous as f64 * 100.0);
+        Self {
+            label,
+            current,
+            previous,
+            change,
+            percent_change,
+        }
+    }
+
+    /// Compares the organization activity totals and the user's commits of two reports
+    ///
+    /// Pull requests are compared by those authored and merged within each period;
+    /// the activity summary's pull request total spans the account's lifetime.
+    pub fn metrics(current: &Report, previous: &Report) -> Vec<Self> {
+        let your_commits = |report: &Report| report.theme_summary().values().sum::<u32>();
+        let authored = |report: &Report| {
+            report
+                .pull_request_summary()
+                .map_or(0, PullRequestSummary::authored)
+        };
+        let merged = |report: &Report| {
+            report
+                .pull_request_summary()
+                .map_or(0, PullRequestSummary::merged)
+        };
+        vec![
+            Delta::new(
+                "Total Commits".to_string(),
+                current.github_activity().commits(),
+                previous.github_activity().commits(),
+            ),
+            Delta::new(
+                "Pull Requests Authored".to_string(),
+                authored(current),
+                authored(previous),
+            ),
+            Delta::new(
+                "Pull Requests Merged".to_string(),
+                merged(current),
+                merged(previous),
+            ),
+            Delta::new(
+                "Total Issues".to_string(),
+                current.github_activity().issues(),
+                previous.github_activity().issues(),
+            ),
+            Delta::new(
+                "Total Reviews".to_string(),
+                current.github_activity().reviews(),
+                previous.github_activity().reviews(),
+            ),
+            Delta::new(
+                "Your Commits".to_string(),
+                your_commits(current),
+                your_commits(previous),
+            ),
+        ]
+    }
+
+    /// Compares the commit themes of two reports, in theme order
+    pub fn themes(current: &Report, previous: &Report) -> Vec<Self> {
+        let mut themes = Vec::new();
+        for theme in current
+            .theme_summary()
+            .keys()
+            .chain(previous.theme_summary().keys())
+        {
+            if !themes.contains(&theme) {
+                themes.push(theme);
+            }
+        }
+        themes.sort_by_key(|theme| theme.sort_order());
+        themes
+            .into_iter()
+            .map(|theme| {
+                Delta::new(
+                    theme.label().to_string(),
+                    current.theme_summary().get(theme).copied().unwrap_or(0),
+                    previous.theme_summary().get(theme).copied().unwrap_or(0),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the metric or theme label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the value in the current period
+    pub fn current(&self) -> u32 {
+        self.current
+    }
+
+    /// Returns the value in the previous period
+    pub fn previous(&self) -> u32 {
+        self.previous
+    }
+
+    /// Returns the absolute change from the previous period
+    pub fn change(&self) -> i64 {
+        self.change
+    }
+
+    /// Returns the change as a percentage of the previous value, if it was not zero
+    #[allow(dead_code)]
+    pub fn percent_change(&self) -> Option<f64> {
+        self.percent_change
+    }
+
+    /// Formats the change with an arrow and percentage (e.g., "▲ +20 (+20.0%)", "▼ -1 (-25.0%)")
+    ///
+    /// The percentage is "n/a" when the previous value was zero.
+    pub fn format_change(&self) -> String {
+        let percent = self
+            .percent_change
+            .map_or_else(|| "n/a".to_string(), |p| format!("{:+.1}%", p));
+        match self.change.cmp(&0) {
+            Ordering::Greater => format!("▲ +{} ({})", self.change, percent),
+            Ordering::Less => format!("▼ {} ({})", self.change, percent),
+            Ordering::Equal => "±0".to_string(),
+        }
+    }
+}
+
+/// Represents how a report compares with the same period of the previous fiscal year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comparison {
+    previous_label: String,
+    previous_from: NaiveDate,
+    previous_to: NaiveDate,
+    metrics: Vec<Delta>,
+    themes: Vec<Delta>,
+}
+
+impl Comparison {
+    /// Compares the activity metrics and commit themes of two reports
+    pub fn between(current: &Report, previous: &Report) -> Self {
+        Self {
+            previous_label: previous.period_label().to_string(),
+            previous_from: previous.period_from(),
+            previous_to: previous.period_to(),
+            metrics: Delta::metrics(current, previous),
+            themes: Delta::themes(current, previous),
+        }
+    }
+
+    /// Returns the label of the previous period (e.g., "FY2023")
+    pub fn previous_label(&self) -> &str {
+        &self.previous_label
+    }
+
+    /// Returns the first day of the previous period
+    pub fn previous_from(&self) -> NaiveDate {
+        self.previous_from
+    }
+
+    /// Returns the last day of the previous period
+    pub fn previous_to(&self) -> NaiveDate {
+        self.previous_to
+    }
+
+    /// Returns the changes of the activity metrics
+    pub fn metrics(&self) -> &[Delta] {
+        &self.metrics
+    }
+
+    /// Returns the changes of the commit themes, in theme order
+    pub fn themes(&self) -> &[Delta] {
+        &self.themes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::entities::pull_request::PullRequest;
+    use crate::domain::value_objects::commit_theme::CommitTheme;
+    use crate::domain::value_objects::period::Period;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+
+    fn report(year: u32, activity: GitHubActivity, themes: Vec<(CommitTheme, u32)>) -> Report {
+        let from = NaiveDate::from_ymd_opt(year as i32, 4, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).unwrap();
+        // One pull request merged in FY2023 and two left open in FY2024
+        let pull_requests: Vec<PullRequest> = [(2023, 5, true), (2024, 5, false), (2024, 6, false)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (year, month, merged))| {
+                let created_at = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap();
+                PullRequest::new(
+                    i as u32 + 1,
+                    "feat: change".to_string(),
+                    "org/app".to_string(),
+                    "user".to_string(),
+                    created_at,
+                )
+                .with_lifecycle(merged.then_some(created_at), None)
+            })
+            .collect();
+        Report::new(
+            year,
+            "Personal".to_string(),
+            from,
+            to,
+            activity,
+            vec![],
+            themes.into_iter().collect::<HashMap<_, _>>(),
+        )
+        .with_pull_request_summary(PullRequestSummary::from_pull_requests(
+            &pull_requests,
+            Period::utc(from, to),
+        ))
+    }
+
+    #[test]
+    fn computes_absolute_and_percentage_changes() {
+        let increase = Delta::new("Commits".to_string(), 120, 100);
+        assert_eq!(increase.change(), 20);
+        assert_eq!(increase.percent_change(), Some(20.0));
+
+        let decrease = Delta::new("Reviews".to_string(), 3, 4);
+        assert_eq!(decrease.change(), -1);
+        assert_eq!(decrease.percent_change(), Some(-25.0));
+
+        let new = Delta::new("Issues".to_string(), 5, 0);
+        assert_eq!(new.change(), 5);
+        assert_eq!(new.percent_change(), None);
+
+        assert_eq!(increase.format_change(), "▲ +20 (+20.0%)");
+        assert_eq!(decrease.format_change(), "▼ -1 (-25.0%)");
+        assert_eq!(new.format_change(), "▲ +5 (n/a)");
+        assert_eq!(Delta::new("PRs".to_string(), 2, 2).format_change(), "±0");
+    }
+
+    #[test]
+    fn compares_metrics_and_themes_of_both_reports() {
+        let current = report(
+            2024,
+            GitHubActivity::new(120, 10, 4, 3),
+            vec![(CommitTheme::Fix, 2), (CommitTheme::Docs, 1)],
+        );
+        let previous = report(
+            2023,
+            GitHubActivity::new(100, 10, 0, 4),
+            vec![(CommitTheme::Feat, 3), (CommitTheme::Fix, 1)],
+        );
+
+        let comparison = Comparison::between(&current, &previous);
+
+        assert_eq!(comparison.previous_label(), "FY2023");
+        assert_eq!(
+            comparison.previous_from(),
+            NaiveDate::from_ymd_opt(2023, 4, 1).unwrap()
+        );
+        let metrics: Vec<_> = comparison
+            .metrics()
+            .iter()
+            .map(|delta| (delta.label(), delta.change()))
+            .collect();
+        assert_eq!(
+            metrics,
+            vec![
+                ("Total Commits", 20),
+                ("Pull Requests Authored", 1),
+                ("Pull Requests Merged", -1),
+                ("Total Issues", 4),
+                ("Total Reviews", -1),
+                ("Your Commits", -1),
+            ]
+        );
+        let themes: Vec<_> = comparison
+            .themes()
+            .iter()
+            .map(|delta| (delta.label(), delta.current(), delta.previous()))
+            .collect();
+        assert_eq!(themes, vec![("feat", 0, 3), ("fix", 2, 1), ("docs", 1, 0)]);
+    }
+}
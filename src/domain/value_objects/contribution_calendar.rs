@@ -0,0 +1,258 @@
+use crate::domain::entities::commit::Commit;
+use crate::domain::value_objects::period::Period;
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Represents the number of contributions made on one day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyContribution {
+    date: NaiveDate,
+    count: u32,
+}
+
+impl DailyContribution {
+    /// Creates a new DailyContribution instance
+    pub fn new(date: NaiveDate, count: u32) -> Self {
+        Self { date, count }
+    }
+
+    /// Returns the day
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the number of contributions
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+/// Represents per-day contribution counts over the period, with streaks
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ContributionCalendar {
+    days: BTreeMap<NaiveDate, u32>,
+    longest_streak: u32,
+    current_streak: u32,
+    most_active_day: Option<DailyContribution>,
+}
+
+impl ContributionCalendar {
+    /// Builds a calendar by counting commits per local day of the period's timezone
+    ///
+    /// See `from_daily_counts` for how the period and `as_of` are used.
+    pub fn from_commits(commits: &[Commit], period: Period, as_of: NaiveDate) -> Self {
+        let mut counts: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+        for commit in commits {
+            *counts
+                .entry(period.local_date(commit.committed_date()))
+                .or_insert(0) += 1;
+        }
+        let counts: Vec<DailyContribution> = counts
+            .into_iter()
+            .map(|(date, count)| DailyContribution::new(date, count))
+            .collect();
+        Self::from_daily_counts(&counts, period, as_of)
+    }
+
+    /// Builds a calendar from per-day counts, such as GitHub's contribution calendar
+    ///
+    /// Every day of the period is present, with zero for days without
+    /// contributions; counts outside the period are ignored. The current streak
+    /// ends at `as_of` (usually today, or the end of a past period), and a day
+    /// without contributions on `as_of` itself does not break it yet.
+    pub fn from_daily_counts(
+        counts: &[DailyContribution],
+        period: Period,
+        as_of: NaiveDate,
+    ) -> Self {
+        let mut days: BTreeMap<NaiveDate, u32> = period
+            .from()
+            .iter_days()
+            .take_while(|date| *date <= period.to())
+            .map(|date| (date, 0))
+            .collect();
+        for contribution in counts {
+            if let Some(count) = days.get_mut(&contribution.date) {
+                *count += contribution.count;
+            }
+        }
+
+        let mut longest_streak = 0;
+        let mut streak = 0;
+        for &count in days.values() {
+            streak = if count > 0 { streak + 1 } else { 0 };
+            longest_streak = longest_streak.max(streak);
+        }
+
+        let as_of = as_of.min(period.to());
+        let mut day = if days.get(&as_of).copied().unwrap_or(0) > 0 {
+            as_of
+        } else {
+            as_of - Duration::days(1)
+        };
+        let mut current_streak = 0;
+        while days.get(&day).copied().unwrap_or(0) > 0 {
+            current_streak += 1;
+            day -= Duration::days(1);
+        }
+
+        // The earliest of the busiest days
+        let most_active_day = days
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(&date, &count)| DailyContribution::new(date, count));
+
+        Self {
+            days,
+            longest_streak,
+            current_streak,
+            most_active_day,
+        }
+    }
+
+    /// Returns the number of contributions per day, for every day of the period
+    pub fn days(&self) -> &BTreeMap<NaiveDate, u32> {
+        &self.days
+    }
+
+    /// Returns the longest run of consecutive days with contributions
+    pub fn longest_streak(&self) -> u32 {
+        self.longest_streak
+    }
+
+    /// Returns the run of consecutive days with contributions up to the end of the period or today
+    pub fn current_streak(&self) -> u32 {
+        self.current_streak
+    }
+
+    /// Returns the day with the most contributions, if any
+    pub fn most_active_day(&self) -> Option<DailyContribution> {
+        self.most_active_day
+    }
+
+    /// Returns the total number of contributions
+    pub fn total(&self) -> u32 {
+        self.days.values().sum()
+    }
+
+    /// Returns true if the calendar covers no days
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn counts_commits_per_day_for_every_day_of_period() {
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-02T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-02T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-04-05T10:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add export",
+                "org/app",
+                "2024-03-31T10:00:00Z",
+            ),
+        ];
+
+        let calendar = ContributionCalendar::from_commits(
+            &commits,
+            Period::utc(date(4, 1), date(4, 30)),
+            date(4, 30),
+        );
+
+        assert_eq!(calendar.days().len(), 30);
+        assert_eq!(calendar.days().get(&date(4, 1)), Some(&0));
+        assert_eq!(calendar.days().get(&date(4, 2)), Some(&2));
+        // Commits outside the period are ignored
+        assert_eq!(calendar.total(), 3);
+        assert_eq!(
+            calendar.most_active_day(),
+            Some(DailyContribution::new(date(4, 2), 2))
+        );
+    }
+
+    #[test]
+    fn calculates_longest_and_current_streaks() {
+        let counts: Vec<_> = [2, 3, 4, 10, 11, 13, 14]
+            .into_iter()
+            .map(|day| DailyContribution::new(date(4, day), 1))
+            .collect();
+
+        let calendar = ContributionCalendar::from_daily_counts(
+            &counts,
+            Period::utc(date(4, 1), date(4, 30)),
+            date(4, 14),
+        );
+
+        assert_eq!(calendar.longest_streak(), 3);
+        assert_eq!(calendar.current_streak(), 2);
+    }
+
+    #[test]
+    fn does_not_break_current_streak_on_a_quiet_today() {
+        let counts = vec![
+            DailyContribution::new(date(4, 12), 1),
+            DailyContribution::new(date(4, 13), 1),
+        ];
+
+        let today_quiet = ContributionCalendar::from_daily_counts(
+            &counts,
+            Period::utc(date(4, 1), date(4, 30)),
+            date(4, 14),
+        );
+        let gap = ContributionCalendar::from_daily_counts(
+            &counts,
+            Period::utc(date(4, 1), date(4, 30)),
+            date(4, 15),
+        );
+
+        assert_eq!(today_quiet.current_streak(), 2);
+        assert_eq!(gap.current_streak(), 0);
+    }
+
+    #[test]
+    fn picks_earliest_of_busiest_days() {
+        let counts = vec![
+            DailyContribution::new(date(4, 20), 5),
+            DailyContribution::new(date(4, 3), 5),
+        ];
+
+        let calendar = ContributionCalendar::from_daily_counts(
+            &counts,
+            Period::utc(date(4, 1), date(4, 30)),
+            date(4, 30),
+        );
+
+        assert_eq!(
+            calendar.most_active_day().map(|d| d.date()),
+            Some(date(4, 3))
+        );
+        assert_eq!(calendar.longest_streak(), 1);
+    }
+}
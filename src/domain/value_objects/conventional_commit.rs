@@ -0,0 +1,454 @@
+use crate::domain::entities::commit::Commit;
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Footer tokens that mark a breaking change
+const BREAKING_CHANGE_TOKENS: [&str; 2] = ["BREAKING CHANGE", "BREAKING-CHANGE"];
+
+/// Represents a trailer such as `Refs: #123` or `BREAKING CHANGE: ...`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitFooter {
+    token: String,
+    value: String,
+}
+
+impl CommitFooter {
+    /// Returns the footer token (e.g. `Refs`, `BREAKING CHANGE`)
+    #[allow(dead_code)]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the footer value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns whether this footer describes a breaking change
+    pub fn is_breaking_change(&self) -> bool {
+        BREAKING_CHANGE_TOKENS.contains(&self.token.as_str())
+    }
+}
+
+/// Represents a commit message structured by the Conventional Commits specification
+///
+/// `<type>[(scope)][!]: <description>`, followed by an optional body and footers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit {
+    commit_type: String,
+    scope: Option<String>,
+    breaking: bool,
+    description: String,
+    body: Option<String>,
+    footers: Vec<CommitFooter>,
+}
+
+impl ConventionalCommit {
+    /// Parses a commit message, returning `None` if the header is not conventional
+    pub fn parse(message: &str) -> Option<Self> {
+        let mut lines = message.lines();
+        let header = lines.next()?.trim();
+        let (prefix, description) = header.split_once(':')?;
+
+        let prefix = prefix.trim_end();
+        let (prefix, bang) = match prefix.strip_suffix('!') {
+            Some(prefix) => (prefix, true),
+            None => (prefix, false),
+        };
+        let (commit_type, scope) = match prefix.split_once('(') {
+            Some((commit_type, rest)) => {
+                let scope = rest.strip_suffix(')')?.trim();
+                if scope.is_empty() || scope.contains(['(', ')']) {
+                    return None;
+                }
+                (commit_type, Some(scope.to_string()))
+            }
+            None => (prefix, None),
+        };
+        if commit_type.is_empty()
+            || !commit_type
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return None;
+        }
+
+        let (body, footers) = Self::parse_body_and_footers(&lines.collect::<Vec<_>>());
+        let breaking = bang || footers.iter().any(CommitFooter::is_breaking_change);
+
+        Some(Self {
+            commit_type: commit_type.to_lowercase(),
+            scope,
+            breaking,
+            description: description.trim().to_string(),
+            body,
+            footers,
+        })
+    }
+
+    /// Splits the lines after the header into a free-form body and trailing footers
+    ///
+    /// Footers start at the first footer-looking line that follows a blank line;
+    /// lines that do not start a new footer continue the previous footer's value.
+    fn parse_body_and_footers(lines: &[&str]) -> (Option<String>, Vec<CommitFooter>) {
+        let footer_start = (0..lines.len()).find(|&i| {
+            Self::parse_footer_line(lines[i]).is_some()
+                && (i == 0 || lines[i - 1].trim().is_empty())
+        });
+        let (body_lines, footer_lines) = lines.split_at(footer_start.unwrap_or(lines.len()));
+
+        let body = body_lines.join("\n").trim().to_string();
+        let body = (!body.is_empty()).then_some(body);
+
+        let mut footers: Vec<CommitFooter> = Vec::new();
+        for line in footer_lines {
+            match (Self::parse_footer_line(line), footers.last_mut()) {
+                (Some(footer), _) => footers.push(footer),
+                (None, Some(last)) => {
+                    last.value.push('\n');
+                    last.value.push_str(line);
+                }
+                (None, None) => {}
+            }
+        }
+        for footer in &mut footers {
+            footer.value = footer.value.trim().to_string();
+        }
+
+        (body, footers)
+    }
+
+    /// Parses `Token: value` or `Token #value`, returning `None` for other lines
+    fn parse_footer_line(line: &str) -> Option<CommitFooter> {
+        for token in BREAKING_CHANGE_TOKENS {
+            if let Some(value) = line
+                .strip_prefix(token)
+                .and_then(|rest| rest.strip_prefix(": "))
+            {
+                return Some(CommitFooter {
+                    token: token.to_string(),
+                    value: value.to_string(),
+                });
+            }
+        }
+
+        let (token, value) = line.split_once(": ").or_else(|| {
+            line.split_once(" #")
+                .map(|(token, _)| (token, &line[token.len() + 1..]))
+        })?;
+        let is_word_token =
+            !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        is_word_token.then(|| CommitFooter {
+            token: token.to_string(),
+            value: value.to_string(),
+        })
+    }
+
+    /// Returns the commit type, lowercased (e.g. `feat`)
+    #[allow(dead_code)]
+    pub fn commit_type(&self) -> &str {
+        &self.commit_type
+    }
+
+    /// Returns the scope in parentheses, if any
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    /// Returns whether the commit introduces a breaking change (`!` or a BREAKING CHANGE footer)
+    #[allow(dead_code)]
+    pub fn is_breaking(&self) -> bool {
+        self.breaking
+    }
+
+    /// Returns the description following the colon
+    #[allow(dead_code)]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the body between the header and the footers
+    #[allow(dead_code)]
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Returns the footers
+    #[allow(dead_code)]
+    pub fn footers(&self) -> &[CommitFooter] {
+        &self.footers
+    }
+
+    /// Returns the theme corresponding to the commit type
+    pub fn theme(&self) -> CommitTheme {
+        CommitTheme::from_short_name(&self.commit_type).unwrap_or(CommitTheme::Other)
+    }
+
+    /// Returns the breaking change note, preferring the footer over the description
+    pub fn breaking_change_note(&self) -> Option<&str> {
+        if !self.breaking {
+            return None;
+        }
+        Some(
+            self.footers
+                .iter()
+                .find(|footer| footer.is_breaking_change())
+                .map_or(self.description.as_str(), CommitFooter::value),
+        )
+    }
+}
+
+/// Represents a breaking change introduced by one of the user's commits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreakingChange {
+    repository: String,
+    sha: String,
+    committed_date: DateTime<Utc>,
+    scope: Option<String>,
+    note: String,
+}
+
+impl BreakingChange {
+    /// Collects breaking changes from commits, oldest first
+    pub fn from_commits(commits: &[Commit]) -> Vec<Self> {
+        let mut changes: Vec<Self> = commits
+            .iter()
+            .filter_map(|commit| {
+                let parsed = ConventionalCommit::parse(commit.message())?;
+                let note = parsed.breaking_change_note()?.to_string();
+                Some(Self {
+                    repository: commit.repository().to_string(),
+                    sha: commit.sha().to_string(),
+                    committed_date: commit.committed_date(),
+                    scope: parsed.scope().map(str::to_string),
+                    note,
+                })
+            })
+            .collect();
+        changes.sort_by_key(|change| change.committed_date);
+        changes
+    }
+
+    /// Returns the repository name
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the commit SHA
+    #[allow(dead_code)]
+    pub fn sha(&self) -> &str {
+        &self.sha
+    }
+
+    /// Returns the abbreviated commit SHA
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(7)]
+    }
+
+    /// Returns the committed date
+    pub fn committed_date(&self) -> DateTime<Utc> {
+        self.committed_date
+    }
+
+    /// Returns the scope of the commit, if any
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    /// Returns the breaking change description
+    pub fn note(&self) -> &str {
+        &self.note
+    }
+}
+
+/// Represents how many of the user's commits touched a Conventional Commits scope
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeCount {
+    scope: String,
+    count: u32,
+}
+
+impl ScopeCount {
+    /// Counts commits per scope (case-insensitive), most frequent first
+    pub fn from_commits(commits: &[Commit]) -> Vec<Self> {
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        for commit in commits {
+            if let Some(scope) = ConventionalCommit::parse(commit.message())
+                .as_ref()
+                .and_then(ConventionalCommit::scope)
+            {
+                *counts.entry(scope.to_lowercase()).or_insert(0) += 1;
+            }
+        }
+
+        let mut scopes: Vec<Self> = counts
+            .into_iter()
+            .map(|(scope, count)| Self { scope, count })
+            .collect();
+        scopes.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.scope.cmp(&b.scope)));
+        scopes
+    }
+
+    /// Returns the scope name
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Returns the number of commits
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_type_scope_and_description() {
+        let parsed = ConventionalCommit::parse("feat(api): add export endpoint").unwrap();
+
+        assert_eq!(parsed.commit_type(), "feat");
+        assert_eq!(parsed.scope(), Some("api"));
+        assert!(!parsed.is_breaking());
+        assert_eq!(parsed.description(), "add export endpoint");
+        assert_eq!(parsed.body(), None);
+        assert!(parsed.footers().is_empty());
+        assert_eq!(parsed.theme(), CommitTheme::Feat);
+    }
+
+    #[test]
+    fn detects_breaking_change_from_bang() {
+        let parsed = ConventionalCommit::parse("feat(api)!: drop v1 endpoints").unwrap();
+
+        assert!(parsed.is_breaking());
+        assert_eq!(parsed.scope(), Some("api"));
+        assert_eq!(parsed.breaking_change_note(), Some("drop v1 endpoints"));
+
+        let parsed = ConventionalCommit::parse("refactor!: rename config keys").unwrap();
+        assert!(parsed.is_breaking());
+        assert_eq!(parsed.scope(), None);
+        assert_eq!(parsed.theme(), CommitTheme::Refactor);
+    }
+
+    #[test]
+    fn parses_body_and_footers() {
+        let message = "fix: handle empty input\n\nThe parser used to panic on empty files.\nNow it returns an error.\n\nRefs #123\nReviewed-by: Jane\nBREAKING CHANGE: parse now returns Result\n  instead of panicking";
+        let parsed = ConventionalCommit::parse(message).unwrap();
+
+        assert_eq!(
+            parsed.body(),
+            Some("The parser used to panic on empty files.\nNow it returns an error.")
+        );
+        let footers: Vec<_> = parsed
+            .footers()
+            .iter()
+            .map(|f| (f.token(), f.value()))
+            .collect();
+        assert_eq!(
+            footers,
+            vec![
+                ("Refs", "#123"),
+                ("Reviewed-by", "Jane"),
+                (
+                    "BREAKING CHANGE",
+                    "parse now returns Result\n  instead of panicking"
+                ),
+            ]
+        );
+        assert!(parsed.is_breaking());
+        assert_eq!(
+            parsed.breaking_change_note(),
+            Some("parse now returns Result\n  instead of panicking")
+        );
+    }
+
+    #[test]
+    fn does_not_treat_body_lines_with_colons_as_footers() {
+        let message = "docs: explain setup\n\nSee the wiki: it has details.";
+        let parsed = ConventionalCommit::parse(message).unwrap();
+
+        assert_eq!(parsed.body(), Some("See the wiki: it has details."));
+        assert!(parsed.footers().is_empty());
+    }
+
+    #[test]
+    fn rejects_non_conventional_headers() {
+        assert_eq!(ConventionalCommit::parse("add new feature"), None);
+        assert_eq!(
+            ConventionalCommit::parse("Merge pull request #1 from org/feature"),
+            None
+        );
+        assert_eq!(
+            ConventionalCommit::parse("Revert \"feat: add export\""),
+            None
+        );
+        assert_eq!(ConventionalCommit::parse("feat(): empty scope"), None);
+        assert_eq!(ConventionalCommit::parse(""), None);
+    }
+
+    #[test]
+    fn collects_breaking_changes_oldest_first() {
+        let commits = vec![
+            Commit::fixture(
+                "bbbbbbbbbb",
+                "feat(cli)!: remove --legacy flag",
+                "org/app",
+                "2024-05-20T10:00:00Z",
+            ),
+            Commit::fixture("cccccccccc", "fix: typo", "org/app", "2024-05-10T10:00:00Z"),
+            Commit::fixture(
+                "aaaaaaaaaa",
+                "chore: bump msrv\n\nBREAKING CHANGE: requires Rust 1.80",
+                "org/app",
+                "2024-05-05T10:00:00Z",
+            ),
+        ];
+
+        let changes = BreakingChange::from_commits(&commits);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].short_sha(), "aaaaaaa");
+        assert_eq!(changes[0].note(), "requires Rust 1.80");
+        assert_eq!(changes[0].scope(), None);
+        assert_eq!(changes[1].note(), "remove --legacy flag");
+        assert_eq!(changes[1].scope(), Some("cli"));
+        assert_eq!(changes[1].repository(), "org/app");
+    }
+
+    #[test]
+    fn counts_commits_per_scope() {
+        let commits = vec![
+            Commit::fixture(
+                "a",
+                "feat(api): add endpoint",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            ),
+            Commit::fixture(
+                "b",
+                "fix(API): handle timeout",
+                "org/app",
+                "2024-05-02T10:00:00Z",
+            ),
+            Commit::fixture(
+                "c",
+                "docs(readme): update",
+                "org/app",
+                "2024-05-03T10:00:00Z",
+            ),
+            Commit::fixture("d", "chore: bump deps", "org/app", "2024-05-04T10:00:00Z"),
+            Commit::fixture("e", "not conventional", "org/app", "2024-05-05T10:00:00Z"),
+        ];
+
+        let scopes = ScopeCount::from_commits(&commits);
+
+        assert_eq!(scopes.len(), 2);
+        assert_eq!(scopes[0].scope(), "api");
+        assert_eq!(scopes[0].count(), 2);
+        assert_eq!(scopes[1].scope(), "readme");
+        assert_eq!(scopes[1].count(), 1);
+    }
+}
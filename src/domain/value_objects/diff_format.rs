@@ -0,0 +1,45 @@
+use anyhow::{anyhow, Result};
+
+/// Represents how a report diff is printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffFormat {
+    /// Aligned columns for the terminal
+    #[default]
+    Terminal,
+    Markdown,
+    Json,
+}
+
+impl DiffFormat {
+    /// Parses a string into a DiffFormat
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "terminal" => Ok(DiffFormat::Terminal),
+            "markdown" => Ok(DiffFormat::Markdown),
+            "json" => Ok(DiffFormat::Json),
+            _ => Err(anyhow!("Invalid diff format: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_string_to_diff_format() {
+        assert_eq!(
+            DiffFormat::from_str("terminal").expect("Failed to parse terminal"),
+            DiffFormat::Terminal
+        );
+        assert_eq!(
+            DiffFormat::from_str("Markdown").expect("Failed to parse markdown"),
+            DiffFormat::Markdown
+        );
+        assert_eq!(
+            DiffFormat::from_str("json").expect("Failed to parse json"),
+            DiffFormat::Json
+        );
+        assert!(DiffFormat::from_str("html").is_err());
+    }
+}
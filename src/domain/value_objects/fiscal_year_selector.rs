@@ -0,0 +1,92 @@
+use crate::domain::services::fiscal_calendar::FiscalCalendar;
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+
+/// Represents which fiscal year to report on, possibly relative to today
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FiscalYearSelector {
+    /// The fiscal year containing today
+    #[default]
+    Current,
+    /// The fiscal year before the current one
+    Last,
+    /// An explicit fiscal year, named after the calendar year it starts in
+    Year(u32),
+}
+
+impl FiscalYearSelector {
+    /// Parses a `--year` value: a year such as `2024`, `current` or `last`
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "current" => Ok(FiscalYearSelector::Current),
+            "last" => Ok(FiscalYearSelector::Last),
+            value => value
+                .parse()
+                .map(FiscalYearSelector::Year)
+                .map_err(|_| anyhow!("Invalid year: {} (expected a year, current or last)", s)),
+        }
+    }
+
+    /// Returns the fiscal year in the department's fiscal calendar
+    pub fn resolve(&self, today: NaiveDate, calendar: &FiscalCalendar) -> u32 {
+        match self {
+            FiscalYearSelector::Current => calendar.fiscal_year_of(today),
+            FiscalYearSelector::Last => calendar.fiscal_year_of(today) - 1,
+            FiscalYearSelector::Year(year) => *year,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn parses_year_values() {
+        assert_eq!(
+            FiscalYearSelector::from_str("2024").unwrap(),
+            FiscalYearSelector::Year(2024)
+        );
+        assert_eq!(
+            FiscalYearSelector::from_str("Current").unwrap(),
+            FiscalYearSelector::Current
+        );
+        assert_eq!(
+            FiscalYearSelector::from_str("last").unwrap(),
+            FiscalYearSelector::Last
+        );
+        assert!(FiscalYearSelector::from_str("next").is_err());
+    }
+
+    #[test]
+    fn resolves_relative_years_from_fiscal_start_month() {
+        // February 2025 is still in FY2024 when the fiscal year starts in April
+        let today = date(2025, 2, 10);
+        let april = FiscalCalendar::new(4, 1).unwrap();
+        let january = FiscalCalendar::new(1, 1).unwrap();
+
+        assert_eq!(FiscalYearSelector::Current.resolve(today, &april), 2024);
+        assert_eq!(FiscalYearSelector::Last.resolve(today, &april), 2023);
+        assert_eq!(FiscalYearSelector::Current.resolve(today, &january), 2025);
+        assert_eq!(FiscalYearSelector::Year(2020).resolve(today, &april), 2020);
+    }
+
+    #[test]
+    fn resolves_current_year_from_fiscal_start_day() {
+        // Books closing on the 20th: January 10th is still in the previous fiscal year
+        let calendar = FiscalCalendar::new(1, 21).unwrap();
+
+        assert_eq!(
+            FiscalYearSelector::Current.resolve(date(2025, 1, 10), &calendar),
+            2024
+        );
+        assert_eq!(
+            FiscalYearSelector::Current.resolve(date(2025, 1, 21), &calendar),
+            2025
+        );
+    }
+}
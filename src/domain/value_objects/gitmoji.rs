@@ -0,0 +1,147 @@
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use serde::{Deserialize, Serialize};
+
+/// Variation selector that some tools append to emoji (e.g. "♻️" vs "♻")
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+
+/// Built-in gitmoji (https://gitmoji.dev) as (emoji, shortcode, theme)
+const BUILT_IN_GITMOJI: [(&str, &str, CommitTheme); 30] = [
+    ("✨", ":sparkles:", CommitTheme::Feat),
+    ("🎉", ":tada:", CommitTheme::Feat),
+    ("🐛", ":bug:", CommitTheme::Fix),
+    ("🚑", ":ambulance:", CommitTheme::Fix),
+    ("🩹", ":adhesive_bandage:", CommitTheme::Fix),
+    ("🔒", ":lock:", CommitTheme::Fix),
+    ("📝", ":memo:", CommitTheme::Docs),
+    ("💡", ":bulb:", CommitTheme::Docs),
+    ("📄", ":page_facing_up:", CommitTheme::Docs),
+    ("♻", ":recycle:", CommitTheme::Refactor),
+    ("🚚", ":truck:", CommitTheme::Refactor),
+    ("🔥", ":fire:", CommitTheme::Refactor),
+    ("⚰", ":coffin:", CommitTheme::Refactor),
+    ("✅", ":white_check_mark:", CommitTheme::Test),
+    ("🧪", ":test_tube:", CommitTheme::Test),
+    ("📦", ":package:", CommitTheme::Build),
+    ("➕", ":heavy_plus_sign:", CommitTheme::Build),
+    ("➖", ":heavy_minus_sign:", CommitTheme::Build),
+    ("⬆", ":arrow_up:", CommitTheme::Build),
+    ("⬇", ":arrow_down:", CommitTheme::Build),
+    ("📌", ":pushpin:", CommitTheme::Build),
+    ("👷", ":construction_worker:", CommitTheme::Ci),
+    ("💚", ":green_heart:", CommitTheme::Ci),
+    ("⚡", ":zap:", CommitTheme::Perf),
+    ("🎨", ":art:", CommitTheme::Style),
+    ("💄", ":lipstick:", CommitTheme::Style),
+    ("🚨", ":rotating_light:", CommitTheme::Style),
+    ("🔧", ":wrench:", CommitTheme::Chore),
+    ("🔖", ":bookmark:", CommitTheme::Chore),
+    ("🙈", ":see_no_evil:", CommitTheme::Chore),
+];
+
+/// Represents a gitmoji (emoji or `:shortcode:`) mapped to a commit theme
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitmojiMapping {
+    code: String,
+    theme: CommitTheme,
+}
+
+impl GitmojiMapping {
+    /// Creates a new GitmojiMapping instance
+    pub fn new(code: String, theme: CommitTheme) -> Self {
+        Self {
+            code: normalize(&code),
+            theme,
+        }
+    }
+
+    /// Returns the built-in mappings, both emoji and shortcode forms
+    pub fn built_in() -> Vec<Self> {
+        BUILT_IN_GITMOJI
+            .into_iter()
+            .flat_map(|(emoji, shortcode, theme)| {
+                [
+                    Self::new(emoji.to_string(), theme.clone()),
+                    Self::new(shortcode.to_string(), theme),
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the emoji or shortcode
+    #[allow(dead_code)]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the theme assigned to commits starting with this gitmoji
+    pub fn theme(&self) -> &CommitTheme {
+        &self.theme
+    }
+
+    /// Returns the theme of the first mapping the subject starts with
+    pub fn find_theme<'a>(subject: &str, mappings: &'a [Self]) -> Option<&'a CommitTheme> {
+        let subject = normalize(subject.trim_start());
+        mappings
+            .iter()
+            .find(|mapping| subject.starts_with(&mapping.code))
+            .map(Self::theme)
+    }
+}
+
+/// Removes variation selectors so "♻️" and "♻" match each other
+fn normalize(text: &str) -> String {
+    text.chars().filter(|&c| c != VARIATION_SELECTOR).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_emoji_and_shortcodes() {
+        let mappings = GitmojiMapping::built_in();
+
+        assert_eq!(
+            GitmojiMapping::find_theme("✨ add export", &mappings),
+            Some(&CommitTheme::Feat)
+        );
+        assert_eq!(
+            GitmojiMapping::find_theme(":bug: fix crash on empty input", &mappings),
+            Some(&CommitTheme::Fix)
+        );
+        assert_eq!(
+            GitmojiMapping::find_theme("📝 update README", &mappings),
+            Some(&CommitTheme::Docs)
+        );
+        assert_eq!(GitmojiMapping::find_theme("add export", &mappings), None);
+    }
+
+    #[test]
+    fn ignores_variation_selectors() {
+        let mappings = GitmojiMapping::built_in();
+
+        assert_eq!(
+            GitmojiMapping::find_theme("♻️ simplify parser", &mappings),
+            Some(&CommitTheme::Refactor)
+        );
+        assert_eq!(
+            GitmojiMapping::find_theme("♻ simplify parser", &mappings),
+            Some(&CommitTheme::Refactor)
+        );
+        assert_eq!(
+            GitmojiMapping::find_theme("⚡️ cache lookups", &mappings),
+            Some(&CommitTheme::Perf)
+        );
+    }
+
+    #[test]
+    fn uses_first_matching_mapping() {
+        let mut mappings = vec![GitmojiMapping::new("✨".to_string(), CommitTheme::Chore)];
+        mappings.extend(GitmojiMapping::built_in());
+
+        assert_eq!(
+            GitmojiMapping::find_theme("✨ add export", &mappings),
+            Some(&CommitTheme::Chore)
+        );
+    }
+}
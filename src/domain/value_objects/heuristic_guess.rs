@@ -0,0 +1,128 @@
+use crate::domain::value_objects::commit_theme::CommitTheme;
+
+/// Leading phrases of commit subjects, most specific first, as (phrase, theme, confidence)
+///
+/// Confidence reflects how reliably the phrase implies the theme: "Fix" almost
+/// always means a bug fix, while a bare "Update" could be anything.
+const KEYWORD_TABLE: [(&str, CommitTheme, f64); 41] = [
+    ("update docs", CommitTheme::Docs, 0.9),
+    ("update documentation", CommitTheme::Docs, 0.9),
+    ("update readme", CommitTheme::Docs, 0.9),
+    ("update changelog", CommitTheme::Docs, 0.8),
+    ("update dependencies", CommitTheme::Build, 0.9),
+    ("update deps", CommitTheme::Build, 0.9),
+    ("add tests", CommitTheme::Test, 0.9),
+    ("add test", CommitTheme::Test, 0.9),
+    ("add docs", CommitTheme::Docs, 0.9),
+    ("add documentation", CommitTheme::Docs, 0.9),
+    ("improve performance", CommitTheme::Perf, 0.9),
+    ("speed up", CommitTheme::Perf, 0.8),
+    ("clean up", CommitTheme::Refactor, 0.7),
+    ("fix", CommitTheme::Fix, 0.9),
+    ("fixed", CommitTheme::Fix, 0.9),
+    ("fixes", CommitTheme::Fix, 0.9),
+    ("hotfix", CommitTheme::Fix, 0.9),
+    ("resolve", CommitTheme::Fix, 0.7),
+    ("correct", CommitTheme::Fix, 0.7),
+    ("add", CommitTheme::Feat, 0.8),
+    ("added", CommitTheme::Feat, 0.8),
+    ("adds", CommitTheme::Feat, 0.8),
+    ("implement", CommitTheme::Feat, 0.8),
+    ("introduce", CommitTheme::Feat, 0.8),
+    ("support", CommitTheme::Feat, 0.6),
+    ("document", CommitTheme::Docs, 0.8),
+    ("refactor", CommitTheme::Refactor, 0.9),
+    ("refactored", CommitTheme::Refactor, 0.9),
+    ("simplify", CommitTheme::Refactor, 0.7),
+    ("cleanup", CommitTheme::Refactor, 0.7),
+    ("rename", CommitTheme::Refactor, 0.6),
+    ("extract", CommitTheme::Refactor, 0.6),
+    ("test", CommitTheme::Test, 0.7),
+    ("bump", CommitTheme::Build, 0.9),
+    ("upgrade", CommitTheme::Build, 0.8),
+    ("downgrade", CommitTheme::Build, 0.8),
+    ("optimize", CommitTheme::Perf, 0.8),
+    ("format", CommitTheme::Style, 0.6),
+    ("lint", CommitTheme::Style, 0.6),
+    ("update", CommitTheme::Chore, 0.4),
+    ("change", CommitTheme::Chore, 0.3),
+];
+
+/// Represents a theme guessed from the leading verb of a commit subject
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeuristicGuess {
+    theme: CommitTheme,
+    confidence: f64,
+}
+
+impl HeuristicGuess {
+    /// Guesses the theme of a subject such as "Fix crash on startup"
+    ///
+    /// Phrases must be followed by a word boundary, so "Fixture" is not "Fix".
+    pub fn from_subject(subject: &str) -> Option<Self> {
+        let subject = subject.trim_start().to_lowercase();
+        KEYWORD_TABLE
+            .iter()
+            .find(|(phrase, _, _)| {
+                subject.strip_prefix(phrase).is_some_and(|rest| {
+                    rest.chars()
+                        .next()
+                        .is_none_or(|c| !c.is_alphanumeric() && c != '-' && c != '_')
+                })
+            })
+            .map(|(_, theme, confidence)| Self {
+                theme: theme.clone(),
+                confidence: *confidence,
+            })
+    }
+
+    /// Returns the guessed theme
+    pub fn theme(&self) -> &CommitTheme {
+        &self.theme
+    }
+
+    /// Returns the confidence of the guess (0.0 - 1.0)
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(subject: &str) -> Option<(CommitTheme, f64)> {
+        HeuristicGuess::from_subject(subject).map(|g| (g.theme().clone(), g.confidence()))
+    }
+
+    #[test]
+    fn guesses_theme_from_leading_verb() {
+        assert_eq!(guess("Fix crash on startup"), Some((CommitTheme::Fix, 0.9)));
+        assert_eq!(guess("Add CSV export"), Some((CommitTheme::Feat, 0.8)));
+        assert_eq!(guess("Refactor parser"), Some((CommitTheme::Refactor, 0.9)));
+        assert_eq!(
+            guess("Bump serde to 1.0.200"),
+            Some((CommitTheme::Build, 0.9))
+        );
+        assert_eq!(guess("Update"), Some((CommitTheme::Chore, 0.4)));
+    }
+
+    #[test]
+    fn prefers_more_specific_phrases() {
+        assert_eq!(guess("Update docs for v2"), Some((CommitTheme::Docs, 0.9)));
+        assert_eq!(guess("update README.md"), Some((CommitTheme::Docs, 0.9)));
+        assert_eq!(
+            guess("Add tests for parser"),
+            Some((CommitTheme::Test, 0.9))
+        );
+        assert_eq!(guess("Update the form"), Some((CommitTheme::Chore, 0.4)));
+    }
+
+    #[test]
+    fn requires_word_boundary() {
+        assert_eq!(guess("Fixture cleanup"), None);
+        assert_eq!(guess("Addressed review comments"), None);
+        assert_eq!(guess("Fix: typo"), Some((CommitTheme::Fix, 0.9)));
+        assert_eq!(guess("WIP"), None);
+    }
+}
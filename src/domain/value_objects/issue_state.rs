@@ -0,0 +1,39 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Represents the state of a GitHub issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueState {
+    Open,
+    Closed,
+}
+
+impl IssueState {
+    /// Parses a GitHub GraphQL `IssueState` value
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "OPEN" => Ok(IssueState::Open),
+            "CLOSED" => Ok(IssueState::Closed),
+            _ => Err(anyhow!("Invalid issue state: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_issue_states() {
+        assert_eq!(
+            IssueState::from_str("OPEN").expect("Failed to parse"),
+            IssueState::Open
+        );
+        assert_eq!(
+            IssueState::from_str("closed").expect("Failed to parse"),
+            IssueState::Closed
+        );
+        assert!(IssueState::from_str("MERGED").is_err());
+    }
+}
@@ -0,0 +1,275 @@
+use crate::domain::entities::issue::Issue;
+use crate::domain::value_objects::issue_state::IssueState;
+use crate::domain::value_objects::period::Period;
+use crate::domain::value_objects::pull_request_summary::percentile;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Group name used for issues without any label
+const UNLABELED: &str = "(unlabeled)";
+
+/// Represents open/closed issue counts for a label or repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueGroup {
+    name: String,
+    open: u32,
+    closed: u32,
+}
+
+impl IssueGroup {
+    /// Returns the label or repository name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of issues open at the end of the period
+    pub fn open(&self) -> u32 {
+        self.open
+    }
+
+    /// Returns the number of issues closed at the end of the period
+    pub fn closed(&self) -> u32 {
+        self.closed
+    }
+
+    /// Returns the share of closed issues (0.0 - 1.0)
+    pub fn closed_ratio(&self) -> f64 {
+        let total = self.open + self.closed;
+        if total == 0 {
+            0.0
+        } else {
+            self.closed as f64 / total as f64
+        }
+    }
+}
+
+/// Represents issue activity within a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueSummary {
+    opened: u32,
+    closed: u32,
+    commented: u32,
+    involved: u32,
+    median_time_to_close_hours: Option<f64>,
+    by_label: Vec<IssueGroup>,
+    by_repository: Vec<IssueGroup>,
+}
+
+impl IssueSummary {
+    /// Builds a summary from issues
+    ///
+    /// When `user` is given, only issues opened, closed or commented on by that
+    /// user within the period are counted; otherwise activity by anyone counts.
+    /// Label and repository groups use the state at the end of the period.
+    pub fn from_issues(issues: &[Issue], period: Period, user: Option<&str>) -> Self {
+        let in_period = |at: DateTime<Utc>| period.contains(at);
+        let by_user = |login: &str| user.is_none_or(|user| user.eq_ignore_ascii_case(login));
+        let period_end = period.until();
+
+        let mut opened = 0;
+        let mut closed = 0;
+        let mut commented = 0;
+        let mut involved = Vec::new();
+
+        for issue in issues {
+            let was_opened = in_period(issue.created_at()) && by_user(issue.author());
+            let was_closed = if issue.transitions().is_empty() {
+                user.is_none() && issue.closed_at().is_some_and(in_period)
+            } else {
+                issue.transitions().iter().any(|transition| {
+                    transition.state() == IssueState::Closed
+                        && in_period(transition.at())
+                        && by_user(transition.actor())
+                })
+            };
+            let was_commented = issue
+                .comments()
+                .iter()
+                .any(|comment| in_period(comment.created_at()) && by_user(comment.author()));
+
+            opened += was_opened as u32;
+            closed += was_closed as u32;
+            commented += was_commented as u32;
+            if was_opened || was_closed || was_commented {
+                involved.push(issue);
+            }
+        }
+
+        let close_times: Vec<f64> = involved
+            .iter()
+            .filter(|issue| issue.closed_at().is_some_and(in_period))
+            .filter_map(|issue| issue.time_to_close())
+            .map(|duration| duration.num_seconds() as f64 / 3600.0)
+            .collect();
+
+        let mut by_label: BTreeMap<&str, IssueGroup> = BTreeMap::new();
+        let mut by_repository: BTreeMap<&str, IssueGroup> = BTreeMap::new();
+        for issue in &involved {
+            let state = issue.state_at(period_end);
+            let labels: Vec<&str> = if issue.labels().is_empty() {
+                vec![UNLABELED]
+            } else {
+                issue.labels().iter().map(String::as_str).collect()
+            };
+            for label in labels {
+                count_state(&mut by_label, label, state);
+            }
+            count_state(&mut by_repository, issue.repository(), state);
+        }
+
+        Self {
+            opened,
+            closed,
+            commented,
+            involved: involved.len() as u32,
+            median_time_to_close_hours: percentile(&close_times, 50.0),
+            by_label: to_sorted_groups(by_label),
+            by_repository: to_sorted_groups(by_repository),
+        }
+    }
+
+    /// Returns the number of issues opened within the period
+    pub fn opened(&self) -> u32 {
+        self.opened
+    }
+
+    /// Returns the number of issues closed within the period
+    pub fn closed(&self) -> u32 {
+        self.closed
+    }
+
+    /// Returns the number of issues commented on within the period
+    pub fn commented(&self) -> u32 {
+        self.commented
+    }
+
+    /// Returns the number of distinct issues opened, closed or commented on
+    pub fn involved(&self) -> u32 {
+        self.involved
+    }
+
+    /// Returns the median time from creation to close in hours
+    pub fn median_time_to_close_hours(&self) -> Option<f64> {
+        self.median_time_to_close_hours
+    }
+
+    /// Returns open/closed counts per label, largest first
+    pub fn by_label(&self) -> &[IssueGroup] {
+        &self.by_label
+    }
+
+    /// Returns open/closed counts per repository, largest first
+    pub fn by_repository(&self) -> &[IssueGroup] {
+        &self.by_repository
+    }
+}
+
+fn count_state<'a>(groups: &mut BTreeMap<&'a str, IssueGroup>, name: &'a str, state: IssueState) {
+    let group = groups.entry(name).or_insert_with(|| IssueGroup {
+        name: name.to_string(),
+        open: 0,
+        closed: 0,
+    });
+    match state {
+        IssueState::Open => group.open += 1,
+        IssueState::Closed => group.closed += 1,
+    }
+}
+
+fn to_sorted_groups(groups: BTreeMap<&str, IssueGroup>) -> Vec<IssueGroup> {
+    let mut groups: Vec<IssueGroup> = groups.into_values().collect();
+    groups.sort_by_key(|group| std::cmp::Reverse(group.open + group.closed));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::issue::{IssueComment, IssueStateTransition};
+    use chrono::{NaiveDate, TimeZone};
+
+    fn at(month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, month, day, 12, 0, 0).unwrap()
+    }
+
+    fn issues() -> Vec<Issue> {
+        vec![
+            // Opened and closed by the user within the period
+            Issue::new(
+                1,
+                "Crash".to_string(),
+                "org/a".to_string(),
+                "connect0459".to_string(),
+                IssueState::Closed,
+                at(5, 1),
+                Some(at(5, 3)),
+            )
+            .with_labels(vec!["bug".to_string()])
+            .with_transitions(vec![IssueStateTransition::new(
+                IssueState::Closed,
+                "connect0459".to_string(),
+                at(5, 3),
+            )]),
+            // Opened by someone else, commented on by the user
+            Issue::new(
+                2,
+                "Feature request".to_string(),
+                "org/b".to_string(),
+                "octocat".to_string(),
+                IssueState::Open,
+                at(6, 1),
+                None,
+            )
+            .with_labels(vec!["enhancement".to_string(), "bug".to_string()])
+            .with_comments(vec![IssueComment::new("connect0459".to_string(), at(6, 2))]),
+            // No involvement of the user
+            Issue::new(
+                3,
+                "Question".to_string(),
+                "org/b".to_string(),
+                "octocat".to_string(),
+                IssueState::Open,
+                at(6, 5),
+                None,
+            ),
+        ]
+    }
+
+    #[test]
+    fn summarizes_issues_of_user() {
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+
+        let summary =
+            IssueSummary::from_issues(&issues(), Period::utc(from, to), Some("connect0459"));
+
+        assert_eq!(summary.opened(), 1);
+        assert_eq!(summary.closed(), 1);
+        assert_eq!(summary.commented(), 1);
+        assert_eq!(summary.involved(), 2);
+        assert_eq!(summary.median_time_to_close_hours(), Some(48.0));
+
+        let bug = &summary.by_label()[0];
+        assert_eq!(bug.name(), "bug");
+        assert_eq!(bug.open(), 1);
+        assert_eq!(bug.closed(), 1);
+        assert_eq!(bug.closed_ratio(), 0.5);
+        assert_eq!(summary.by_repository().len(), 2);
+    }
+
+    #[test]
+    fn summarizes_issues_of_everyone_without_user() {
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+
+        let summary = IssueSummary::from_issues(&issues(), Period::utc(from, to), None);
+
+        assert_eq!(summary.opened(), 3);
+        assert_eq!(summary.involved(), 3);
+        assert!(summary
+            .by_label()
+            .iter()
+            .any(|group| group.name() == UNLABELED));
+    }
+}
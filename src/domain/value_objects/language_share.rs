@@ -0,0 +1,132 @@
+use crate::domain::entities::commit::Commit;
+use crate::domain::entities::repository_languages::RepositoryLanguages;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Represents how much of the user's work was done in a language
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageShare {
+    name: String,
+    weighted_commits: f64,
+    percentage: f64,
+}
+
+impl LanguageShare {
+    /// Builds language shares weighted by the user's commits
+    ///
+    /// Each commit is split across the languages of its repository in
+    /// proportion to their code size. Repositories without commits do not count.
+    pub fn from_commits(repositories: &[RepositoryLanguages], commits: &[Commit]) -> Vec<Self> {
+        let mut commits_by_repository: HashMap<String, u32> = HashMap::new();
+        for commit in commits {
+            *commits_by_repository
+                .entry(commit.repository().to_lowercase())
+                .or_insert(0) += 1;
+        }
+
+        let mut weights: HashMap<&str, f64> = HashMap::new();
+        for repository in repositories {
+            let Some(&count) = commits_by_repository.get(&repository.repository().to_lowercase())
+            else {
+                continue;
+            };
+            for (language, share) in repository.shares() {
+                *weights.entry(language).or_insert(0.0) += count as f64 * share;
+            }
+        }
+
+        let total: f64 = weights.values().sum();
+        let mut shares: Vec<Self> = weights
+            .into_iter()
+            .map(|(name, weighted_commits)| Self {
+                name: name.to_string(),
+                weighted_commits,
+                percentage: weighted_commits / total * 100.0,
+            })
+            .collect();
+        shares.sort_by(|a, b| {
+            b.weighted_commits
+                .total_cmp(&a.weighted_commits)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        shares
+    }
+
+    /// Returns the language name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of commits attributed to the language
+    pub fn weighted_commits(&self) -> f64 {
+        self.weighted_commits
+    }
+
+    /// Returns the share of the user's commits (0 - 100)
+    pub fn percentage(&self) -> f64 {
+        self.percentage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::repository_languages::LanguageSize;
+
+    #[test]
+    fn weights_languages_by_commits_per_repository() {
+        let repositories = vec![
+            RepositoryLanguages::new(
+                "org/api".to_string(),
+                vec![
+                    LanguageSize::new("Rust".to_string(), 900),
+                    LanguageSize::new("Shell".to_string(), 100),
+                ],
+            ),
+            RepositoryLanguages::new(
+                "org/web".to_string(),
+                vec![LanguageSize::new("TypeScript".to_string(), 500)],
+            ),
+            RepositoryLanguages::new(
+                "org/untouched".to_string(),
+                vec![LanguageSize::new("Go".to_string(), 10_000)],
+            ),
+        ];
+        let commits = vec![
+            Commit::fixture(
+                "abc123",
+                "feat: add feature",
+                "org/api",
+                "2024-05-01T12:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add feature",
+                "org/api",
+                "2024-05-01T12:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add feature",
+                "org/api",
+                "2024-05-01T12:00:00Z",
+            ),
+            Commit::fixture(
+                "abc123",
+                "feat: add feature",
+                "org/web",
+                "2024-05-01T12:00:00Z",
+            ),
+        ];
+
+        let shares = LanguageShare::from_commits(&repositories, &commits);
+
+        assert_eq!(shares.len(), 3);
+        assert_eq!(shares[0].name(), "Rust");
+        assert!((shares[0].weighted_commits() - 2.7).abs() < 1e-9);
+        assert!((shares[0].percentage() - 67.5).abs() < 1e-9);
+        assert_eq!(shares[1].name(), "TypeScript");
+        assert_eq!(shares[1].percentage(), 25.0);
+        assert!(shares.iter().all(|share| share.name() != "Go"));
+    }
+}
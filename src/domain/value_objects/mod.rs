@@ -1,2 +1,31 @@
+pub mod activity_breakdown;
+pub mod activity_timeline;
+pub mod classification_summary;
+pub mod commit_classifier;
+pub mod commit_filter;
 pub mod commit_theme;
+pub mod commit_time_distribution;
+pub mod comparison;
+pub mod contribution_calendar;
+pub mod conventional_commit;
+pub mod diff_format;
+pub mod fiscal_year_selector;
+pub mod gitmoji;
+pub mod heuristic_guess;
+pub mod issue_state;
+pub mod issue_summary;
+pub mod language_share;
 pub mod output_format;
+pub mod period;
+pub mod period_kind;
+pub mod pull_request_summary;
+pub mod report_diff;
+pub mod repository_filter;
+pub mod repository_mirror;
+pub mod repository_visibility;
+pub mod revert;
+pub mod review_state;
+pub mod review_summary;
+pub mod source_mode;
+pub mod week_layout;
+pub mod working_hours;
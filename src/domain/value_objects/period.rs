@@ -0,0 +1,145 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Represents a reporting period of whole days in a department's timezone
+///
+/// Days start at local midnight, so a JST fiscal year starting on April 1st
+/// begins at 15:00 UTC on March 31st.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Period {
+    from: NaiveDate,
+    to: NaiveDate,
+    #[serde(default)]
+    timezone: Tz,
+}
+
+impl Period {
+    /// Creates a new Period instance covering `from` to `to` (inclusive) in the given timezone
+    pub fn new(from: NaiveDate, to: NaiveDate, timezone: Tz) -> Self {
+        Self { from, to, timezone }
+    }
+
+    /// Creates a new Period instance in UTC
+    #[allow(dead_code)]
+    pub fn utc(from: NaiveDate, to: NaiveDate) -> Self {
+        Self::new(from, to, Tz::UTC)
+    }
+
+    /// Returns the first day of the period (inclusive)
+    pub fn from(&self) -> NaiveDate {
+        self.from
+    }
+
+    /// Returns the last day of the period (inclusive)
+    pub fn to(&self) -> NaiveDate {
+        self.to
+    }
+
+    /// Returns the timezone the days of the period are in
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns the instant the period starts: local midnight of the first day
+    pub fn since(&self) -> DateTime<Utc> {
+        self.start_of_day(self.from)
+    }
+
+    /// Returns the last second of the period: just before local midnight after the last day
+    pub fn until(&self) -> DateTime<Utc> {
+        self.start_of_day(self.to + Duration::days(1)) - Duration::seconds(1)
+    }
+
+    /// Returns true if the instant falls within the period
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        self.since() <= at && at <= self.until()
+    }
+
+    /// Returns the local day the instant falls on
+    pub fn local_date(&self, at: DateTime<Utc>) -> NaiveDate {
+        at.with_timezone(&self.timezone).date_naive()
+    }
+
+    /// Returns the first instant of the local day
+    ///
+    /// Where a DST change skips midnight, the day starts at the first local time after the gap.
+    fn start_of_day(&self, date: NaiveDate) -> DateTime<Utc> {
+        let mut time = date.and_time(NaiveTime::MIN);
+        loop {
+            if let Some(start) = self.timezone.from_local_datetime(&time).earliest() {
+                return start.with_timezone(&Utc);
+            }
+            time += Duration::minutes(30);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn starts_and_ends_at_local_midnight() {
+        let period = Period::new(date(2024, 4, 1), date(2025, 3, 31), chrono_tz::Asia::Tokyo);
+
+        assert_eq!(
+            period.since(),
+            Utc.with_ymd_and_hms(2024, 3, 31, 15, 0, 0).unwrap()
+        );
+        assert_eq!(
+            period.until(),
+            Utc.with_ymd_and_hms(2025, 3, 31, 14, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_utc_boundaries_for_utc() {
+        let period = Period::utc(date(2024, 4, 1), date(2025, 3, 31));
+
+        assert_eq!(
+            period.since(),
+            Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            period.until(),
+            Utc.with_ymd_and_hms(2025, 3, 31, 23, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn contains_instants_by_local_day() {
+        let period = Period::new(date(2024, 4, 1), date(2025, 3, 31), chrono_tz::Asia::Tokyo);
+        // 2024-03-31 16:00 UTC is 01:00 on April 1st in Tokyo
+        let first_morning = Utc.with_ymd_and_hms(2024, 3, 31, 16, 0, 0).unwrap();
+        // 2025-03-31 16:00 UTC is already April 1st in Tokyo
+        let next_year = Utc.with_ymd_and_hms(2025, 3, 31, 16, 0, 0).unwrap();
+
+        assert!(period.contains(first_morning));
+        assert_eq!(period.local_date(first_morning), date(2024, 4, 1));
+        assert!(!period.contains(next_year));
+    }
+
+    #[test]
+    fn accounts_for_daylight_saving_time() {
+        // New York is UTC-5 in January and UTC-4 from mid-March
+        let period = Period::new(
+            date(2024, 1, 1),
+            date(2024, 6, 30),
+            chrono_tz::America::New_York,
+        );
+
+        assert_eq!(
+            period.since(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 5, 0, 0).unwrap()
+        );
+        assert_eq!(
+            period.until(),
+            Utc.with_ymd_and_hms(2024, 7, 1, 3, 59, 59).unwrap()
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use crate::domain::services::fiscal_calendar::FiscalCalendar;
+use anyhow::{anyhow, bail, Result};
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Represents which part of a fiscal year a report covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PeriodKind {
+    /// The whole fiscal year
+    #[default]
+    FiscalYear,
+    /// A fiscal quarter (1-4) of the fiscal calendar
+    Quarter { quarter: u32 },
+    /// A fiscal half (1-2) of the fiscal calendar
+    Half { half: u32 },
+    /// A fiscal month, named after the calendar month it nominally starts in
+    Month { year: i32, month: u32 },
+    /// An explicit range of days (inclusive)
+    Custom { from: NaiveDate, to: NaiveDate },
+    /// The fiscal quarter before the one containing today; see `anchor`
+    LastQuarter,
+}
+
+impl PeriodKind {
+    /// Parses a `--period` value: `year`, `Q1`-`Q4`, `H1`/`H2`, `month=YYYY-MM` or `last-quarter`
+    pub fn from_str(s: &str) -> Result<Self> {
+        let value = s.trim().to_lowercase();
+        if let Some(month) = value.strip_prefix("month=") {
+            let date = NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+                .map_err(|_| anyhow!("Invalid month: {} (expected YYYY-MM)", month))?;
+            return Ok(PeriodKind::Month {
+                year: date.year(),
+                month: date.month(),
+            });
+        }
+        match value.as_str() {
+            "year" | "fy" => Ok(PeriodKind::FiscalYear),
+            "q1" | "q2" | "q3" | "q4" => Ok(PeriodKind::Quarter {
+                quarter: value[1..].parse()?,
+            }),
+            "h1" | "h2" => Ok(PeriodKind::Half {
+                half: value[1..].parse()?,
+            }),
+            "last-quarter" => Ok(PeriodKind::LastQuarter),
+            _ => Err(anyhow!(
+                "Invalid period: {} (expected year, Q1-Q4, H1, H2, month=YYYY-MM or last-quarter)",
+                s
+            )),
+        }
+    }
+
+    /// Creates a custom period from `--since` and `--until` dates
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `from` is after `to`
+    pub fn custom(from: NaiveDate, to: NaiveDate) -> Result<Self> {
+        if from > to {
+            bail!("--since ({}) must not be after --until ({})", from, to);
+        }
+        Ok(PeriodKind::Custom { from, to })
+    }
+
+    /// Pins the period down relative to today, returning it with its fiscal year
+    ///
+    /// `last-quarter` becomes the concrete quarter before the one containing
+    /// today, possibly in the previous fiscal year, and ignores `fiscal_year`.
+    pub fn anchor(
+        &self,
+        fiscal_year: u32,
+        today: NaiveDate,
+        calendar: &FiscalCalendar,
+    ) -> (Self, u32) {
+        match self {
+            PeriodKind::LastQuarter => {
+                let (current_year, current_quarter) = calendar.quarter_of(today);
+                if current_quarter == 1 {
+                    (PeriodKind::Quarter { quarter: 4 }, current_year - 1)
+                } else {
+                    let quarter = current_quarter - 1;
+                    (PeriodKind::Quarter { quarter }, current_year)
+                }
+            }
+            _ => (*self, self.fiscal_year(fiscal_year, calendar)),
+        }
+    }
+
+    /// Returns the same period one fiscal year earlier, with its fiscal year
+    ///
+    /// Months and custom ranges move back twelve calendar months.
+    pub fn previous_year(&self, fiscal_year: u32, calendar: &FiscalCalendar) -> (Self, u32) {
+        let previous = match *self {
+            PeriodKind::Month { year, month } => PeriodKind::Month {
+                year: year - 1,
+                month,
+            },
+            PeriodKind::Custom { from, to } => PeriodKind::Custom {
+                from: from - Months::new(12),
+                to: to - Months::new(12),
+            },
+            PeriodKind::LastQuarter => {
+                unreachable!("last-quarter must be anchored before going back a year")
+            }
+            _ => return (*self, fiscal_year - 1),
+        };
+        (previous, previous.fiscal_year(fiscal_year - 1, calendar))
+    }
+
+    /// Returns the first and last day of the period
+    ///
+    /// Years, quarters, halves and months come from the fiscal calendar;
+    /// custom ranges are absolute. Relative periods must be anchored first.
+    pub fn resolve(&self, fiscal_year: u32, calendar: &FiscalCalendar) -> (NaiveDate, NaiveDate) {
+        match *self {
+            PeriodKind::FiscalYear => calendar.year(fiscal_year),
+            PeriodKind::LastQuarter => {
+                unreachable!("last-quarter must be anchored before resolving")
+            }
+            PeriodKind::Quarter { quarter } => calendar.quarter(fiscal_year, quarter),
+            PeriodKind::Half { half } => calendar.half(fiscal_year, half),
+            PeriodKind::Month { year, month } => calendar.month(year, month),
+            PeriodKind::Custom { from, to } => (from, to),
+        }
+    }
+
+    /// Returns the fiscal year the period belongs to
+    ///
+    /// Months and custom ranges belong to the fiscal year containing their first day.
+    pub fn fiscal_year(&self, fiscal_year: u32, calendar: &FiscalCalendar) -> u32 {
+        let first_day = match *self {
+            PeriodKind::Month { year, month } => calendar.month(year, month).0,
+            PeriodKind::Custom { from, .. } => from,
+            _ => return fiscal_year,
+        };
+        calendar.fiscal_year_of(first_day)
+    }
+
+    /// Returns a label for the period (e.g., "FY2024", "FY2024-Q3", "2025-06")
+    pub fn label(&self, fiscal_year: u32) -> String {
+        match *self {
+            PeriodKind::FiscalYear => format!("FY{}", fiscal_year),
+            PeriodKind::Quarter { quarter } => format!("FY{}-Q{}", fiscal_year, quarter),
+            PeriodKind::Half { half } => format!("FY{}-H{}", fiscal_year, half),
+            PeriodKind::Month { year, month } => format!("{}-{:02}", year, month),
+            PeriodKind::Custom { from, to } => format!("{}_{}", from, to),
+            PeriodKind::LastQuarter => "last-quarter".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::week_layout::{QuarterPattern, WeekLayout};
+    use chrono::Weekday;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn starting_in(month: u32) -> FiscalCalendar {
+        FiscalCalendar::new(month, 1).unwrap()
+    }
+
+    #[test]
+    fn parses_period_values() {
+        assert_eq!(
+            PeriodKind::from_str("year").unwrap(),
+            PeriodKind::FiscalYear
+        );
+        assert_eq!(
+            PeriodKind::from_str("Q3").unwrap(),
+            PeriodKind::Quarter { quarter: 3 }
+        );
+        assert_eq!(
+            PeriodKind::from_str("h2").unwrap(),
+            PeriodKind::Half { half: 2 }
+        );
+        assert_eq!(
+            PeriodKind::from_str("month=2025-06").unwrap(),
+            PeriodKind::Month {
+                year: 2025,
+                month: 6
+            }
+        );
+        assert!(PeriodKind::from_str("Q5").is_err());
+        assert!(PeriodKind::from_str("month=2025-13").is_err());
+        assert!(PeriodKind::from_str("week").is_err());
+    }
+
+    #[test]
+    fn resolves_fiscal_year() {
+        // Fiscal year starting in April
+        assert_eq!(
+            PeriodKind::FiscalYear.resolve(2024, &starting_in(4)),
+            (date(2024, 4, 1), date(2025, 3, 31))
+        );
+        // Calendar year
+        assert_eq!(
+            PeriodKind::FiscalYear.resolve(2024, &starting_in(1)),
+            (date(2024, 1, 1), date(2024, 12, 31))
+        );
+    }
+
+    #[test]
+    fn resolves_quarters_and_halves_from_fiscal_start_month() {
+        assert_eq!(
+            PeriodKind::Quarter { quarter: 1 }.resolve(2024, &starting_in(4)),
+            (date(2024, 4, 1), date(2024, 6, 30))
+        );
+        assert_eq!(
+            PeriodKind::Quarter { quarter: 4 }.resolve(2024, &starting_in(4)),
+            (date(2025, 1, 1), date(2025, 3, 31))
+        );
+        assert_eq!(
+            PeriodKind::Half { half: 2 }.resolve(2024, &starting_in(4)),
+            (date(2024, 10, 1), date(2025, 3, 31))
+        );
+        assert_eq!(
+            PeriodKind::Quarter { quarter: 3 }.resolve(2024, &starting_in(1)),
+            (date(2024, 7, 1), date(2024, 9, 30))
+        );
+    }
+
+    #[test]
+    fn resolves_quarters_from_week_based_calendar() {
+        let calendar = starting_in(2)
+            .with_week_layout(WeekLayout::new(QuarterPattern::FourFourFive, Weekday::Sun));
+
+        assert_eq!(
+            PeriodKind::Quarter { quarter: 1 }.resolve(2024, &calendar),
+            (date(2024, 2, 4), date(2024, 5, 4))
+        );
+        // February 3rd, 2024 is the last day of FY2023's 53-week year
+        assert_eq!(
+            PeriodKind::LastQuarter.anchor(2030, date(2024, 2, 3), &calendar),
+            (PeriodKind::Quarter { quarter: 3 }, 2023)
+        );
+    }
+
+    #[test]
+    fn resolves_months_and_custom_ranges_to_their_fiscal_year() {
+        let month = PeriodKind::Month {
+            year: 2025,
+            month: 2,
+        };
+        assert_eq!(
+            month.resolve(2024, &starting_in(4)),
+            (date(2025, 2, 1), date(2025, 2, 28))
+        );
+        assert_eq!(month.fiscal_year(2030, &starting_in(4)), 2024);
+        assert_eq!(month.fiscal_year(2030, &starting_in(1)), 2025);
+
+        // Fiscal months follow the calendar's start day and week layout
+        let closing_on_20th = FiscalCalendar::new(4, 21).unwrap();
+        assert_eq!(
+            month.resolve(2024, &closing_on_20th),
+            (date(2025, 2, 21), date(2025, 3, 20))
+        );
+        let retail = starting_in(2)
+            .with_week_layout(WeekLayout::new(QuarterPattern::FourFourFive, Weekday::Sun));
+        assert_eq!(
+            month.resolve(2024, &retail),
+            (date(2025, 2, 2), date(2025, 3, 1))
+        );
+        assert_eq!(month.fiscal_year(2030, &retail), 2025);
+
+        let custom = PeriodKind::custom(date(2024, 5, 10), date(2024, 8, 20)).unwrap();
+        assert_eq!(
+            custom.resolve(2030, &starting_in(4)),
+            (date(2024, 5, 10), date(2024, 8, 20))
+        );
+        assert_eq!(custom.fiscal_year(2030, &starting_in(4)), 2024);
+        assert!(PeriodKind::custom(date(2024, 8, 20), date(2024, 5, 10)).is_err());
+    }
+
+    #[test]
+    fn goes_back_one_fiscal_year() {
+        let calendar = starting_in(4);
+
+        assert_eq!(
+            PeriodKind::Quarter { quarter: 3 }.previous_year(2024, &calendar),
+            (PeriodKind::Quarter { quarter: 3 }, 2023)
+        );
+        assert_eq!(
+            PeriodKind::Month {
+                year: 2025,
+                month: 2
+            }
+            .previous_year(2024, &calendar),
+            (
+                PeriodKind::Month {
+                    year: 2024,
+                    month: 2
+                },
+                2023
+            )
+        );
+        // The end of February moves to the last day of the previous February
+        assert_eq!(
+            PeriodKind::custom(date(2024, 1, 10), date(2024, 2, 29))
+                .unwrap()
+                .previous_year(2023, &calendar),
+            (
+                PeriodKind::Custom {
+                    from: date(2023, 1, 10),
+                    to: date(2023, 2, 28)
+                },
+                2022
+            )
+        );
+    }
+
+    #[test]
+    fn labels_periods() {
+        assert_eq!(PeriodKind::FiscalYear.label(2024), "FY2024");
+        assert_eq!(PeriodKind::Quarter { quarter: 3 }.label(2024), "FY2024-Q3");
+        assert_eq!(PeriodKind::Half { half: 1 }.label(2024), "FY2024-H1");
+        assert_eq!(
+            PeriodKind::Month {
+                year: 2025,
+                month: 6
+            }
+            .label(2025),
+            "2025-06"
+        );
+        assert_eq!(
+            PeriodKind::custom(date(2024, 4, 1), date(2024, 6, 30))
+                .unwrap()
+                .label(2024),
+            "2024-04-01_2024-06-30"
+        );
+    }
+
+    #[test]
+    fn anchors_last_quarter_to_today() {
+        let last_quarter = PeriodKind::from_str("last-quarter").unwrap();
+
+        // November is in Q3 of FY2024 when the fiscal year starts in April
+        assert_eq!(
+            last_quarter.anchor(2030, date(2024, 11, 15), &starting_in(4)),
+            (PeriodKind::Quarter { quarter: 2 }, 2024)
+        );
+        // May is in Q1, so the last quarter is Q4 of the previous fiscal year
+        assert_eq!(
+            last_quarter.anchor(2030, date(2024, 5, 1), &starting_in(4)),
+            (PeriodKind::Quarter { quarter: 4 }, 2023)
+        );
+        assert_eq!(
+            last_quarter.anchor(2030, date(2025, 2, 1), &starting_in(1)),
+            (PeriodKind::Quarter { quarter: 4 }, 2024)
+        );
+        // Other periods keep the requested fiscal year
+        assert_eq!(
+            PeriodKind::Half { half: 1 }.anchor(2022, date(2024, 5, 1), &starting_in(4)),
+            (PeriodKind::Half { half: 1 }, 2022)
+        );
+    }
+}
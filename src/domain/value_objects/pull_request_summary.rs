@@ -0,0 +1,167 @@
+use crate::domain::entities::pull_request::PullRequest;
+use crate::domain::value_objects::period::Period;
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Number of largest pull requests kept in the summary
+const LARGEST_PULL_REQUESTS_LIMIT: usize = 5;
+
+/// Represents lifecycle statistics of pull requests within a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestSummary {
+    authored: u32,
+    merged: u32,
+    median_cycle_time_hours: Option<f64>,
+    p90_cycle_time_hours: Option<f64>,
+    median_time_to_first_review_hours: Option<f64>,
+    largest: Vec<PullRequest>,
+}
+
+impl PullRequestSummary {
+    /// Builds a summary from pull requests
+    ///
+    /// Pull requests are counted as authored when created within the period and
+    /// as merged when merged within the period. Cycle times are calculated from
+    /// pull requests merged within the period.
+    pub fn from_pull_requests(pull_requests: &[PullRequest], period: Period) -> Self {
+        let authored: Vec<&PullRequest> = pull_requests
+            .iter()
+            .filter(|pr| period.contains(pr.created_at()))
+            .collect();
+        let merged: Vec<&PullRequest> = pull_requests
+            .iter()
+            .filter(|pr| pr.merged_at().is_some_and(|at| period.contains(at)))
+            .collect();
+
+        let cycle_times: Vec<f64> = merged
+            .iter()
+            .filter_map(|pr| pr.cycle_time())
+            .map(to_hours)
+            .collect();
+        let first_review_times: Vec<f64> = authored
+            .iter()
+            .filter_map(|pr| pr.time_to_first_review())
+            .map(to_hours)
+            .collect();
+
+        let mut largest: Vec<PullRequest> = authored.iter().map(|pr| (*pr).clone()).collect();
+        largest.sort_by_key(|pr| std::cmp::Reverse(pr.size()));
+        largest.truncate(LARGEST_PULL_REQUESTS_LIMIT);
+
+        Self {
+            authored: authored.len() as u32,
+            merged: merged.len() as u32,
+            median_cycle_time_hours: percentile(&cycle_times, 50.0),
+            p90_cycle_time_hours: percentile(&cycle_times, 90.0),
+            median_time_to_first_review_hours: percentile(&first_review_times, 50.0),
+            largest,
+        }
+    }
+
+    /// Returns the number of pull requests created within the period
+    pub fn authored(&self) -> u32 {
+        self.authored
+    }
+
+    /// Returns the number of pull requests merged within the period
+    pub fn merged(&self) -> u32 {
+        self.merged
+    }
+
+    /// Returns the median time from creation to merge in hours
+    pub fn median_cycle_time_hours(&self) -> Option<f64> {
+        self.median_cycle_time_hours
+    }
+
+    /// Returns the 90th percentile time from creation to merge in hours
+    pub fn p90_cycle_time_hours(&self) -> Option<f64> {
+        self.p90_cycle_time_hours
+    }
+
+    /// Returns the median time from creation to the first review in hours
+    pub fn median_time_to_first_review_hours(&self) -> Option<f64> {
+        self.median_time_to_first_review_hours
+    }
+
+    /// Returns the largest pull requests by changed lines
+    pub fn largest(&self) -> &[PullRequest] {
+        &self.largest
+    }
+}
+
+fn to_hours(duration: Duration) -> f64 {
+    duration.num_seconds() as f64 / 3600.0
+}
+
+/// Calculates a percentile using the nearest-rank method
+pub(crate) fn percentile(values: &[f64], percentile: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let rank = ((percentile / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    fn pull_request(number: u32, created_day: u32, merged_after_hours: Option<i64>) -> PullRequest {
+        let created_at = Utc.with_ymd_and_hms(2024, 5, created_day, 0, 0, 0).unwrap();
+        let merged_at = merged_after_hours.map(|hours| created_at + Duration::hours(hours));
+        PullRequest::new(
+            number,
+            format!("PR {}", number),
+            "connect0459/nenpo".to_string(),
+            "connect0459".to_string(),
+            created_at,
+        )
+        .with_lifecycle(merged_at, merged_at)
+        .with_changes(number * 10, 0)
+        .with_reviews(1, Some(created_at + Duration::hours(2)))
+    }
+
+    #[test]
+    fn summarizes_pull_requests() {
+        let pull_requests: Vec<PullRequest> = (1..=10)
+            .map(|n| pull_request(n, n, Some(n as i64)))
+            .collect();
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+
+        let summary = PullRequestSummary::from_pull_requests(&pull_requests, Period::utc(from, to));
+
+        assert_eq!(summary.authored(), 10);
+        assert_eq!(summary.merged(), 10);
+        assert_eq!(summary.median_cycle_time_hours(), Some(5.0));
+        assert_eq!(summary.p90_cycle_time_hours(), Some(9.0));
+        assert_eq!(summary.median_time_to_first_review_hours(), Some(2.0));
+        assert_eq!(summary.largest().len(), 5);
+        assert_eq!(summary.largest()[0].number(), 10);
+    }
+
+    #[test]
+    fn counts_only_pull_requests_within_period() {
+        let pull_requests = vec![pull_request(1, 1, Some(24)), pull_request(2, 31, None)];
+        let from = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+
+        let summary = PullRequestSummary::from_pull_requests(&pull_requests, Period::utc(from, to));
+
+        // PR 1 was created before the period but merged within it
+        assert_eq!(summary.authored(), 1);
+        assert_eq!(summary.merged(), 1);
+        assert_eq!(summary.median_cycle_time_hours(), Some(24.0));
+    }
+
+    #[test]
+    fn returns_none_for_percentile_of_empty_values() {
+        assert_eq!(percentile(&[], 50.0), None);
+        assert_eq!(percentile(&[3.0, 1.0, 2.0], 50.0), Some(2.0));
+    }
+}
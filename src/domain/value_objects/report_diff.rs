@@ -0,0 +1,176 @@
+use crate::domain::entities::report::Report;
+use crate::domain::value_objects::comparison::Delta;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Identifies one of the two reports being compared
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiffSide {
+    source: String,
+    department_name: String,
+    period_label: String,
+    period_from: NaiveDate,
+    period_to: NaiveDate,
+}
+
+impl DiffSide {
+    /// Describes a report loaded from the given source
+    ///
+    /// Reports written before period labels existed fall back to the fiscal year label.
+    pub fn new(source: String, report: &Report) -> Self {
+        let period_label = if report.period_label().is_empty() {
+            format!("FY{}", report.year())
+        } else {
+            report.period_label().to_string()
+        };
+        Self {
+            source,
+            department_name: report.department_name().to_string(),
+            period_label,
+            period_from: report.period_from(),
+            period_to: report.period_to(),
+        }
+    }
+
+    /// Returns where the report was loaded from (e.g., its file name)
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the department name of the report
+    pub fn department_name(&self) -> &str {
+        &self.department_name
+    }
+
+    /// Returns the period label of the report (e.g., "FY2024-Q3")
+    pub fn period_label(&self) -> &str {
+        &self.period_label
+    }
+
+    /// Returns the first day of the report's period
+    pub fn period_from(&self) -> NaiveDate {
+        self.period_from
+    }
+
+    /// Returns the last day of the report's period
+    pub fn period_to(&self) -> NaiveDate {
+        self.period_to
+    }
+}
+
+/// Represents a side-by-side comparison of two previously generated reports
+///
+/// Each delta takes the left report as the previous value and the right report as the current one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportDiff {
+    left: DiffSide,
+    right: DiffSide,
+    metrics: Vec<Delta>,
+    themes: Vec<Delta>,
+}
+
+impl ReportDiff {
+    /// Compares the activity metrics and commit themes of two reports
+    pub fn between(
+        left: DiffSide,
+        left_report: &Report,
+        right: DiffSide,
+        right_report: &Report,
+    ) -> Self {
+        Self {
+            left,
+            right,
+            metrics: Delta::metrics(right_report, left_report),
+            themes: Delta::themes(right_report, left_report),
+        }
+    }
+
+    /// Returns the left-hand report
+    pub fn left(&self) -> &DiffSide {
+        &self.left
+    }
+
+    /// Returns the right-hand report
+    pub fn right(&self) -> &DiffSide {
+        &self.right
+    }
+
+    /// Returns the differences of the activity metrics
+    pub fn metrics(&self) -> &[Delta] {
+        &self.metrics
+    }
+
+    /// Returns the differences of the commit themes, in theme order
+    pub fn themes(&self) -> &[Delta] {
+        &self.themes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::value_objects::commit_theme::CommitTheme;
+    use crate::domain::value_objects::period_kind::PeriodKind;
+    use std::collections::HashMap;
+
+    fn report(department: &str, commits: u32, themes: Vec<(CommitTheme, u32)>) -> Report {
+        Report::new(
+            2024,
+            department.to_string(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+            GitHubActivity::new(commits, 10, 2, 5),
+            vec![],
+            themes.into_iter().collect::<HashMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn compares_right_report_against_left_report() {
+        let left = report("Team A", 80, vec![(CommitTheme::Feat, 4)]);
+        let right = report("Team B", 100, vec![(CommitTheme::Fix, 2)]);
+
+        let diff = ReportDiff::between(
+            DiffSide::new("a.json".to_string(), &left),
+            &left,
+            DiffSide::new("b.json".to_string(), &right),
+            &right,
+        );
+
+        assert_eq!(diff.left().department_name(), "Team A");
+        assert_eq!(diff.right().source(), "b.json");
+        assert_eq!(diff.metrics()[0].label(), "Total Commits");
+        assert_eq!(diff.metrics()[0].previous(), 80);
+        assert_eq!(diff.metrics()[0].current(), 100);
+        let themes: Vec<_> = diff
+            .themes()
+            .iter()
+            .map(|delta| (delta.label(), delta.previous(), delta.current()))
+            .collect();
+        assert_eq!(themes, vec![("feat", 4, 0), ("fix", 0, 2)]);
+    }
+
+    #[test]
+    fn describes_period_of_each_side() {
+        let quarter = report("Personal", 1, vec![])
+            .with_period(PeriodKind::Quarter { quarter: 3 }, "FY2024-Q3".to_string());
+        assert_eq!(
+            DiffSide::new("q3.json".to_string(), &quarter).period_label(),
+            "FY2024-Q3"
+        );
+
+        let legacy: Report = serde_json::from_value({
+            let mut json = serde_json::to_value(report("Personal", 1, vec![])).unwrap();
+            json.as_object_mut().unwrap().remove("period_label");
+            json
+        })
+        .unwrap();
+        let side = DiffSide::new("legacy.json".to_string(), &legacy);
+        assert_eq!(side.period_label(), "FY2024");
+        assert_eq!(
+            side.period_to(),
+            NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::domain::value_objects::repository_visibility::RepositoryVisibility;
+use anyhow::{Context, Result};
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+
+/// Represents which repositories of an organization/user are collected
+///
+/// Include and exclude patterns are globs matched against either the
+/// repository name (`nenpo`) or the name with owner (`connect0459/nenpo`).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RepositoryFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+    skip_forks: bool,
+    skip_archived: bool,
+    visibility: RepositoryVisibility,
+}
+
+impl RepositoryFilter {
+    /// Creates a new RepositoryFilter instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any include or exclude pattern is not a valid glob
+    pub fn new(
+        include: Vec<String>,
+        exclude: Vec<String>,
+        skip_forks: bool,
+        skip_archived: bool,
+        visibility: RepositoryVisibility,
+    ) -> Result<Self> {
+        for pattern in include.iter().chain(exclude.iter()) {
+            Pattern::new(pattern)
+                .with_context(|| format!("Invalid repository pattern: {}", pattern))?;
+        }
+
+        Ok(Self {
+            include,
+            exclude,
+            skip_forks,
+            skip_archived,
+            visibility,
+        })
+    }
+
+    /// Returns whether the filter lets every repository through
+    pub fn is_unrestricted(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the reason a repository is excluded, or `None` if it is collected
+    pub fn exclusion_reason(
+        &self,
+        owner: &str,
+        name: &str,
+        is_fork: bool,
+        is_archived: bool,
+        is_private: bool,
+    ) -> Option<String> {
+        let full_name = format!("{}/{}", owner, name);
+        let matches = |pattern: &String| {
+            Pattern::new(pattern)
+                .map(|pattern| pattern.matches(name) || pattern.matches(&full_name))
+                .unwrap_or(false)
+        };
+
+        if self.skip_forks && is_fork {
+            return Some("fork".to_string());
+        }
+        if self.skip_archived && is_archived {
+            return Some("archived".to_string());
+        }
+        if !self.visibility.allows(is_private) {
+            let visibility = if is_private { "private" } else { "public" };
+            return Some(format!("{} repository", visibility));
+        }
+        if !self.include.is_empty() && !self.include.iter().any(matches) {
+            return Some("not matched by include_repositories".to_string());
+        }
+        if let Some(pattern) = self.exclude.iter().find(|pattern| matches(pattern)) {
+            return Some(format!("matched exclude pattern \"{}\"", pattern));
+        }
+        None
+    }
+
+    /// Returns a stable key identifying the filter, or `None` if unrestricted
+    ///
+    /// Used to keep cached data fetched with different filters apart.
+    pub fn cache_key(&self) -> Option<String> {
+        if self.is_unrestricted() {
+            return None;
+        }
+
+        let description = format!(
+            "include={};exclude={};skip_forks={};skip_archived={};visibility={}",
+            self.include.join(","),
+            self.exclude.join(","),
+            self.skip_forks,
+            self.skip_archived,
+            self.visibility.as_str()
+        );
+
+        // FNV-1a, which unlike `DefaultHasher` is stable across Rust releases
+        let hash = description
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            });
+        Some(format!("{:016x}", hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unrestricted_filter_collects_everything() {
+        let filter = RepositoryFilter::default();
+
+        assert!(filter.is_unrestricted());
+        assert_eq!(filter.cache_key(), None);
+        assert_eq!(
+            filter.exclusion_reason("org", "repo", true, true, true),
+            None
+        );
+    }
+
+    #[test]
+    fn excludes_forks_archived_and_by_visibility() {
+        let filter =
+            RepositoryFilter::new(vec![], vec![], true, true, RepositoryVisibility::Public)
+                .expect("Failed to create filter");
+
+        assert_eq!(
+            filter.exclusion_reason("org", "fork", true, false, false),
+            Some("fork".to_string())
+        );
+        assert_eq!(
+            filter.exclusion_reason("org", "old", false, true, false),
+            Some("archived".to_string())
+        );
+        assert_eq!(
+            filter.exclusion_reason("org", "secret", false, false, true),
+            Some("private repository".to_string())
+        );
+        assert_eq!(
+            filter.exclusion_reason("org", "app", false, false, false),
+            None
+        );
+    }
+
+    #[test]
+    fn applies_include_and_exclude_patterns() {
+        let filter = RepositoryFilter::new(
+            vec!["api-*".to_string(), "org/web".to_string()],
+            vec!["*-sandbox".to_string()],
+            false,
+            false,
+            RepositoryVisibility::All,
+        )
+        .expect("Failed to create filter");
+
+        assert_eq!(
+            filter.exclusion_reason("org", "api-users", false, false, false),
+            None
+        );
+        assert_eq!(
+            filter.exclusion_reason("org", "web", false, false, false),
+            None
+        );
+        assert_eq!(
+            filter.exclusion_reason("org", "scratch", false, false, false),
+            Some("not matched by include_repositories".to_string())
+        );
+        assert_eq!(
+            filter.exclusion_reason("org", "api-sandbox", false, false, false),
+            Some("matched exclude pattern \"*-sandbox\"".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let result = RepositoryFilter::new(
+            vec!["[".to_string()],
+            vec![],
+            false,
+            false,
+            RepositoryVisibility::All,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn derives_distinct_cache_keys() {
+        let forks = RepositoryFilter::new(vec![], vec![], true, false, RepositoryVisibility::All)
+            .expect("Failed to create filter");
+        let archived =
+            RepositoryFilter::new(vec![], vec![], false, true, RepositoryVisibility::All)
+                .expect("Failed to create filter");
+
+        assert!(forks.cache_key().is_some());
+        assert_ne!(forks.cache_key(), archived.cache_key());
+        assert_eq!(forks.cache_key(), forks.clone().cache_key());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::domain::entities::commit::Commit;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Represents a repository whose commits were also found in other repositories
+/// (forks, mirrors) and were counted once
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryMirror {
+    repository: String,
+    mirrors: Vec<String>,
+    commits: u32,
+}
+
+impl RepositoryMirror {
+    /// Creates a new RepositoryMirror instance
+    #[allow(dead_code)]
+    pub fn new(repository: String, mirrors: Vec<String>, commits: u32) -> Self {
+        Self {
+            repository,
+            mirrors,
+            commits,
+        }
+    }
+
+    /// Groups deduplicated commits by the repository they were kept in and the
+    /// other repositories they appeared in, most shared commits first
+    pub fn from_commits(commits: &[Commit]) -> Vec<Self> {
+        let mut counts: BTreeMap<(String, Vec<String>), u32> = BTreeMap::new();
+        for commit in commits {
+            let repositories = commit.repositories();
+            let Some((repository, mirrors)) = repositories.split_first() else {
+                continue;
+            };
+            if mirrors.is_empty() {
+                continue;
+            }
+            let key = (
+                repository.to_string(),
+                mirrors.iter().map(|mirror| mirror.to_string()).collect(),
+            );
+            *counts.entry(key).or_insert(0) += 1;
+        }
+
+        let mut mirrors: Vec<Self> = counts
+            .into_iter()
+            .map(|((repository, mirrors), commits)| Self {
+                repository,
+                mirrors,
+                commits,
+            })
+            .collect();
+        mirrors.sort_by_key(|mirror| std::cmp::Reverse(mirror.commits));
+        mirrors
+    }
+
+    /// Returns the repository the commits were counted in
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the other repositories the commits also appeared in
+    pub fn mirrors(&self) -> &[String] {
+        &self.mirrors
+    }
+
+    /// Returns the number of commits shared with the mirrors
+    pub fn commits(&self) -> u32 {
+        self.commits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_shared_commits_by_repository_and_mirrors() {
+        let commits: Vec<Commit> = [
+            ("a1", "org/app"),
+            ("a1", "fork/app"),
+            ("a2", "org/app"),
+            ("a2", "fork/app"),
+            ("b1", "org/lib"),
+            ("b1", "mirror/lib"),
+            ("c1", "org/tool"),
+        ]
+        .iter()
+        .map(|(sha, repository)| {
+            Commit::fixture(sha, "feat: change", repository, "2024-05-01T00:00:00Z")
+        })
+        .collect();
+        let (unique, _) = Commit::deduplicate(commits);
+
+        let mirrors = RepositoryMirror::from_commits(&unique);
+
+        assert_eq!(mirrors.len(), 2);
+        assert_eq!(mirrors[0].repository(), "org/app");
+        assert_eq!(mirrors[0].mirrors(), ["fork/app".to_string()]);
+        assert_eq!(mirrors[0].commits(), 2);
+        assert_eq!(mirrors[1].repository(), "org/lib");
+        assert_eq!(mirrors[1].commits(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Represents which repositories are collected based on their visibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepositoryVisibility {
+    /// Public and private repositories
+    #[default]
+    All,
+    /// Public repositories only
+    Public,
+    /// Private repositories only
+    Private,
+}
+
+impl RepositoryVisibility {
+    /// Parses a string into a RepositoryVisibility
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(RepositoryVisibility::All),
+            "public" => Ok(RepositoryVisibility::Public),
+            "private" => Ok(RepositoryVisibility::Private),
+            _ => Err(anyhow!("Invalid repository visibility: {}", s)),
+        }
+    }
+
+    /// Converts the RepositoryVisibility to a string
+    pub fn as_str(&self) -> &str {
+        match self {
+            RepositoryVisibility::All => "all",
+            RepositoryVisibility::Public => "public",
+            RepositoryVisibility::Private => "private",
+        }
+    }
+
+    /// Returns whether a repository with the given privacy is allowed
+    pub fn allows(&self, is_private: bool) -> bool {
+        match self {
+            RepositoryVisibility::All => true,
+            RepositoryVisibility::Public => !is_private,
+            RepositoryVisibility::Private => is_private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_string_to_repository_visibility() {
+        assert_eq!(
+            RepositoryVisibility::from_str("PUBLIC").expect("Failed to parse public"),
+            RepositoryVisibility::Public
+        );
+        assert_eq!(
+            RepositoryVisibility::from_str("private").expect("Failed to parse private"),
+            RepositoryVisibility::Private
+        );
+        assert!(RepositoryVisibility::from_str("internal").is_err());
+    }
+
+    #[test]
+    fn allows_repositories_by_visibility() {
+        assert!(RepositoryVisibility::All.allows(true));
+        assert!(RepositoryVisibility::Public.allows(false));
+        assert!(!RepositoryVisibility::Public.allows(true));
+        assert!(!RepositoryVisibility::Private.allows(false));
+    }
+}
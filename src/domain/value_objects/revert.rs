@@ -0,0 +1,342 @@
+use crate::domain::entities::commit::Commit;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Trailer that `git revert` writes into the commit body
+const REVERT_TRAILER: &str = "This reverts commit ";
+
+/// Represents a revert commit, linked to the commit it reverted when that is known
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revert {
+    repository: String,
+    sha: String,
+    committed_date: DateTime<Utc>,
+    reverted_subject: Option<String>,
+    reverted_sha: Option<String>,
+}
+
+impl Revert {
+    /// Detects revert commits and links them to their targets among the same commits
+    ///
+    /// A revert is recognized by a `Revert "<subject>"` subject or a
+    /// `This reverts commit <sha>` body. The target is found by SHA when the body
+    /// names one, otherwise by the latest earlier commit in the same repository
+    /// with the quoted subject. Reverts are returned oldest first.
+    pub fn detect(commits: &[Commit]) -> Vec<Self> {
+        let mut reverts: Vec<Self> = commits
+            .iter()
+            .filter_map(|commit| {
+                let quoted_subject = parse_quoted_subject(commit.message());
+                let trailer_sha = parse_trailer_sha(commit.message());
+                if quoted_subject.is_none() && trailer_sha.is_none() {
+                    return None;
+                }
+
+                let target = match trailer_sha {
+                    Some(sha) => commits
+                        .iter()
+                        .find(|c| c.sha() != commit.sha() && c.sha().starts_with(sha)),
+                    None => commits
+                        .iter()
+                        .filter(|c| {
+                            c.sha() != commit.sha()
+                                && c.repository().eq_ignore_ascii_case(commit.repository())
+                                && c.committed_date() <= commit.committed_date()
+                                && Some(subject_of(c.message())) == quoted_subject
+                        })
+                        .max_by_key(|c| c.committed_date()),
+                };
+
+                Some(Self {
+                    repository: commit.repository().to_string(),
+                    sha: commit.sha().to_string(),
+                    committed_date: commit.committed_date(),
+                    reverted_subject: quoted_subject
+                        .or_else(|| target.map(|c| subject_of(c.message())))
+                        .map(str::to_string),
+                    reverted_sha: target
+                        .map(|c| c.sha().to_string())
+                        .or_else(|| trailer_sha.map(str::to_string)),
+                })
+            })
+            .collect();
+        reverts.sort_by_key(|revert| revert.committed_date);
+        reverts
+    }
+
+    /// Removes reverts whose target is among the commits, together with the target
+    ///
+    /// A revert that is itself reverted no longer undoes its target, so in a chain
+    /// such as original → revert → revert of the revert only the last two cancel out
+    /// and the original, which is in effect at the end of the chain, is kept.
+    /// Expects `reverts` oldest first, as returned by [`Revert::detect`].
+    /// Returns the remaining commits and the number of commits removed.
+    pub fn exclude_pairs(commits: Vec<Commit>, reverts: &[Self]) -> (Vec<Commit>, u32) {
+        let shas: HashSet<&str> = commits.iter().map(Commit::sha).collect();
+        // Newest first, so a revert is known to be undone before it is reached
+        let mut undone: HashSet<&str> = HashSet::new();
+        let mut paired: HashSet<&str> = HashSet::new();
+        for revert in reverts.iter().rev() {
+            let Some(target) = revert.reverted_sha.as_deref() else {
+                continue;
+            };
+            if !shas.contains(target) || undone.contains(revert.sha.as_str()) {
+                continue;
+            }
+            undone.insert(target);
+            paired.insert(&revert.sha);
+            paired.insert(target);
+        }
+
+        let before = commits.len();
+        let kept: Vec<Commit> = commits
+            .into_iter()
+            .filter(|commit| !paired.contains(commit.sha()))
+            .collect();
+        let removed = (before - kept.len()) as u32;
+        (kept, removed)
+    }
+
+    /// Returns the repository name
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// Returns the SHA of the revert commit
+    #[allow(dead_code)]
+    pub fn sha(&self) -> &str {
+        &self.sha
+    }
+
+    /// Returns the abbreviated SHA of the revert commit
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(7)]
+    }
+
+    /// Returns the committed date of the revert commit
+    pub fn committed_date(&self) -> DateTime<Utc> {
+        self.committed_date
+    }
+
+    /// Returns the subject of the reverted commit, if known
+    pub fn reverted_subject(&self) -> Option<&str> {
+        self.reverted_subject.as_deref()
+    }
+
+    /// Returns the SHA of the reverted commit, if known
+    #[allow(dead_code)]
+    pub fn reverted_sha(&self) -> Option<&str> {
+        self.reverted_sha.as_deref()
+    }
+
+    /// Returns the abbreviated SHA of the reverted commit, if known
+    pub fn reverted_short_sha(&self) -> Option<&str> {
+        self.reverted_sha
+            .as_deref()
+            .map(|sha| &sha[..sha.len().min(7)])
+    }
+}
+
+/// Returns the first line of a commit message
+fn subject_of(message: &str) -> &str {
+    message.lines().next().unwrap_or("").trim()
+}
+
+/// Extracts `X` from a `Revert "X"` subject
+fn parse_quoted_subject(message: &str) -> Option<&str> {
+    subject_of(message)
+        .strip_prefix("Revert \"")?
+        .strip_suffix('"')
+}
+
+/// Extracts the SHA from a `This reverts commit <sha>.` body line
+fn parse_trailer_sha(message: &str) -> Option<&str> {
+    message.lines().skip(1).find_map(|line| {
+        let rest = line.trim().strip_prefix(REVERT_TRAILER)?;
+        let end = rest
+            .find(|c: char| !c.is_ascii_hexdigit())
+            .unwrap_or(rest.len());
+        (end >= 7).then(|| &rest[..end])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_revert_to_target_by_trailer_sha() {
+        let commits = vec![
+            Commit::fixture(
+                "aaaaaaa1111",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            ),
+            Commit::fixture(
+                "bbbbbbb2222",
+                "Revert \"feat: add export\"\n\nThis reverts commit aaaaaaa1111.",
+                "org/app",
+                "2024-05-03T10:00:00Z",
+            ),
+        ];
+
+        let reverts = Revert::detect(&commits);
+
+        assert_eq!(reverts.len(), 1);
+        assert_eq!(reverts[0].sha(), "bbbbbbb2222");
+        assert_eq!(reverts[0].reverted_sha(), Some("aaaaaaa1111"));
+        assert_eq!(reverts[0].reverted_subject(), Some("feat: add export"));
+        assert_eq!(reverts[0].reverted_short_sha(), Some("aaaaaaa"));
+    }
+
+    #[test]
+    fn links_revert_to_target_by_quoted_subject() {
+        let commits = vec![
+            Commit::fixture(
+                "aaaaaaa1111",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            ),
+            Commit::fixture(
+                "ccccccc3333",
+                "feat: add export",
+                "org/app",
+                "2024-05-02T10:00:00Z",
+            ),
+            Commit::fixture(
+                "bbbbbbb2222",
+                "Revert \"feat: add export\"",
+                "org/app",
+                "2024-05-03T10:00:00Z",
+            ),
+        ];
+
+        let reverts = Revert::detect(&commits);
+
+        // The latest earlier commit with the subject is the target
+        assert_eq!(reverts[0].reverted_sha(), Some("ccccccc3333"));
+    }
+
+    #[test]
+    fn keeps_unlinked_reverts() {
+        let commits = vec![Commit::fixture(
+            "bbbbbbb2222",
+            "Undo export\n\nThis reverts commit 0123456789abcdef.",
+            "org/app",
+            "2024-05-03T10:00:00Z",
+        )];
+
+        let reverts = Revert::detect(&commits);
+
+        assert_eq!(reverts.len(), 1);
+        assert_eq!(reverts[0].reverted_subject(), None);
+        assert_eq!(reverts[0].reverted_sha(), Some("0123456789abcdef"));
+    }
+
+    #[test]
+    fn ignores_regular_commits() {
+        let commits = vec![
+            Commit::fixture(
+                "aaaaaaa1111",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            ),
+            Commit::fixture(
+                "bbbbbbb2222",
+                "Revert the export button color",
+                "org/app",
+                "2024-05-02T10:00:00Z",
+            ),
+        ];
+
+        assert!(Revert::detect(&commits).is_empty());
+    }
+
+    #[test]
+    fn excludes_only_linked_pairs() {
+        let commits = vec![
+            Commit::fixture(
+                "aaaaaaa1111",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            ),
+            Commit::fixture(
+                "bbbbbbb2222",
+                "Revert \"feat: add export\"",
+                "org/app",
+                "2024-05-03T10:00:00Z",
+            ),
+            Commit::fixture(
+                "ddddddd4444",
+                "Revert \"feat: something older\"",
+                "org/app",
+                "2024-05-04T10:00:00Z",
+            ),
+            Commit::fixture(
+                "eeeeeee5555",
+                "fix: typo",
+                "org/app",
+                "2024-05-05T10:00:00Z",
+            ),
+        ];
+        let reverts = Revert::detect(&commits);
+
+        let (kept, removed) = Revert::exclude_pairs(commits, &reverts);
+
+        assert_eq!(removed, 2);
+        let shas: Vec<_> = kept.iter().map(Commit::sha).collect();
+        assert_eq!(shas, vec!["ddddddd4444", "eeeeeee5555"]);
+    }
+
+    #[test]
+    fn keeps_commit_in_effect_at_end_of_revert_chain() {
+        let commits = vec![
+            Commit::fixture(
+                "aaaaaaa1111",
+                "feat: add export",
+                "org/app",
+                "2024-05-01T10:00:00Z",
+            ),
+            Commit::fixture(
+                "bbbbbbb2222",
+                "Revert \"feat: add export\"\n\nThis reverts commit aaaaaaa1111.",
+                "org/app",
+                "2024-05-02T10:00:00Z",
+            ),
+            Commit::fixture(
+                "ccccccc3333",
+                "Revert \"Revert \"feat: add export\"\"\n\nThis reverts commit bbbbbbb2222.",
+                "org/app",
+                "2024-05-03T10:00:00Z",
+            ),
+        ];
+        let reverts = Revert::detect(&commits);
+
+        let (kept, removed) = Revert::exclude_pairs(commits.clone(), &reverts);
+
+        // The revert of the revert re-applies the original change
+        assert_eq!(removed, 2);
+        let shas: Vec<_> = kept.iter().map(Commit::sha).collect();
+        assert_eq!(shas, vec!["aaaaaaa1111"]);
+
+        // Reverting once more undoes the original change again
+        let mut commits = commits;
+        commits.push(Commit::fixture(
+            "ddddddd4444",
+            "Revert \"Revert \"Revert \"feat: add export\"\"\"\n\nThis reverts commit ccccccc3333.",
+            "org/app",
+            "2024-05-04T10:00:00Z",
+        ));
+        let reverts = Revert::detect(&commits);
+
+        let (kept, removed) = Revert::exclude_pairs(commits, &reverts);
+
+        assert_eq!(removed, 4);
+        assert!(kept.is_empty());
+    }
+}
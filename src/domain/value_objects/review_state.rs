@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Represents the state of a submitted pull request review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewState {
+    /// Approved the changes
+    Approved,
+    /// Requested changes
+    ChangesRequested,
+    /// Left comments without an explicit verdict
+    Commented,
+    /// Review was dismissed after submission
+    Dismissed,
+}
+
+impl ReviewState {
+    /// Parses a GitHub GraphQL `PullRequestReviewState` value
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "APPROVED" => Ok(ReviewState::Approved),
+            "CHANGES_REQUESTED" => Ok(ReviewState::ChangesRequested),
+            "COMMENTED" => Ok(ReviewState::Commented),
+            "DISMISSED" => Ok(ReviewState::Dismissed),
+            _ => Err(anyhow!("Invalid review state: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_review_states() {
+        assert_eq!(
+            ReviewState::from_str("APPROVED").expect("Failed to parse"),
+            ReviewState::Approved
+        );
+        assert_eq!(
+            ReviewState::from_str("CHANGES_REQUESTED").expect("Failed to parse"),
+            ReviewState::ChangesRequested
+        );
+        assert_eq!(
+            ReviewState::from_str("commented").expect("Failed to parse"),
+            ReviewState::Commented
+        );
+        assert!(ReviewState::from_str("PENDING").is_err());
+    }
+}
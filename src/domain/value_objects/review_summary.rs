@@ -0,0 +1,193 @@
+use crate::domain::entities::review::Review;
+use crate::domain::value_objects::pull_request_summary::percentile;
+use crate::domain::value_objects::review_state::ReviewState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Represents the number of distinct pull requests reviewed for a repository or author
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewedPullRequests {
+    name: String,
+    pull_requests: u32,
+}
+
+impl ReviewedPullRequests {
+    /// Returns the repository or author name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of distinct pull requests reviewed
+    pub fn pull_requests(&self) -> u32 {
+        self.pull_requests
+    }
+}
+
+/// Represents code review activity of a user within a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewSummary {
+    total: u32,
+    approved: u32,
+    changes_requested: u32,
+    commented: u32,
+    comments: u32,
+    pull_requests_reviewed: u32,
+    median_turnaround_hours: Option<f64>,
+    by_repository: Vec<ReviewedPullRequests>,
+    by_author: Vec<ReviewedPullRequests>,
+}
+
+impl ReviewSummary {
+    /// Builds a summary from submitted reviews
+    pub fn from_reviews(reviews: &[Review]) -> Self {
+        let count_state = |state: ReviewState| {
+            reviews
+                .iter()
+                .filter(|review| review.state() == state)
+                .count() as u32
+        };
+
+        let mut by_repository: BTreeMap<&str, BTreeSet<(&str, u32)>> = BTreeMap::new();
+        let mut by_author: BTreeMap<&str, BTreeSet<(&str, u32)>> = BTreeMap::new();
+        for review in reviews {
+            let pull_request = (review.repository(), review.pull_request_number());
+            by_repository
+                .entry(review.repository())
+                .or_default()
+                .insert(pull_request);
+            by_author
+                .entry(review.pull_request_author())
+                .or_default()
+                .insert(pull_request);
+        }
+
+        let turnarounds: Vec<f64> = reviews
+            .iter()
+            .filter_map(|review| review.turnaround())
+            .map(|duration| duration.num_seconds() as f64 / 3600.0)
+            .collect();
+
+        Self {
+            total: reviews.len() as u32,
+            approved: count_state(ReviewState::Approved),
+            changes_requested: count_state(ReviewState::ChangesRequested),
+            commented: count_state(ReviewState::Commented),
+            comments: reviews.iter().map(|review| review.comments_count()).sum(),
+            pull_requests_reviewed: by_repository.values().map(|prs| prs.len() as u32).sum(),
+            median_turnaround_hours: percentile(&turnarounds, 50.0),
+            by_repository: to_sorted_counts(by_repository),
+            by_author: to_sorted_counts(by_author),
+        }
+    }
+
+    /// Returns the total number of submitted reviews
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// Returns the number of approving reviews
+    pub fn approved(&self) -> u32 {
+        self.approved
+    }
+
+    /// Returns the number of reviews requesting changes
+    pub fn changes_requested(&self) -> u32 {
+        self.changes_requested
+    }
+
+    /// Returns the number of comment-only reviews
+    pub fn commented(&self) -> u32 {
+        self.commented
+    }
+
+    /// Returns the total number of review comments
+    pub fn comments(&self) -> u32 {
+        self.comments
+    }
+
+    /// Returns the number of distinct pull requests reviewed
+    pub fn pull_requests_reviewed(&self) -> u32 {
+        self.pull_requests_reviewed
+    }
+
+    /// Returns the median time from review request to first review in hours
+    pub fn median_turnaround_hours(&self) -> Option<f64> {
+        self.median_turnaround_hours
+    }
+
+    /// Returns the pull requests reviewed per repository, most reviewed first
+    pub fn by_repository(&self) -> &[ReviewedPullRequests] {
+        &self.by_repository
+    }
+
+    /// Returns the pull requests reviewed per pull request author, most reviewed first
+    pub fn by_author(&self) -> &[ReviewedPullRequests] {
+        &self.by_author
+    }
+}
+
+fn to_sorted_counts(groups: BTreeMap<&str, BTreeSet<(&str, u32)>>) -> Vec<ReviewedPullRequests> {
+    let mut counts: Vec<ReviewedPullRequests> = groups
+        .into_iter()
+        .map(|(name, pull_requests)| ReviewedPullRequests {
+            name: name.to_string(),
+            pull_requests: pull_requests.len() as u32,
+        })
+        .collect();
+    counts.sort_by_key(|count| std::cmp::Reverse(count.pull_requests));
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn review(repository: &str, number: u32, author: &str, state: ReviewState) -> Review {
+        let submitted_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        Review::new(
+            repository.to_string(),
+            number,
+            author.to_string(),
+            state,
+            submitted_at,
+            2,
+        )
+    }
+
+    #[test]
+    fn summarizes_reviews() {
+        let requested_at = Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap();
+        let reviews = vec![
+            review("org/a", 1, "alice", ReviewState::Commented)
+                .with_requested_at(Some(requested_at)),
+            review("org/a", 1, "alice", ReviewState::Approved),
+            review("org/a", 2, "bob", ReviewState::ChangesRequested)
+                .with_requested_at(Some(requested_at + Duration::hours(2))),
+            review("org/b", 3, "alice", ReviewState::Approved),
+        ];
+
+        let summary = ReviewSummary::from_reviews(&reviews);
+
+        assert_eq!(summary.total(), 4);
+        assert_eq!(summary.approved(), 2);
+        assert_eq!(summary.changes_requested(), 1);
+        assert_eq!(summary.commented(), 1);
+        assert_eq!(summary.comments(), 8);
+        assert_eq!(summary.pull_requests_reviewed(), 3);
+        assert_eq!(summary.median_turnaround_hours(), Some(2.0));
+        assert_eq!(summary.by_repository()[0].name(), "org/a");
+        assert_eq!(summary.by_repository()[0].pull_requests(), 2);
+        assert_eq!(summary.by_author()[0].name(), "alice");
+        assert_eq!(summary.by_author()[0].pull_requests(), 2);
+    }
+
+    #[test]
+    fn summarizes_no_reviews() {
+        let summary = ReviewSummary::from_reviews(&[]);
+
+        assert_eq!(summary.total(), 0);
+        assert_eq!(summary.median_turnaround_hours(), None);
+        assert!(summary.by_repository().is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Represents where a department's GitHub activity is collected from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceMode {
+    /// Only repositories owned by the configured organizations/users
+    #[default]
+    Repositories,
+    /// Repositories plus the target user's contributions collection,
+    /// which also covers repositories owned by others
+    Contributions,
+}
+
+impl SourceMode {
+    /// Parses a string into a SourceMode
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "repositories" => Ok(SourceMode::Repositories),
+            "contributions" => Ok(SourceMode::Contributions),
+            _ => Err(anyhow!("Invalid source mode: {}", s)),
+        }
+    }
+
+    /// Converts the SourceMode to a string
+    #[allow(dead_code)]
+    pub fn as_str(&self) -> &str {
+        match self {
+            SourceMode::Repositories => "repositories",
+            SourceMode::Contributions => "contributions",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_string_to_source_mode() {
+        assert_eq!(
+            SourceMode::from_str("repositories").expect("Failed to parse repositories"),
+            SourceMode::Repositories
+        );
+        assert_eq!(
+            SourceMode::from_str("Contributions").expect("Failed to parse contributions"),
+            SourceMode::Contributions
+        );
+        assert!(SourceMode::from_str("everything").is_err());
+    }
+
+    #[test]
+    fn defaults_to_repositories() {
+        assert_eq!(SourceMode::default(), SourceMode::Repositories);
+        assert_eq!(SourceMode::default().as_str(), "repositories");
+    }
+}
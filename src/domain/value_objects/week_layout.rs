@@ -0,0 +1,94 @@
+use anyhow::{anyhow, Result};
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+/// Represents how many weeks each month of a fiscal quarter has in a week-based calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum QuarterPattern {
+    /// 4-4-5 weeks
+    #[default]
+    #[serde(rename = "4-4-5")]
+    FourFourFive,
+    /// 4-5-4 weeks
+    #[serde(rename = "4-5-4")]
+    FourFiveFour,
+    /// 5-4-4 weeks
+    #[serde(rename = "5-4-4")]
+    FiveFourFour,
+}
+
+impl QuarterPattern {
+    /// Parses a string into a QuarterPattern
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "4-4-5" => Ok(QuarterPattern::FourFourFive),
+            "4-5-4" => Ok(QuarterPattern::FourFiveFour),
+            "5-4-4" => Ok(QuarterPattern::FiveFourFour),
+            _ => Err(anyhow!(
+                "Invalid quarter pattern: {} (expected 4-4-5, 4-5-4 or 5-4-4)",
+                s
+            )),
+        }
+    }
+
+    /// Returns the number of weeks in each month of a quarter
+    pub fn weeks(&self) -> [u32; 3] {
+        match self {
+            QuarterPattern::FourFourFive => [4, 4, 5],
+            QuarterPattern::FourFiveFour => [4, 5, 4],
+            QuarterPattern::FiveFourFour => [5, 4, 4],
+        }
+    }
+}
+
+/// Represents a 52/53-week fiscal calendar made of whole weeks
+///
+/// Each fiscal year starts on the `week_start` day nearest to the nominal
+/// start date, so years have 52 weeks, or 53 when the drift adds up to a week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeekLayout {
+    pattern: QuarterPattern,
+    week_start: Weekday,
+}
+
+impl WeekLayout {
+    /// Creates a new WeekLayout instance
+    pub fn new(pattern: QuarterPattern, week_start: Weekday) -> Self {
+        Self {
+            pattern,
+            week_start,
+        }
+    }
+
+    /// Returns the quarter pattern
+    pub fn pattern(&self) -> QuarterPattern {
+        self.pattern
+    }
+
+    /// Returns the day weeks start on
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quarter_patterns() {
+        assert_eq!(
+            QuarterPattern::from_str("4-4-5").unwrap().weeks(),
+            [4, 4, 5]
+        );
+        assert_eq!(
+            QuarterPattern::from_str("4-5-4").unwrap().weeks(),
+            [4, 5, 4]
+        );
+        assert_eq!(
+            QuarterPattern::from_str("5-4-4").unwrap().weeks(),
+            [5, 4, 4]
+        );
+        assert!(QuarterPattern::from_str("4-4-4").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::{bail, Result};
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+/// Represents a department's working hours, Monday to Friday
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkingHours {
+    start_hour: u32,
+    end_hour: u32,
+}
+
+impl Default for WorkingHours {
+    /// 09:00-18:00
+    fn default() -> Self {
+        Self {
+            start_hour: 9,
+            end_hour: 18,
+        }
+    }
+}
+
+impl WorkingHours {
+    /// Creates a new WorkingHours instance covering `start_hour:00` to `end_hour:00`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless `start_hour < end_hour <= 24`
+    pub fn new(start_hour: u32, end_hour: u32) -> Result<Self> {
+        if start_hour >= end_hour || end_hour > 24 {
+            bail!(
+                "Working hours must satisfy start < end <= 24, got {}-{}",
+                start_hour,
+                end_hour
+            );
+        }
+        Ok(Self {
+            start_hour,
+            end_hour,
+        })
+    }
+
+    /// Returns the hour working hours start at (0-23)
+    pub fn start_hour(&self) -> u32 {
+        self.start_hour
+    }
+
+    /// Returns the hour working hours end at (1-24, exclusive)
+    pub fn end_hour(&self) -> u32 {
+        self.end_hour
+    }
+
+    /// Returns true if a local hour on the given weekday is within working hours
+    pub fn contains(&self, weekday: Weekday, hour: u32) -> bool {
+        !matches!(weekday, Weekday::Sat | Weekday::Sun)
+            && (self.start_hour..self.end_hour).contains(&hour)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_weekday_hours_within_range() {
+        let hours = WorkingHours::new(9, 18).expect("Valid working hours");
+
+        assert!(hours.contains(Weekday::Mon, 9));
+        assert!(hours.contains(Weekday::Fri, 17));
+        assert!(!hours.contains(Weekday::Fri, 18));
+        assert!(!hours.contains(Weekday::Tue, 8));
+        assert!(!hours.contains(Weekday::Sat, 10));
+        assert!(!hours.contains(Weekday::Sun, 10));
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        assert!(WorkingHours::new(18, 9).is_err());
+        assert!(WorkingHours::new(9, 9).is_err());
+        assert!(WorkingHours::new(0, 25).is_err());
+        assert!(WorkingHours::new(0, 24).is_ok());
+    }
+}
@@ -1,6 +1,9 @@
 use crate::domain::entities::commit::Commit;
+use crate::domain::value_objects::period::Period;
+use crate::domain::value_objects::repository_filter::RepositoryFilter;
 use anyhow::{Context, Result};
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
@@ -15,18 +18,18 @@ pub trait CommitCache {
     fn get(
         &self,
         org_or_user: &str,
-        from: NaiveDate,
-        to: NaiveDate,
+        period: Period,
         author: Option<&str>,
+        filter: &RepositoryFilter,
     ) -> Result<Option<Vec<Commit>>>;
 
     /// Sets commits in cache
     fn set(
         &self,
         org_or_user: &str,
-        from: NaiveDate,
-        to: NaiveDate,
+        period: Period,
         author: Option<&str>,
+        filter: &RepositoryFilter,
         commits: &[Commit],
     ) -> Result<()>;
 
@@ -42,9 +45,9 @@ impl CommitCache for NoOpCache {
     fn get(
         &self,
         _org_or_user: &str,
-        _from: NaiveDate,
-        _to: NaiveDate,
+        _period: Period,
         _author: Option<&str>,
+        _filter: &RepositoryFilter,
     ) -> Result<Option<Vec<Commit>>> {
         Ok(None)
     }
@@ -52,9 +55,9 @@ impl CommitCache for NoOpCache {
     fn set(
         &self,
         _org_or_user: &str,
-        _from: NaiveDate,
-        _to: NaiveDate,
+        _period: Period,
         _author: Option<&str>,
+        _filter: &RepositoryFilter,
         _commits: &[Commit],
     ) -> Result<()> {
         Ok(())
@@ -65,6 +68,13 @@ impl CommitCache for NoOpCache {
     }
 }
 
+/// Version of the cache entry format
+///
+/// Bump this when cached entities gain fields that older entries would load
+/// with misleading defaults (e.g., commits without parent counts or churn);
+/// entries written with another version are treated as a cache miss.
+pub(super) const CACHE_FORMAT_VERSION: u32 = 2;
+
 /// File-based cache implementation
 pub struct FileCache {
     cache_dir: PathBuf,
@@ -72,6 +82,9 @@ pub struct FileCache {
 
 #[derive(Serialize, Deserialize)]
 struct CacheEntry {
+    /// Entries written before versioning have no version and load as 0
+    #[serde(default)]
+    version: u32,
     org_or_user: String,
     from: NaiveDate,
     to: NaiveDate,
@@ -104,44 +117,64 @@ impl FileCache {
     }
 
     /// Generates a cache file path for the given parameters
-    fn cache_file_path(
+    ///
+    /// `filter_key` distinguishes data fetched with a repository filter and
+    /// `kind` distinguishes the cached data (e.g. `commits`, `pull_requests`).
+    /// Periods outside UTC cover different instants, so their timezone is part
+    /// of the name.
+    pub(super) fn cache_file_path(
         &self,
         org_or_user: &str,
-        from: NaiveDate,
-        to: NaiveDate,
+        period: Period,
         author: Option<&str>,
+        filter_key: Option<&str>,
+        kind: &str,
     ) -> PathBuf {
-        let filename = if let Some(author_name) = author {
-            format!(
-                "{}_{}_{}_{}_commits.json",
-                org_or_user,
-                from.format("%Y%m%d"),
-                to.format("%Y%m%d"),
-                author_name
-            )
-        } else {
-            format!(
-                "{}_{}_{}_commits.json",
-                org_or_user,
-                from.format("%Y%m%d"),
-                to.format("%Y%m%d")
-            )
-        };
+        let mut filename = format!(
+            "{}_{}_{}",
+            org_or_user,
+            period.from().format("%Y%m%d"),
+            period.to().format("%Y%m%d")
+        );
+        let timezone =
+            (period.timezone() != Tz::UTC).then(|| period.timezone().name().replace('/', "-"));
+        for part in [timezone.as_deref(), author, filter_key]
+            .into_iter()
+            .flatten()
+        {
+            filename.push('_');
+            filename.push_str(part);
+        }
+        let filename = format!("{}_{}.json", filename, kind);
         self.cache_dir.join(filename)
     }
+
+    /// Returns true if the period has ended, so its data can no longer change
+    ///
+    /// Data of a period still in progress (e.g., the current fiscal year) is
+    /// neither written to nor read from the cache.
+    pub(super) fn is_cacheable(period: Period) -> bool {
+        period.until() <= Utc::now()
+    }
 }
 
 impl CommitCache for FileCache {
     fn get(
         &self,
         org_or_user: &str,
-        from: NaiveDate,
-        to: NaiveDate,
+        period: Period,
         author: Option<&str>,
+        filter: &RepositoryFilter,
     ) -> Result<Option<Vec<Commit>>> {
-        let cache_file = self.cache_file_path(org_or_user, from, to, author);
-
-        if !cache_file.exists() {
+        let cache_file = self.cache_file_path(
+            org_or_user,
+            period,
+            author,
+            filter.cache_key().as_deref(),
+            "commits",
+        );
+
+        if !Self::is_cacheable(period) || !cache_file.exists() {
             return Ok(None);
         }
 
@@ -149,6 +182,9 @@ impl CommitCache for FileCache {
 
         let entry: CacheEntry =
             serde_json::from_str(&content).context("Failed to deserialize cache entry")?;
+        if entry.version != CACHE_FORMAT_VERSION {
+            return Ok(None);
+        }
 
         Ok(Some(entry.commits))
     }
@@ -156,17 +192,28 @@ impl CommitCache for FileCache {
     fn set(
         &self,
         org_or_user: &str,
-        from: NaiveDate,
-        to: NaiveDate,
+        period: Period,
         author: Option<&str>,
+        filter: &RepositoryFilter,
         commits: &[Commit],
     ) -> Result<()> {
-        let cache_file = self.cache_file_path(org_or_user, from, to, author);
+        if !Self::is_cacheable(period) {
+            return Ok(());
+        }
+
+        let cache_file = self.cache_file_path(
+            org_or_user,
+            period,
+            author,
+            filter.cache_key().as_deref(),
+            "commits",
+        );
 
         let entry = CacheEntry {
+            version: CACHE_FORMAT_VERSION,
             org_or_user: org_or_user.to_string(),
-            from,
-            to,
+            from: period.from(),
+            to: period.to(),
             commits: commits.to_vec(),
         };
 
@@ -208,7 +255,12 @@ mod tests {
         let to = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
 
         let result = cache
-            .get("test-org", from, to, None)
+            .get(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default(),
+            )
             .expect("Failed to get cache");
         assert!(result.is_none());
     }
@@ -240,11 +292,22 @@ mod tests {
         ];
 
         cache
-            .set("test-org", from, to, None, &commits)
+            .set(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default(),
+                &commits,
+            )
             .expect("Failed to set cache");
 
         let cached = cache
-            .get("test-org", from, to, None)
+            .get(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default(),
+            )
             .expect("Failed to get cache")
             .expect("Cache should exist");
 
@@ -253,6 +316,48 @@ mod tests {
         assert_eq!(cached[1].sha(), "def456");
     }
 
+    #[test]
+    fn treats_entries_of_an_older_format_as_a_miss() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = FileCache::with_cache_dir(temp_dir.path().to_path_buf())
+            .expect("Failed to create cache");
+
+        // Written before commits carried parent counts, author logins and churn
+        fs::write(
+            temp_dir
+                .path()
+                .join("test-org_20240101_20241231_commits.json"),
+            r#"{
+  "org_or_user": "test-org",
+  "from": "2024-01-01",
+  "to": "2024-12-31",
+  "commits": [
+    {
+      "sha": "abc123",
+      "message": "Merge pull request #1 from test-org/feature",
+      "author": "John Doe",
+      "committed_date": "2024-01-15T10:30:00Z",
+      "repository": "test-org/repo1"
+    }
+  ]
+}"#,
+        )
+        .expect("Failed to write cache file");
+
+        let result = cache
+            .get(
+                "test-org",
+                Period::utc(
+                    NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                    NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+                ),
+                None,
+                &RepositoryFilter::default(),
+            )
+            .expect("Failed to get cache");
+        assert!(result.is_none());
+    }
+
     #[test]
     fn clears_cache() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -271,13 +376,24 @@ mod tests {
         )];
 
         cache
-            .set("test-org", from, to, None, &commits)
+            .set(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default(),
+                &commits,
+            )
             .expect("Failed to set cache");
 
         cache.clear().expect("Failed to clear cache");
 
         let result = cache
-            .get("test-org", from, to, None)
+            .get(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default(),
+            )
             .expect("Failed to get cache");
         assert!(result.is_none());
     }
@@ -311,22 +427,165 @@ mod tests {
         )];
 
         cache
-            .set("test-org", from1, to1, None, &commits1)
+            .set(
+                "test-org",
+                Period::utc(from1, to1),
+                None,
+                &RepositoryFilter::default(),
+                &commits1,
+            )
             .expect("Failed to set cache 1");
         cache
-            .set("test-org", from2, to2, None, &commits2)
+            .set(
+                "test-org",
+                Period::utc(from2, to2),
+                None,
+                &RepositoryFilter::default(),
+                &commits2,
+            )
             .expect("Failed to set cache 2");
 
         let cached1 = cache
-            .get("test-org", from1, to1, None)
+            .get(
+                "test-org",
+                Period::utc(from1, to1),
+                None,
+                &RepositoryFilter::default(),
+            )
             .expect("Failed to get cache 1")
             .expect("Cache 1 should exist");
         let cached2 = cache
-            .get("test-org", from2, to2, None)
+            .get(
+                "test-org",
+                Period::utc(from2, to2),
+                None,
+                &RepositoryFilter::default(),
+            )
             .expect("Failed to get cache 2")
             .expect("Cache 2 should exist");
 
         assert_eq!(cached1[0].message(), "feat: Q1-Q2");
         assert_eq!(cached2[0].message(), "feat: Q3-Q4");
     }
+
+    #[test]
+    fn caches_separately_for_different_repository_filters() {
+        use crate::domain::value_objects::repository_visibility::RepositoryVisibility;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = FileCache::with_cache_dir(temp_dir.path().to_path_buf())
+            .expect("Failed to create cache");
+
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let filter = RepositoryFilter::new(vec![], vec![], true, false, RepositoryVisibility::All)
+            .expect("Failed to create filter");
+
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add feature".to_string(),
+            "John Doe".to_string(),
+            Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap(),
+            "test-org/repo1".to_string(),
+        )];
+
+        cache
+            .set("test-org", Period::utc(from, to), None, &filter, &commits)
+            .expect("Failed to set cache");
+
+        assert!(cache
+            .get("test-org", Period::utc(from, to), None, &filter)
+            .expect("Failed to get cache")
+            .is_some());
+        assert!(cache
+            .get(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default()
+            )
+            .expect("Failed to get cache")
+            .is_none());
+    }
+
+    #[test]
+    fn caches_separately_for_different_timezones() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = FileCache::with_cache_dir(temp_dir.path().to_path_buf())
+            .expect("Failed to create cache");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        let tokyo = Period::new(from, to, chrono_tz::Asia::Tokyo);
+
+        cache
+            .set("test-org", tokyo, None, &RepositoryFilter::default(), &[])
+            .expect("Failed to set cache");
+
+        assert!(cache
+            .get("test-org", tokyo, None, &RepositoryFilter::default())
+            .expect("Failed to get cache")
+            .is_some());
+        assert!(cache
+            .get(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default()
+            )
+            .expect("Failed to get cache")
+            .is_none());
+        assert!(temp_dir
+            .path()
+            .join("test-org_20240401_20250331_Asia-Tokyo_commits.json")
+            .exists());
+    }
+
+    #[test]
+    fn does_not_cache_periods_that_have_not_ended() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = FileCache::with_cache_dir(temp_dir.path().to_path_buf())
+            .expect("Failed to create cache");
+
+        let today = Utc::now().date_naive();
+        let period = Period::utc(today - chrono::Duration::days(30), today);
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add feature".to_string(),
+            "John Doe".to_string(),
+            Utc::now(),
+            "test-org/repo1".to_string(),
+        )];
+
+        cache
+            .set(
+                "test-org",
+                period,
+                None,
+                &RepositoryFilter::default(),
+                &commits,
+            )
+            .expect("Failed to set cache");
+
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+
+        // Entries written for the period by an earlier version are not reused either
+        fs::write(
+            cache.cache_file_path("test-org", period, None, None, "commits"),
+            serde_json::to_string(&CacheEntry {
+                version: CACHE_FORMAT_VERSION,
+                org_or_user: "test-org".to_string(),
+                from: period.from(),
+                to: period.to(),
+                commits,
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        let cached = cache
+            .get("test-org", period, None, &RepositoryFilter::default())
+            .expect("Failed to get cache");
+        assert!(cached.is_none());
+    }
 }
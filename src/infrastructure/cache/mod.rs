@@ -1,3 +1,5 @@
 pub mod commit_cache;
+pub mod pull_request_cache;
 
 pub use commit_cache::{CommitCache, FileCache, NoOpCache};
+pub use pull_request_cache::PullRequestCache;
@@ -0,0 +1,209 @@
+use crate::domain::entities::pull_request::PullRequest;
+use crate::domain::value_objects::period::Period;
+use crate::infrastructure::cache::commit_cache::CACHE_FORMAT_VERSION;
+use crate::infrastructure::cache::{FileCache, NoOpCache};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Trait for caching pull requests
+pub trait PullRequestCache {
+    /// Gets cached pull requests for the specified parameters
+    ///
+    /// # Returns
+    ///
+    /// `Some(pull_requests)` if cache hit, `None` if cache miss
+    fn get_pull_requests(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        author: Option<&str>,
+    ) -> Result<Option<Vec<PullRequest>>>;
+
+    /// Sets pull requests in cache
+    fn set_pull_requests(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        author: Option<&str>,
+        pull_requests: &[PullRequest],
+    ) -> Result<()>;
+}
+
+impl PullRequestCache for NoOpCache {
+    fn get_pull_requests(
+        &self,
+        _org_or_user: &str,
+        _period: Period,
+        _author: Option<&str>,
+    ) -> Result<Option<Vec<PullRequest>>> {
+        Ok(None)
+    }
+
+    fn set_pull_requests(
+        &self,
+        _org_or_user: &str,
+        _period: Period,
+        _author: Option<&str>,
+        _pull_requests: &[PullRequest],
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct PullRequestCacheEntry {
+    #[serde(default)]
+    version: u32,
+    org_or_user: String,
+    from: NaiveDate,
+    to: NaiveDate,
+    pull_requests: Vec<PullRequest>,
+}
+
+impl PullRequestCache for FileCache {
+    fn get_pull_requests(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        author: Option<&str>,
+    ) -> Result<Option<Vec<PullRequest>>> {
+        let cache_file = self.cache_file_path(org_or_user, period, author, None, "pull_requests");
+
+        if !Self::is_cacheable(period) || !cache_file.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&cache_file).context("Failed to read cache file")?;
+
+        let entry: PullRequestCacheEntry =
+            serde_json::from_str(&content).context("Failed to deserialize cache entry")?;
+        if entry.version != CACHE_FORMAT_VERSION {
+            return Ok(None);
+        }
+
+        Ok(Some(entry.pull_requests))
+    }
+
+    fn set_pull_requests(
+        &self,
+        org_or_user: &str,
+        period: Period,
+        author: Option<&str>,
+        pull_requests: &[PullRequest],
+    ) -> Result<()> {
+        if !Self::is_cacheable(period) {
+            return Ok(());
+        }
+
+        let cache_file = self.cache_file_path(org_or_user, period, author, None, "pull_requests");
+
+        let entry = PullRequestCacheEntry {
+            version: CACHE_FORMAT_VERSION,
+            org_or_user: org_or_user.to_string(),
+            from: period.from(),
+            to: period.to(),
+            pull_requests: pull_requests.to_vec(),
+        };
+
+        let json =
+            serde_json::to_string_pretty(&entry).context("Failed to serialize cache entry")?;
+
+        fs::write(&cache_file, json).context("Failed to write cache file")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::repository_filter::RepositoryFilter;
+    use crate::infrastructure::cache::CommitCache;
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    #[test]
+    fn stores_and_retrieves_pull_requests_from_cache() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = FileCache::with_cache_dir(temp_dir.path().to_path_buf())
+            .expect("Failed to create cache");
+
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        let pull_requests = vec![PullRequest::new(
+            42,
+            "feat: add report".to_string(),
+            "test-org/repo1".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap(),
+        )];
+
+        assert!(cache
+            .get_pull_requests("test-org", Period::utc(from, to), Some("connect0459"))
+            .expect("Failed to get cache")
+            .is_none());
+
+        cache
+            .set_pull_requests(
+                "test-org",
+                Period::utc(from, to),
+                Some("connect0459"),
+                &pull_requests,
+            )
+            .expect("Failed to set cache");
+
+        let cached = cache
+            .get_pull_requests("test-org", Period::utc(from, to), Some("connect0459"))
+            .expect("Failed to get cache")
+            .expect("Cache should exist");
+
+        assert_eq!(cached, pull_requests);
+    }
+
+    #[test]
+    fn does_not_mix_pull_requests_with_commits() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = FileCache::with_cache_dir(temp_dir.path().to_path_buf())
+            .expect("Failed to create cache");
+
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        cache
+            .set_pull_requests("test-org", Period::utc(from, to), None, &[])
+            .expect("Failed to set cache");
+
+        let commits = cache
+            .get(
+                "test-org",
+                Period::utc(from, to),
+                None,
+                &RepositoryFilter::default(),
+            )
+            .expect("Failed to get cache");
+        assert!(commits.is_none());
+    }
+
+    #[test]
+    fn does_not_cache_pull_requests_of_periods_that_have_not_ended() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = FileCache::with_cache_dir(temp_dir.path().to_path_buf())
+            .expect("Failed to create cache");
+
+        let today = Utc::now().date_naive();
+        let period = Period::utc(today, today + chrono::Duration::days(30));
+
+        cache
+            .set_pull_requests("test-org", period, None, &[])
+            .expect("Failed to set cache");
+
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+        assert!(cache
+            .get_pull_requests("test-org", period, None)
+            .expect("Failed to get cache")
+            .is_none());
+    }
+}
@@ -1,9 +1,22 @@
 use crate::domain::entities::config::Config;
 use crate::domain::entities::department::Department;
 use crate::domain::repositories::config_repository::ConfigRepository;
+use crate::domain::services::fiscal_calendar::FiscalCalendar;
+use crate::domain::value_objects::commit_classifier::{ClassificationRule, RuleMatcher};
+use crate::domain::value_objects::commit_filter::CommitFilter;
+use crate::domain::value_objects::commit_theme::{CommitTheme, CustomTheme};
+use crate::domain::value_objects::gitmoji::GitmojiMapping;
 use crate::domain::value_objects::output_format::OutputFormat;
+use crate::domain::value_objects::repository_filter::RepositoryFilter;
+use crate::domain::value_objects::repository_visibility::RepositoryVisibility;
+use crate::domain::value_objects::source_mode::SourceMode;
+use crate::domain::value_objects::week_layout::{QuarterPattern, WeekLayout};
+use crate::domain::value_objects::working_hours::WorkingHours;
 use anyhow::{Context, Result};
+use chrono::Weekday;
+use chrono_tz::Tz;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::path::Path;
 
 /// Intermediate structure for deserializing TOML
@@ -16,6 +29,46 @@ struct TomlConfig {
     default_output_format: String,
     output_directory: String,
     departments: Vec<TomlDepartment>,
+    #[serde(default)]
+    commit_filters: TomlCommitFilters,
+    #[serde(default)]
+    commit_categories: Vec<TomlCommitCategory>,
+    #[serde(default)]
+    commit_rules: Vec<TomlCommitRule>,
+    #[serde(default)]
+    gitmoji: BTreeMap<String, String>,
+    #[serde(default)]
+    heuristic_classification: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TomlCommitCategory {
+    key: String,
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    order: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TomlCommitRule {
+    #[serde(default)]
+    prefix: Option<String>,
+    #[serde(default)]
+    pattern: Option<String>,
+    theme: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TomlCommitFilters {
+    #[serde(default)]
+    exclude_bots: bool,
+    #[serde(default)]
+    bot_authors: Vec<String>,
+    #[serde(default)]
+    exclude_merge_commits: bool,
+    #[serde(default)]
+    exclude_reverts: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,6 +78,39 @@ struct TomlDepartment {
     fiscal_year_start_month: u32,
     github_organizations: Vec<String>,
     local_documents: Vec<String>,
+    #[serde(default)]
+    source_mode: Option<String>,
+    #[serde(default)]
+    include_repositories: Vec<String>,
+    #[serde(default)]
+    exclude_repositories: Vec<String>,
+    #[serde(default)]
+    skip_forks: bool,
+    #[serde(default)]
+    skip_archived: bool,
+    #[serde(default)]
+    visibility: Option<String>,
+    #[serde(default)]
+    timezone: Option<String>,
+    #[serde(default)]
+    working_hours: Option<TomlWorkingHours>,
+    #[serde(default)]
+    fiscal_year_start_day: Option<u32>,
+    #[serde(default)]
+    fiscal_calendar: Option<TomlFiscalCalendar>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TomlWorkingHours {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct TomlFiscalCalendar {
+    pattern: String,
+    #[serde(default)]
+    week_start: Option<String>,
 }
 
 /// TOML-based configuration repository
@@ -59,14 +145,85 @@ impl ConfigRepository for TomlConfigRepository {
             .departments
             .into_iter()
             .map(|d| {
-                Department::new(
+                let source_mode = match d.source_mode.as_deref() {
+                    Some(mode) => SourceMode::from_str(mode)
+                        .with_context(|| format!("Invalid source mode: {}", mode))?,
+                    None => SourceMode::default(),
+                };
+                let visibility = match d.visibility.as_deref() {
+                    Some(visibility) => RepositoryVisibility::from_str(visibility)
+                        .with_context(|| format!("Invalid visibility: {}", visibility))?,
+                    None => RepositoryVisibility::default(),
+                };
+                let repository_filter = RepositoryFilter::new(
+                    d.include_repositories,
+                    d.exclude_repositories,
+                    d.skip_forks,
+                    d.skip_archived,
+                    visibility,
+                )
+                .with_context(|| format!("Invalid repository filter for {}", d.name))?;
+
+                let timezone = match d.timezone.as_deref() {
+                    Some(timezone) => timezone
+                        .parse::<Tz>()
+                        .with_context(|| format!("Invalid timezone for {}", d.name))?,
+                    None => Tz::UTC,
+                };
+                let working_hours = match d.working_hours {
+                    Some(hours) => WorkingHours::new(hours.start, hours.end)
+                        .with_context(|| format!("Invalid working hours for {}", d.name))?,
+                    None => WorkingHours::default(),
+                };
+                let fiscal_year_start_day = d.fiscal_year_start_day.unwrap_or(1);
+                FiscalCalendar::new(d.fiscal_year_start_month, fiscal_year_start_day)
+                    .with_context(|| format!("Invalid fiscal year start for {}", d.name))?;
+                let week_layout = match d.fiscal_calendar {
+                    Some(calendar) => {
+                        let pattern = QuarterPattern::from_str(&calendar.pattern)
+                            .with_context(|| format!("Invalid fiscal calendar for {}", d.name))?;
+                        let week_start = match calendar.week_start.as_deref() {
+                            Some(day) => day
+                                .parse::<Weekday>()
+                                .map_err(|_| anyhow::anyhow!("Invalid week start: {}", day))?,
+                            None => Weekday::Sun,
+                        };
+                        Some(WeekLayout::new(pattern, week_start))
+                    }
+                    None => None,
+                };
+
+                let department = Department::new(
                     d.name,
                     d.fiscal_year_start_month,
                     d.github_organizations,
                     d.local_documents,
                 )
+                .with_source_mode(source_mode)
+                .with_repository_filter(repository_filter)
+                .with_timezone(timezone)
+                .with_working_hours(working_hours)
+                .with_fiscal_year_start_day(fiscal_year_start_day);
+
+                Ok(match week_layout {
+                    Some(week_layout) => department.with_week_layout(week_layout),
+                    None => department,
+                })
             })
-            .collect();
+            .collect::<Result<_>>()?;
+
+        let commit_filter = CommitFilter::new(
+            toml_config.commit_filters.exclude_bots,
+            toml_config.commit_filters.bot_authors,
+            toml_config.commit_filters.exclude_merge_commits,
+        )
+        .context("Invalid commit filters")?
+        .with_exclude_reverts(toml_config.commit_filters.exclude_reverts);
+
+        let custom_themes = build_custom_themes(toml_config.commit_categories)?;
+        let classification_rules =
+            build_classification_rules(toml_config.commit_rules, &custom_themes)?;
+        let gitmoji_mappings = build_gitmoji_mappings(toml_config.gitmoji, &custom_themes)?;
 
         Ok(Config::with_target_user(
             toml_config.target_github_user,
@@ -74,10 +231,90 @@ impl ConfigRepository for TomlConfigRepository {
             output_format,
             toml_config.output_directory,
             departments,
-        ))
+        )
+        .with_commit_filter(commit_filter)
+        .with_classification_rules(classification_rules)
+        .with_gitmoji_mappings(gitmoji_mappings)
+        .with_heuristic_classification(toml_config.heuristic_classification))
     }
 }
 
+/// Builds the custom commit categories
+///
+/// Categories without an explicit order keep their position in the file.
+fn build_custom_themes(categories: Vec<TomlCommitCategory>) -> Result<Vec<CustomTheme>> {
+    let mut custom_themes: Vec<CustomTheme> = Vec::new();
+    for (index, category) in categories.into_iter().enumerate() {
+        if CommitTheme::from_short_name(&category.key).is_some() {
+            anyhow::bail!(
+                "Commit category \"{}\" conflicts with a built-in theme",
+                category.key
+            );
+        }
+        if custom_themes
+            .iter()
+            .any(|theme| theme.key() == category.key)
+        {
+            anyhow::bail!("Duplicate commit category: {}", category.key);
+        }
+        let display_name = category
+            .display_name
+            .unwrap_or_else(|| category.key.clone());
+        let order = category.order.unwrap_or(index as u32);
+        custom_themes.push(CustomTheme::new(category.key, display_name, order));
+    }
+    Ok(custom_themes)
+}
+
+/// Resolves a theme name to a built-in theme or one of the custom categories
+fn resolve_theme(name: &str, custom_themes: &[CustomTheme]) -> Result<CommitTheme> {
+    CommitTheme::from_short_name(name)
+        .or_else(|| {
+            custom_themes
+                .iter()
+                .find(|theme| theme.key() == name)
+                .map(|theme| CommitTheme::Custom(theme.clone()))
+        })
+        .with_context(|| format!("Unknown commit theme: {}", name))
+}
+
+/// Builds commit rules, each targeting a built-in theme or a custom category
+fn build_classification_rules(
+    rules: Vec<TomlCommitRule>,
+    custom_themes: &[CustomTheme],
+) -> Result<Vec<ClassificationRule>> {
+    rules
+        .into_iter()
+        .map(|rule| {
+            let theme = resolve_theme(&rule.theme, custom_themes)?;
+            let matcher = match (rule.prefix, rule.pattern) {
+                (Some(prefix), None) => RuleMatcher::Prefix(prefix),
+                (None, Some(pattern)) => RuleMatcher::Pattern(pattern),
+                _ => anyhow::bail!(
+                    "Commit rule for \"{}\" must set exactly one of prefix or pattern",
+                    rule.theme
+                ),
+            };
+            ClassificationRule::new(matcher, theme)
+        })
+        .collect()
+}
+
+/// Builds gitmoji mappings from an emoji or `:shortcode:` to a theme name
+fn build_gitmoji_mappings(
+    gitmoji: BTreeMap<String, String>,
+    custom_themes: &[CustomTheme],
+) -> Result<Vec<GitmojiMapping>> {
+    gitmoji
+        .into_iter()
+        .map(|(code, theme)| {
+            let theme = resolve_theme(&theme, custom_themes)
+                .with_context(|| format!("Invalid gitmoji mapping for {}", code))?;
+            Ok(GitmojiMapping::new(code, theme))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +394,410 @@ local_documents = []
         fs::remove_file(temp_file).expect("Failed to remove temp file");
     }
 
+    #[test]
+    fn loads_config_with_source_mode() {
+        let toml_content = r#"
+target_github_user = "connect0459"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[departments]]
+name = "Personal"
+fiscal_year_start_month = 1
+github_organizations = ["connect0459"]
+local_documents = []
+source_mode = "contributions"
+
+[[departments]]
+name = "Corporate"
+fiscal_year_start_month = 4
+github_organizations = ["voyagegroup"]
+local_documents = []
+"#;
+
+        let temp_file = "/tmp/test_config_with_source_mode.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let repository = TomlConfigRepository::new();
+        let config = repository
+            .load(Path::new(temp_file))
+            .expect("Failed to load config");
+
+        assert_eq!(
+            config.departments()[0].source_mode(),
+            SourceMode::Contributions
+        );
+        assert_eq!(
+            config.departments()[1].source_mode(),
+            SourceMode::Repositories
+        );
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn loads_config_with_repository_filter() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[departments]]
+name = "Personal"
+fiscal_year_start_month = 1
+github_organizations = ["connect0459"]
+local_documents = []
+include_repositories = ["nenpo*"]
+exclude_repositories = ["*-sandbox"]
+skip_forks = true
+skip_archived = true
+visibility = "public"
+
+[[departments]]
+name = "Corporate"
+fiscal_year_start_month = 4
+github_organizations = ["voyagegroup"]
+local_documents = []
+"#;
+
+        let temp_file = "/tmp/test_config_with_repository_filter.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let repository = TomlConfigRepository::new();
+        let config = repository
+            .load(Path::new(temp_file))
+            .expect("Failed to load config");
+
+        let expected = RepositoryFilter::new(
+            vec!["nenpo*".to_string()],
+            vec!["*-sandbox".to_string()],
+            true,
+            true,
+            RepositoryVisibility::Public,
+        )
+        .expect("Failed to create filter");
+        assert_eq!(config.departments()[0].repository_filter(), &expected);
+        assert!(config.departments()[1]
+            .repository_filter()
+            .is_unrestricted());
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn returns_error_for_invalid_visibility() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[departments]]
+name = "Personal"
+fiscal_year_start_month = 1
+github_organizations = ["connect0459"]
+local_documents = []
+visibility = "internal"
+"#;
+
+        let temp_file = "/tmp/test_config_with_invalid_visibility.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let result = TomlConfigRepository::new().load(Path::new(temp_file));
+        assert!(result.is_err());
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn loads_config_with_timezone_and_working_hours() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[departments]]
+name = "Tokyo"
+fiscal_year_start_month = 4
+github_organizations = ["connect0459"]
+local_documents = []
+timezone = "Asia/Tokyo"
+working_hours = { start = 10, end = 19 }
+
+[[departments]]
+name = "Personal"
+fiscal_year_start_month = 1
+github_organizations = ["connect0459"]
+local_documents = []
+"#;
+
+        let temp_file = "/tmp/test_config_with_timezone.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let config = TomlConfigRepository::new()
+            .load(Path::new(temp_file))
+            .expect("Failed to load config");
+
+        assert_eq!(config.departments()[0].timezone(), chrono_tz::Asia::Tokyo);
+        assert_eq!(
+            config.departments()[0].working_hours(),
+            WorkingHours::new(10, 19).expect("Valid working hours")
+        );
+        assert_eq!(config.departments()[1].timezone(), Tz::UTC);
+        assert_eq!(
+            config.departments()[1].working_hours(),
+            WorkingHours::default()
+        );
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn returns_error_for_unknown_timezone() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[departments]]
+name = "Tokyo"
+fiscal_year_start_month = 4
+github_organizations = ["connect0459"]
+local_documents = []
+timezone = "JST"
+"#;
+
+        let temp_file = "/tmp/test_config_with_unknown_timezone.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let result = TomlConfigRepository::new().load(Path::new(temp_file));
+        let error = result.expect_err("Unknown timezone should be rejected");
+        assert!(format!("{:#}", error).contains("Invalid timezone for Tokyo"));
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn loads_config_with_fiscal_calendar() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[departments]]
+name = "Closing20th"
+fiscal_year_start_month = 4
+fiscal_year_start_day = 21
+github_organizations = ["connect0459"]
+local_documents = []
+
+[[departments]]
+name = "Retail"
+fiscal_year_start_month = 2
+github_organizations = ["connect0459"]
+local_documents = []
+fiscal_calendar = { pattern = "5-4-4", week_start = "monday" }
+"#;
+
+        let temp_file = "/tmp/test_config_with_fiscal_calendar.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let config = TomlConfigRepository::new()
+            .load(Path::new(temp_file))
+            .expect("Failed to load config");
+
+        assert_eq!(config.departments()[0].fiscal_year_start_day(), 21);
+        assert_eq!(config.departments()[0].week_layout(), None);
+        assert_eq!(config.departments()[1].fiscal_year_start_day(), 1);
+        assert_eq!(
+            config.departments()[1].week_layout(),
+            Some(WeekLayout::new(QuarterPattern::FiveFourFour, Weekday::Mon))
+        );
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn returns_error_for_invalid_fiscal_calendar() {
+        for (name, setting) in [
+            ("start_day", "fiscal_year_start_day = 31"),
+            ("pattern", "fiscal_calendar = { pattern = \"4-4-4\" }"),
+            (
+                "week_start",
+                "fiscal_calendar = { pattern = \"4-4-5\", week_start = \"someday\" }",
+            ),
+        ] {
+            let toml_content = format!(
+                r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[departments]]
+name = "Retail"
+fiscal_year_start_month = 2
+github_organizations = ["connect0459"]
+local_documents = []
+{}
+"#,
+                setting
+            );
+
+            let temp_file = format!("/tmp/test_config_with_invalid_{}.toml", name);
+            let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+            file.write_all(toml_content.as_bytes())
+                .expect("Failed to write temp file");
+
+            let result = TomlConfigRepository::new().load(Path::new(&temp_file));
+            assert!(result.is_err(), "{} should be rejected", name);
+
+            fs::remove_file(&temp_file).expect("Failed to remove temp file");
+        }
+    }
+
+    #[test]
+    fn loads_config_with_commit_filters() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[commit_filters]
+exclude_bots = true
+bot_authors = ["renovate*"]
+exclude_merge_commits = true
+exclude_reverts = true
+
+[[departments]]
+name = "Personal"
+fiscal_year_start_month = 1
+github_organizations = ["connect0459"]
+local_documents = []
+"#;
+
+        let temp_file = "/tmp/test_config_with_commit_filters.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let config = TomlConfigRepository::new()
+            .load(Path::new(temp_file))
+            .expect("Failed to load config");
+
+        let expected = CommitFilter::new(true, vec!["renovate*".to_string()], true)
+            .expect("Failed to create filter")
+            .with_exclude_reverts(true);
+        assert_eq!(config.commit_filter(), &expected);
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn loads_config_with_commit_rules() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+heuristic_classification = true
+
+[[commit_categories]]
+key = "ticket"
+display_name = "Ticket Work"
+
+[[commit_rules]]
+prefix = "修正:"
+theme = "fix"
+
+[[commit_rules]]
+pattern = "^[A-Z]+-[0-9]+"
+theme = "ticket"
+
+[gitmoji]
+"🛂" = "fix"
+":alien:" = "ticket"
+
+[[departments]]
+name = "Corporate"
+fiscal_year_start_month = 1
+github_organizations = ["example-org"]
+local_documents = []
+"#;
+
+        let temp_file = "/tmp/test_config_with_commit_rules.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let config = TomlConfigRepository::new()
+            .load(Path::new(temp_file))
+            .expect("Failed to load config");
+
+        assert!(config.heuristic_classification());
+        let rules = config.classification_rules();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(
+            rules[0].matcher(),
+            &RuleMatcher::Prefix("修正:".to_string())
+        );
+        assert_eq!(rules[0].theme(), &CommitTheme::Fix);
+        let CommitTheme::Custom(custom) = rules[1].theme() else {
+            panic!("Expected a custom theme");
+        };
+        assert_eq!(custom.key(), "ticket");
+        assert_eq!(custom.display_name(), "Ticket Work");
+
+        let gitmoji: Vec<_> = config
+            .gitmoji_mappings()
+            .iter()
+            .map(|m| (m.code(), m.theme().short_name()))
+            .collect();
+        assert_eq!(gitmoji, vec![(":alien:", "ticket"), ("🛂", "fix")]);
+
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
+    #[test]
+    fn returns_error_for_commit_rule_with_unknown_theme() {
+        let toml_content = r#"
+default_fiscal_year_start_month = 1
+default_output_format = "markdown"
+output_directory = "./reports"
+
+[[commit_rules]]
+prefix = "追加:"
+theme = "feature"
+
+[[departments]]
+name = "Corporate"
+fiscal_year_start_month = 1
+github_organizations = ["example-org"]
+local_documents = []
+"#;
+
+        let temp_file = "/tmp/test_config_with_unknown_rule_theme.toml";
+        let mut file = fs::File::create(temp_file).expect("Failed to create temp file");
+        file.write_all(toml_content.as_bytes())
+            .expect("Failed to write temp file");
+
+        let result = TomlConfigRepository::new().load(Path::new(temp_file));
+
+        assert!(result.is_err());
+        fs::remove_file(temp_file).expect("Failed to remove temp file");
+    }
+
     #[test]
     fn returns_error_when_loading_nonexistent_file() {
         let repository = TomlConfigRepository::new();
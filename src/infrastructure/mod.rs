@@ -3,3 +3,4 @@ pub mod config;
 pub mod document;
 pub mod github;
 pub mod output;
+pub mod report;
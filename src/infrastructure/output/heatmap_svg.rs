@@ -0,0 +1,114 @@
+use crate::domain::value_objects::contribution_calendar::ContributionCalendar;
+use chrono::{Datelike, NaiveDate};
+
+/// Size of one day cell in pixels
+const CELL_SIZE: u32 = 10;
+/// Distance between the top-left corners of adjacent cells in pixels
+const CELL_STEP: u32 = 12;
+/// Space above the grid for month labels in pixels
+const HEADER_HEIGHT: u32 = 15;
+/// Cell colors from no contributions to the busiest days, as on GitHub profiles
+const LEVEL_COLORS: [&str; 5] = ["#ebedf0", "#9be9a8", "#40c463", "#30a14e", "#216e39"];
+
+/// Renders the calendar as a GitHub-style heatmap: one column per week, Sunday at the top
+pub fn render_heatmap_svg(calendar: &ContributionCalendar) -> String {
+    let days = calendar.days();
+    let Some(&first) = days.keys().next() else {
+        return String::new();
+    };
+    let max = days.values().copied().max().unwrap_or(0);
+    let first_sunday =
+        first - chrono::Duration::days(first.weekday().num_days_from_sunday() as i64);
+    let column = |date: NaiveDate| ((date - first_sunday).num_days() / 7) as u32;
+
+    let weeks = days.keys().last().map_or(0, |&last| column(last) + 1);
+    let width = weeks * CELL_STEP;
+    let height = HEADER_HEIGHT + 7 * CELL_STEP;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" font-family=\"sans-serif\" font-size=\"9\">\n",
+        width, height, width, height
+    );
+    for (&date, &count) in days {
+        let x = column(date) * CELL_STEP;
+        if date.day() == 1 || date == first {
+            svg.push_str(&format!(
+                "  <text x=\"{}\" y=\"10\" fill=\"#767676\">{}</text>\n",
+                x,
+                date.format("%b")
+            ));
+        }
+        let y = HEADER_HEIGHT + date.weekday().num_days_from_sunday() * CELL_STEP;
+        svg.push_str(&format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"2\" fill=\"{}\"><title>{}: {} contribution{}</title></rect>\n",
+            x,
+            y,
+            CELL_SIZE,
+            CELL_SIZE,
+            LEVEL_COLORS[level(count, max)],
+            date,
+            count,
+            if count == 1 { "" } else { "s" }
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Maps a count to a color level: 0 for none, then quartiles of the busiest day
+fn level(count: u32, max: u32) -> usize {
+    if count == 0 || max == 0 {
+        return 0;
+    }
+    (((count * 4).div_ceil(max)) as usize).clamp(1, 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::contribution_calendar::DailyContribution;
+    use crate::domain::value_objects::period::Period;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn renders_one_cell_per_day_in_week_columns() {
+        // 2024-04-01 is a Monday, so it sits in the second row of the first column
+        let counts = vec![
+            DailyContribution::new(date(4, 1), 4),
+            DailyContribution::new(date(4, 7), 1),
+        ];
+        let calendar = ContributionCalendar::from_daily_counts(
+            &counts,
+            Period::utc(date(4, 1), date(4, 30)),
+            date(4, 30),
+        );
+
+        let svg = render_heatmap_svg(&calendar);
+
+        assert_eq!(svg.matches("<rect").count(), 30);
+        assert!(svg.contains(
+            "<rect x=\"0\" y=\"27\" width=\"10\" height=\"10\" rx=\"2\" fill=\"#216e39\"><title>2024-04-01: 4 contributions</title></rect>"
+        ));
+        assert!(svg.contains(
+            "<rect x=\"12\" y=\"15\" width=\"10\" height=\"10\" rx=\"2\" fill=\"#9be9a8\"><title>2024-04-07: 1 contribution</title></rect>"
+        ));
+        assert!(svg.contains("<text x=\"0\" y=\"10\" fill=\"#767676\">Apr</text>"));
+    }
+
+    #[test]
+    fn maps_counts_to_quartile_levels() {
+        assert_eq!(level(0, 8), 0);
+        assert_eq!(level(1, 8), 1);
+        assert_eq!(level(2, 8), 1);
+        assert_eq!(level(3, 8), 2);
+        assert_eq!(level(8, 8), 4);
+    }
+
+    #[test]
+    fn renders_nothing_for_empty_calendar() {
+        assert!(render_heatmap_svg(&ContributionCalendar::default()).is_empty());
+    }
+}
@@ -1,6 +1,17 @@
+use crate::domain::entities::release::Release;
 use crate::domain::entities::report::Report;
+use crate::domain::entities::trend_report::TrendReport;
 use crate::domain::repositories::output_repository::OutputRepository;
+use crate::domain::value_objects::activity_timeline::TimelineBucket;
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use crate::domain::value_objects::commit_time_distribution::WEEKDAY_LABELS;
+use crate::domain::value_objects::period_kind::PeriodKind;
+use crate::domain::value_objects::revert::Revert;
+use crate::infrastructure::output::heatmap_svg::render_heatmap_svg;
+use crate::infrastructure::output::trend_chart_svg::render_trend_chart_svg;
 use anyhow::Result;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::path::Path;
 
 /// HTML output repository
@@ -20,67 +31,16 @@ impl OutputRepository for HtmlOutputRepository {
         // Calculate total commits from theme summary
         let your_commits_count: usize = report.theme_summary().values().map(|&v| v as usize).sum();
 
-        let mut content = format!(
-            r#"<!DOCTYPE html>
-<html lang="ja">
-<head>
-    <meta charset="UTF-8">
-    <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>Annual Report {}</title>
-    <style>
-        body {{
-            font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif;
-            max-width: 800px;
-            margin: 0 auto;
-            padding: 20px;
-            background-color: #f5f5f5;
-        }}
-        .container {{
-            background-color: white;
-            padding: 30px;
-            border-radius: 8px;
-            box-shadow: 0 2px 4px rgba(0,0,0,0.1);
-        }}
-        h1 {{
-            color: #333;
-            border-bottom: 2px solid #007bff;
-            padding-bottom: 10px;
-        }}
-        h2 {{
-            color: #555;
-            margin-top: 30px;
-        }}
-        h3 {{
-            color: #666;
-            margin-top: 20px;
-        }}
-        h4 {{
-            color: #777;
-            margin-top: 15px;
-        }}
-        ul {{
-            list-style-type: none;
-            padding-left: 0;
-        }}
-        li {{
-            padding: 8px 0;
-            border-bottom: 1px solid #eee;
-        }}
-        .stat {{
-            font-weight: bold;
-            color: #007bff;
-        }}
-    </style>
-</head>
-<body>
-    <div class="container">
-        <h1>Annual Report {}</h1>
+        let mut content = page_header(&report_title(report));
+        content.push_str(&format!(
+            r#"        <h1>{}</h1>
         <h2>{}</h2>
 
         <h3>Period</h3>
         <ul>
             <li>From: {}</li>
             <li>To: {}</li>
+            <li>Timezone: {}</li>
         </ul>
 
         <h3>Organization Activity Summary</h3>
@@ -96,35 +56,496 @@ impl OutputRepository for HtmlOutputRepository {
             <li>Your Commits: <span class="stat">{}</span></li>
         </ul>
 "#,
-            report.year(),
-            report.year(),
+            escape_html(&report_title(report)),
             report.department_name(),
             report.period_from(),
             report.period_to(),
+            report.timezone(),
             report.github_activity().commits(),
             report.github_activity().pull_requests(),
             report.github_activity().issues(),
             report.github_activity().reviews(),
             your_commits_count,
-        );
+        ));
 
         // Theme Summary (Conventional Commits)
         if !report.theme_summary().is_empty() {
             content.push_str("\n        <h4>Commit Themes</h4>\n");
             content.push_str("        <ul>\n");
             let mut themes: Vec<_> = report.theme_summary().iter().collect();
-            themes.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+            // Sort by count descending, then by theme order
+            themes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.sort_order().cmp(&b.0.sort_order())));
 
             for (theme, count) in themes {
                 content.push_str(&format!(
                     "            <li>{}: <span class=\"stat\">{}</span></li>\n",
-                    theme.short_name(),
+                    escape_html(theme.label()),
                     count
                 ));
             }
             content.push_str("        </ul>\n");
         }
 
+        // Classification (only show if any commit was classified)
+        let classification = report.classification_summary();
+        if classification.total() > 0 {
+            content.push_str("\n        <h4>Classification</h4>\n");
+            content.push_str("        <ul>\n");
+            for (label, count, suffix) in [
+                (
+                    "Conventional Commits",
+                    classification.convention(),
+                    String::new(),
+                ),
+                ("Custom Rules", classification.rule(), String::new()),
+                ("Gitmoji", classification.gitmoji(), String::new()),
+                (
+                    "Heuristic",
+                    classification.heuristic(),
+                    confidence_suffix(classification.heuristic_confidence()),
+                ),
+                ("Unclassified", classification.unclassified(), String::new()),
+            ] {
+                content.push_str(&format!(
+                    "            <li>{}: <span class=\"stat\">{}</span>{}</li>\n",
+                    label, count, suffix
+                ));
+            }
+            content.push_str("        </ul>\n");
+        }
+
+        // Commit Scopes (only show if commits used Conventional Commits scopes)
+        if !report.scope_summary().is_empty() {
+            content.push_str("\n        <h4>Commit Scopes</h4>\n");
+            content.push_str("        <ul>\n");
+            for scope in report.scope_summary() {
+                content.push_str(&format!(
+                    "            <li>{}: <span class=\"stat\">{}</span></li>\n",
+                    escape_html(scope.scope()),
+                    scope.count()
+                ));
+            }
+            content.push_str("        </ul>\n");
+        }
+
+        // Breaking Changes (only show if any commit was marked as breaking)
+        if !report.breaking_changes().is_empty() {
+            content.push_str("\n        <h4>Breaking Changes</h4>\n");
+            content.push_str("        <ul>\n");
+            for change in report.breaking_changes() {
+                let scope = change
+                    .scope()
+                    .map(|scope| format!(" ({})", escape_html(scope)))
+                    .unwrap_or_default();
+                content.push_str(&format!(
+                    "            <li>{} {}{} <code>{}</code>: {}</li>\n",
+                    report.local_date(change.committed_date()),
+                    escape_html(change.repository()),
+                    scope,
+                    change.short_sha(),
+                    escape_html(&flatten_note(change.note()))
+                ));
+            }
+            content.push_str("        </ul>\n");
+        }
+
+        // Reverts (only show if any commit was reverted)
+        if !report.reverts().is_empty() {
+            content.push_str("\n        <h4>Reverts</h4>\n");
+            content.push_str("        <ul>\n");
+            for revert in report.reverts() {
+                content.push_str(&format!(
+                    "            <li>{} {} <code>{}</code> reverted {}</li>\n",
+                    report.local_date(revert.committed_date()),
+                    escape_html(revert.repository()),
+                    revert.short_sha(),
+                    describe_reverted(revert)
+                ));
+            }
+            content.push_str("        </ul>\n");
+        }
+
+        // Excluded Commits (only show if filtering or deduplication dropped anything)
+        let excluded = report.excluded_commits();
+        if excluded.total() > 0 || report.duplicate_commits() > 0 {
+            content.push_str("\n        <h4>Excluded Commits</h4>\n");
+            content.push_str("        <ul>\n");
+            for (label, count) in [
+                ("Bot Commits", excluded.bots()),
+                ("Merge Commits", excluded.merges()),
+                ("Reverted Commits", excluded.reverts()),
+            ] {
+                content.push_str(&format!(
+                    "            <li>{}: <span class=\"stat\">{}</span></li>\n",
+                    label, count
+                ));
+            }
+            content.push_str(&format!(
+                "            <li>Duplicate Commits: <span class=\"stat\">{}</span>",
+                report.duplicate_commits()
+            ));
+            if !report.repository_mirrors().is_empty() {
+                content.push_str("\n                <ul>\n");
+                for mirror in report.repository_mirrors() {
+                    let mirrors: Vec<String> =
+                        mirror.mirrors().iter().map(|m| escape_html(m)).collect();
+                    content.push_str(&format!(
+                        "                    <li>{} (also in {}): <span class=\"stat\">{}</span></li>\n",
+                        escape_html(mirror.repository()),
+                        mirrors.join(", "),
+                        mirror.commits()
+                    ));
+                }
+                content.push_str("                </ul>\n            ");
+            }
+            content.push_str("</li>\n");
+            content.push_str("        </ul>\n");
+        }
+
+        // Languages (only show if the user's commits could be attributed to languages)
+        if !report.languages().is_empty() {
+            content.push_str("\n        <h3>Languages</h3>\n");
+            content.push_str("        <ul>\n");
+            for language in report.languages() {
+                content.push_str(&format!(
+                    "            <li>{}: <span class=\"stat\">{:.1}%</span> ({:.1} commits)</li>\n",
+                    escape_html(language.name()),
+                    language.percentage(),
+                    language.weighted_commits()
+                ));
+            }
+            content.push_str("        </ul>\n");
+        }
+
+        // Pull Requests (only show if there is pull request activity)
+        if let Some(summary) = report
+            .pull_request_summary()
+            .filter(|s| s.authored() > 0 || s.merged() > 0)
+        {
+            content.push_str("\n        <h3>Pull Requests</h3>\n");
+            content.push_str("        <ul>\n");
+            content.push_str(&format!(
+                "            <li>Authored: <span class=\"stat\">{}</span></li>\n",
+                summary.authored()
+            ));
+            content.push_str(&format!(
+                "            <li>Merged: <span class=\"stat\">{}</span></li>\n",
+                summary.merged()
+            ));
+            content.push_str(&format!(
+                "            <li>Median Cycle Time: <span class=\"stat\">{}</span></li>\n",
+                format_hours(summary.median_cycle_time_hours())
+            ));
+            content.push_str(&format!(
+                "            <li>P90 Cycle Time: <span class=\"stat\">{}</span></li>\n",
+                format_hours(summary.p90_cycle_time_hours())
+            ));
+            content.push_str(&format!(
+                "            <li>Median Time to First Review: <span class=\"stat\">{}</span></li>\n",
+                format_hours(summary.median_time_to_first_review_hours())
+            ));
+            content.push_str("        </ul>\n");
+
+            if !summary.largest().is_empty() {
+                content.push_str("\n        <h4>Largest Pull Requests</h4>\n");
+                content.push_str("        <ul>\n");
+                for pr in summary.largest() {
+                    content.push_str(&format!(
+                        "            <li>{}#{} {} (+{}/-{})</li>\n",
+                        escape_html(pr.repository()),
+                        pr.number(),
+                        escape_html(pr.title()),
+                        pr.additions(),
+                        pr.deletions()
+                    ));
+                }
+                content.push_str("        </ul>\n");
+            }
+        }
+
+        // Code Reviews (only show if the user submitted reviews)
+        if let Some(summary) = report.review_summary().filter(|s| s.total() > 0) {
+            content.push_str("\n        <h3>Code Reviews</h3>\n");
+            content.push_str("        <ul>\n");
+            for (label, value) in [
+                ("Reviews Given", summary.total().to_string()),
+                ("Approved", summary.approved().to_string()),
+                ("Changes Requested", summary.changes_requested().to_string()),
+                ("Commented", summary.commented().to_string()),
+                ("Review Comments", summary.comments().to_string()),
+                (
+                    "Pull Requests Reviewed",
+                    summary.pull_requests_reviewed().to_string(),
+                ),
+                (
+                    "Median Review Turnaround",
+                    format_hours(summary.median_turnaround_hours()),
+                ),
+            ] {
+                content.push_str(&format!(
+                    "            <li>{}: <span class=\"stat\">{}</span></li>\n",
+                    label, value
+                ));
+            }
+            content.push_str("        </ul>\n");
+
+            for (title, counts) in [
+                (
+                    "Pull Requests Reviewed by Repository",
+                    summary.by_repository(),
+                ),
+                ("Pull Requests Reviewed by Author", summary.by_author()),
+            ] {
+                content.push_str(&format!("\n        <h4>{}</h4>\n", title));
+                content.push_str("        <ul>\n");
+                for count in counts {
+                    content.push_str(&format!(
+                        "            <li>{}: <span class=\"stat\">{}</span></li>\n",
+                        escape_html(count.name()),
+                        count.pull_requests()
+                    ));
+                }
+                content.push_str("        </ul>\n");
+            }
+        }
+
+        // Issues (only show if the user was involved in issues)
+        if let Some(summary) = report.issue_summary().filter(|s| s.involved() > 0) {
+            content.push_str("\n        <h3>Issues</h3>\n");
+            content.push_str("        <ul>\n");
+            for (label, value) in [
+                ("Opened", summary.opened().to_string()),
+                ("Closed", summary.closed().to_string()),
+                ("Commented", summary.commented().to_string()),
+                (
+                    "Median Time to Close",
+                    format_hours(summary.median_time_to_close_hours()),
+                ),
+            ] {
+                content.push_str(&format!(
+                    "            <li>{}: <span class=\"stat\">{}</span></li>\n",
+                    label, value
+                ));
+            }
+            content.push_str("        </ul>\n");
+
+            for (title, column, groups) in [
+                ("Issues by Label", "Label", summary.by_label()),
+                (
+                    "Issues by Repository",
+                    "Repository",
+                    summary.by_repository(),
+                ),
+            ] {
+                content.push_str(&format!("\n        <h4>{}</h4>\n", title));
+                content.push_str("        <table>\n");
+                content.push_str(&format!(
+                    "            <tr><th>{}</th><th>Open</th><th>Closed</th><th>Closed Ratio</th></tr>\n",
+                    column
+                ));
+                for group in groups {
+                    content.push_str(&format!(
+                        "            <tr><td>{}</td><td>{}</td><td>{}</td><td>{:.0}%</td></tr>\n",
+                        escape_html(group.name()),
+                        group.open(),
+                        group.closed(),
+                        group.closed_ratio() * 100.0
+                    ));
+                }
+                content.push_str("        </table>\n");
+            }
+        }
+
+        // Releases (only show if anything was shipped)
+        if !report.releases().is_empty() {
+            content.push_str("\n        <h3>Releases</h3>\n");
+            content.push_str("        <ul>\n");
+            for release in report.releases() {
+                content.push_str(&format!(
+                    "            <li>{} {} <span class=\"stat\">{}</span>{}",
+                    report.local_date(release.published_at()),
+                    escape_html(release.repository()),
+                    escape_html(release.name()),
+                    escape_html(&release_suffix(release))
+                ));
+                if !release.themes().is_empty() {
+                    content.push_str(&format!(
+                        " — {}",
+                        escape_html(&format_themes(release.themes()))
+                    ));
+                }
+                content.push_str("</li>\n");
+            }
+            content.push_str("        </ul>\n");
+        }
+
+        // Timeline (only show if the report covers a period)
+        if !report.timeline().is_empty() {
+            content.push_str("\n        <h3>Timeline</h3>\n");
+            for (title, column, buckets) in [
+                ("Monthly", "Month", report.timeline().monthly()),
+                ("Weekly", "Week", report.timeline().weekly()),
+            ] {
+                content.push_str(&format!("        <h4>{}</h4>\n", title));
+                content.push_str(&timeline_chart(column, buckets));
+            }
+        }
+
+        // Contribution Calendar (only show if the report covers a period)
+        let calendar = report.contribution_calendar();
+        if !calendar.is_empty() {
+            content.push_str("\n        <h3>Contribution Calendar</h3>\n");
+            content.push_str(&render_heatmap_svg(calendar));
+            content.push_str("        <ul>\n");
+            content.push_str(&format!(
+                "            <li>Total Contributions: <span class=\"stat\">{}</span></li>\n",
+                calendar.total()
+            ));
+            content.push_str(&format!(
+                "            <li>Longest Streak: <span class=\"stat\">{}</span> days</li>\n",
+                calendar.longest_streak()
+            ));
+            content.push_str(&format!(
+                "            <li>Current Streak: <span class=\"stat\">{}</span> days</li>\n",
+                calendar.current_streak()
+            ));
+            if let Some(day) = calendar.most_active_day() {
+                content.push_str(&format!(
+                    "            <li>Most Active Day: {} (<span class=\"stat\">{}</span> contributions)</li>\n",
+                    day.date(),
+                    day.count()
+                ));
+            }
+            content.push_str("        </ul>\n");
+        }
+
+        // Working Hours (only show if there are commits to place in time)
+        let distribution = report.commit_time_distribution();
+        if let Some(percentage) = distribution.outside_working_hours_percentage() {
+            let working_hours = distribution.working_hours();
+            content.push_str("\n        <h3>Working Hours</h3>\n");
+            content.push_str("        <ul>\n");
+            content.push_str(&format!(
+                "            <li>Timezone: {}</li>\n",
+                escape_html(distribution.timezone())
+            ));
+            content.push_str(&format!(
+                "            <li>Working Hours: {:02}:00-{:02}:00, Mon-Fri</li>\n",
+                working_hours.start_hour(),
+                working_hours.end_hour()
+            ));
+            content.push_str(&format!(
+                "            <li>Outside Working Hours: <span class=\"stat\">{:.1}%</span> ({} of {} commits)</li>\n",
+                percentage,
+                distribution.outside_working_hours(),
+                distribution.total()
+            ));
+            content.push_str("        </ul>\n");
+
+            let by_hour: Vec<(String, u32)> = distribution
+                .by_hour()
+                .iter()
+                .enumerate()
+                .map(|(hour, &count)| (format!("{:02}:00", hour), count))
+                .collect();
+            content.push_str("        <h4>Commits by Hour</h4>\n");
+            content.push_str(&histogram("Hour", &by_hour));
+
+            let by_weekday: Vec<(String, u32)> = WEEKDAY_LABELS
+                .iter()
+                .zip(distribution.by_weekday())
+                .map(|(weekday, &count)| (weekday.to_string(), count))
+                .collect();
+            content.push_str("        <h4>Commits by Weekday</h4>\n");
+            content.push_str(&histogram("Weekday", &by_weekday));
+        }
+
+        // Activity by Organization / Repository (only show if there is any activity)
+        for (title, breakdown) in [
+            ("Activity by Organization", report.organization_breakdown()),
+            ("Activity by Repository", report.repository_breakdown()),
+        ] {
+            if breakdown.is_empty() {
+                continue;
+            }
+            content.push_str(&format!("\n        <h3>{}</h3>\n", title));
+            content.push_str("        <table>\n");
+            content.push_str(
+                "            <tr><th>Name</th><th>Commits</th><th>Pull Requests</th><th>Churn</th><th>Themes</th></tr>\n",
+            );
+            for entry in breakdown {
+                content.push_str(&format!(
+                    "            <tr><td>{}</td><td>{}</td><td>{}</td><td>+{} / -{}</td><td>{}</td></tr>\n",
+                    escape_html(entry.name()),
+                    entry.commits(),
+                    entry.pull_requests(),
+                    entry.additions(),
+                    entry.deletions(),
+                    escape_html(&format_themes(entry.themes())),
+                ));
+            }
+            content.push_str("        </table>\n");
+        }
+
+        // Contributions by Repository (only show in contributions source mode)
+        if !report.contributions().is_empty() {
+            content.push_str("\n        <h3>Contributions by Repository</h3>\n");
+            content.push_str("        <table>\n");
+            content.push_str(
+                "            <tr><th>Repository</th><th>Commits</th><th>Pull Requests</th><th>Issues</th><th>Reviews</th></tr>\n",
+            );
+            for contribution in report.contributions() {
+                content.push_str(&format!(
+                    "            <tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    escape_html(contribution.repository()),
+                    contribution.commits(),
+                    contribution.pull_requests(),
+                    contribution.issues(),
+                    contribution.reviews(),
+                ));
+            }
+            content.push_str("        </table>\n");
+        }
+
+        // Comparison with the previous fiscal year (only show if requested)
+        if let Some(comparison) = report.comparison() {
+            content.push_str(&format!(
+                "\n        <h3>Compared with {} ({} to {})</h3>\n",
+                escape_html(comparison.previous_label()),
+                comparison.previous_from(),
+                comparison.previous_to()
+            ));
+            for (column, deltas) in [
+                ("Metric", comparison.metrics()),
+                ("Theme", comparison.themes()),
+            ] {
+                if deltas.is_empty() {
+                    continue;
+                }
+                content.push_str("        <table>\n");
+                content.push_str(&format!(
+                    "            <tr><th>{}</th><th>Current</th><th>Previous</th><th>Change</th></tr>\n",
+                    column
+                ));
+                for delta in deltas {
+                    let class = match delta.change().cmp(&0) {
+                        Ordering::Greater => " class=\"up\"",
+                        Ordering::Less => " class=\"down\"",
+                        Ordering::Equal => "",
+                    };
+                    content.push_str(&format!(
+                        "            <tr><td>{}</td><td>{}</td><td>{}</td><td{}>{}</td></tr>\n",
+                        escape_html(delta.label()),
+                        delta.current(),
+                        delta.previous(),
+                        class,
+                        delta.format_change()
+                    ));
+                }
+                content.push_str("        </table>\n");
+            }
+        }
+
         // Local Documents (only show if there are documents)
         if !report.documents().is_empty() {
             content.push_str("\n        <h3>Local Documents</h3>\n");
@@ -135,100 +556,1153 @@ impl OutputRepository for HtmlOutputRepository {
             content.push_str("        </ul>\n");
         }
 
-        content.push_str(
-            r#"    </div>
-</body>
-</html>
-"#,
-        );
+        content.push_str(PAGE_FOOTER);
 
         std::fs::write(path, content)?;
         Ok(())
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::entities::document_content::DocumentContent;
-    use crate::domain::entities::github_activity::GitHubActivity;
-    use chrono::NaiveDate;
-    use std::collections::HashMap;
-    use tempfile::TempDir;
 
-    #[test]
-    fn outputs_report_to_html_file() {
-        let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let output_path = temp_dir.path().join("test_report.html");
+    fn output_trend(&self, trend: &TrendReport, path: &Path) -> Result<()> {
+        let title = trend_title(trend);
+        let mut content = page_header(&title);
+        content.push_str(&format!(
+            "        <h1>{}</h1>\n        <h2>{}</h2>\n",
+            escape_html(&title),
+            escape_html(trend.department_name())
+        ));
 
-        let activity = GitHubActivity::new(100, 20, 15, 30);
-        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
-        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
-        let report = Report::new(
-            2024,
-            "個人".to_string(),
-            from,
-            to,
-            activity,
-            vec![],
-            HashMap::new(),
+        content.push_str("\n        <h3>Year by Year</h3>\n");
+        content.push_str(&render_trend_chart_svg(trend));
+        content.push_str("        <table>\n");
+        content.push_str(
+            "            <tr><th>Fiscal Year</th><th>Period</th><th>Commits</th><th>Pull Requests</th><th>Merged</th><th>Reviews</th><th>Churn</th></tr>\n",
         );
+        for year in trend.years() {
+            content.push_str(&format!(
+                "            <tr><td>FY{}</td><td>{} to {}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>+{} / -{}</td></tr>\n",
+                year.year(),
+                year.period_from(),
+                year.period_to(),
+                year.commits(),
+                year.pull_requests(),
+                year.merged_pull_requests(),
+                year.reviews(),
+                year.additions(),
+                year.deletions(),
+            ));
+        }
+        content.push_str("        </table>\n");
 
-        let repository = HtmlOutputRepository::new();
-
-        repository
-            .output(&report, &output_path)
-            .expect("Failed to output report");
+        // Commit Themes (only show if any year has commits)
+        let themes = trend.themes();
+        if !themes.is_empty() {
+            content.push_str("\n        <h3>Commit Themes</h3>\n");
+            content.push_str("        <table>\n            <tr><th>Fiscal Year</th>");
+            for theme in &themes {
+                content.push_str(&format!("<th>{}</th>", escape_html(theme.label())));
+            }
+            content.push_str("</tr>\n");
+            for year in trend.years() {
+                content.push_str(&format!("            <tr><td>FY{}</td>", year.year()));
+                for theme in &themes {
+                    let count = year.themes().get(theme).copied().unwrap_or(0);
+                    content.push_str(&format!("<td>{}</td>", count));
+                }
+                content.push_str("</tr>\n");
+            }
+            content.push_str("        </table>\n");
+        }
 
-        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        content.push_str(PAGE_FOOTER);
 
-        // Verify HTML structure
-        assert!(content.contains("<!DOCTYPE html>"));
-        assert!(content.contains("<title>Annual Report 2024</title>"));
-        assert!(content.contains("<h1>Annual Report 2024</h1>"));
-        assert!(content.contains("<h2>個人</h2>"));
-        assert!(content.contains("<h3>Organization Activity Summary</h3>"));
-        assert!(content.contains("Total Commits: <span class=\"stat\">100</span>"));
-        assert!(content.contains("<h3>Your Activity</h3>"));
-        assert!(content.contains("Your Commits: <span class=\"stat\">0</span>"));
-        // No theme summary, so 0
+        std::fs::write(path, content)?;
+        Ok(())
     }
+}
 
-    #[test]
-    fn outputs_html_report_with_documents() {
-        let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let output_path = temp_dir.path().join("test_report_with_docs.html");
-
-        let activity = GitHubActivity::new(100, 20, 15, 30);
-        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
-        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
-
-        let documents = vec![
-            DocumentContent::new("doc1.md".to_string(), "Content 1".to_string()),
-            DocumentContent::new("doc2.md".to_string(), "Content 2".to_string()),
-        ];
-
-        let report = Report::new(
-            2024,
-            "個人".to_string(),
-            from,
-            to,
-            activity,
-            documents,
-            HashMap::new(),
-        );
-
-        let repository = HtmlOutputRepository::new();
-
-        repository
-            .output(&report, &output_path)
-            .expect("Failed to output report");
-
-        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+/// Closes the container, body and document opened by `page_header`
+const PAGE_FOOTER: &str = r#"    </div>
+</body>
+</html>
+"#;
 
-        assert!(content.contains("<h3>Local Documents</h3>"));
-        assert!(content.contains("<li>doc1.md</li>"));
-        assert!(content.contains("<li>doc2.md</li>"));
+/// Renders the document head with the shared stylesheet and opens the container
+fn page_header(title: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="ja">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{}</title>
+    <style>
+        body {{
+            font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif;
+            max-width: 800px;
+            margin: 0 auto;
+            padding: 20px;
+            background-color: #f5f5f5;
+        }}
+        .container {{
+            background-color: white;
+            padding: 30px;
+            border-radius: 8px;
+            box-shadow: 0 2px 4px rgba(0,0,0,0.1);
+        }}
+        h1 {{
+            color: #333;
+            border-bottom: 2px solid #007bff;
+            padding-bottom: 10px;
+        }}
+        h2 {{
+            color: #555;
+            margin-top: 30px;
+        }}
+        h3 {{
+            color: #666;
+            margin-top: 20px;
+        }}
+        h4 {{
+            color: #777;
+            margin-top: 15px;
+        }}
+        ul {{
+            list-style-type: none;
+            padding-left: 0;
+        }}
+        li {{
+            padding: 8px 0;
+            border-bottom: 1px solid #eee;
+        }}
+        .stat {{
+            font-weight: bold;
+            color: #007bff;
+        }}
+        table {{
+            width: 100%;
+            border-collapse: collapse;
+        }}
+        th, td {{
+            padding: 8px;
+            border-bottom: 1px solid #eee;
+            text-align: right;
+        }}
+        th:first-child, td:first-child {{
+            text-align: left;
+        }}
+        .bar-cell {{
+            width: 50%;
+        }}
+        .bar {{
+            height: 12px;
+            background-color: #007bff;
+            border-radius: 2px;
+        }}
+        .up {{
+            color: #28a745;
+        }}
+        .down {{
+            color: #dc3545;
+        }}
+    </style>
+</head>
+<body>
+    <div class="container">
+"#,
+        escape_html(title)
+    )
+}
+
+/// Returns the trend report title (e.g., "Trend Report FY2021-FY2025")
+fn trend_title(trend: &TrendReport) -> String {
+    match (trend.first_year(), trend.last_year()) {
+        (Some(from), Some(to)) => format!("Trend Report FY{}-FY{}", from, to),
+        _ => "Trend Report".to_string(),
+    }
+}
+
+/// Returns the report title: "Annual Report 2024" for a fiscal year, "Report FY2024-Q3" otherwise
+fn report_title(report: &Report) -> String {
+    match report.period_kind() {
+        PeriodKind::FiscalYear => format!("Annual Report {}", report.year()),
+        _ => format!("Report {}", report.period_label()),
+    }
+}
+
+/// Formats a duration in hours for display
+fn format_hours(hours: Option<f64>) -> String {
+    hours
+        .map(|h| format!("{:.1} hours", h))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Formats the tag (when it differs from the name) and prerelease marker of a release
+fn release_suffix(release: &Release) -> String {
+    let mut suffix = String::new();
+    if release.name() != release.tag_name() {
+        suffix.push_str(&format!(" ({})", release.tag_name()));
+    }
+    if release.is_prerelease() {
+        suffix.push_str(" [prerelease]");
+    }
+    suffix
+}
+
+/// Renders timeline buckets as a table with a bar per bucket, scaled to the busiest one
+fn timeline_chart(column: &str, buckets: &[TimelineBucket]) -> String {
+    let activity =
+        |bucket: &TimelineBucket| bucket.commits() + bucket.pull_requests() + bucket.issues();
+    let max = buckets.iter().map(activity).max().unwrap_or(0).max(1);
+
+    let mut chart = String::from("        <table>\n");
+    chart.push_str(&format!(
+        "            <tr><th>{}</th><th class=\"bar-cell\"></th><th>Commits</th><th>Pull Requests</th><th>Issues</th></tr>\n",
+        column
+    ));
+    for bucket in buckets {
+        chart.push_str(&format!(
+            "            <tr><td>{}</td><td class=\"bar-cell\"><div class=\"bar\" style=\"width: {:.1}%\"></div></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            bucket.label(),
+            activity(bucket) as f64 / max as f64 * 100.0,
+            bucket.commits(),
+            bucket.pull_requests(),
+            bucket.issues(),
+        ));
+    }
+    chart.push_str("        </table>\n");
+    chart
+}
+
+/// Renders labeled commit counts as a table with a bar per row, scaled to the largest count
+fn histogram(column: &str, rows: &[(String, u32)]) -> String {
+    let max = rows
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let mut chart = String::from("        <table>\n");
+    chart.push_str(&format!(
+        "            <tr><th>{}</th><th class=\"bar-cell\"></th><th>Commits</th></tr>\n",
+        column
+    ));
+    for (label, count) in rows {
+        chart.push_str(&format!(
+            "            <tr><td>{}</td><td class=\"bar-cell\"><div class=\"bar\" style=\"width: {:.1}%\"></div></td><td>{}</td></tr>\n",
+            label,
+            *count as f64 / max as f64 * 100.0,
+            count,
+        ));
+    }
+    chart.push_str("        </table>\n");
+    chart
+}
+
+/// Formats commit theme counts, largest first (e.g., "feat: 3, fix: 1")
+fn format_themes(themes: &HashMap<CommitTheme, u32>) -> String {
+    let mut themes: Vec<_> = themes.iter().collect();
+    themes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.sort_order().cmp(&b.0.sort_order())));
+    themes
+        .iter()
+        .map(|(theme, count)| format!("{}: {}", theme.label(), count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Joins a multi-line breaking change note into a single line
+fn flatten_note(note: &str) -> String {
+    note.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Describes the target of a revert (e.g., "&quot;feat: add export&quot; (<code>abc1234</code>)")
+fn describe_reverted(revert: &Revert) -> String {
+    let subject = revert
+        .reverted_subject()
+        .map(|subject| format!("&quot;{}&quot;", escape_html(subject)));
+    let sha = revert
+        .reverted_short_sha()
+        .map(|sha| format!("<code>{}</code>", sha));
+    match (subject, sha) {
+        (Some(subject), Some(sha)) => format!("{} ({})", subject, sha),
+        (Some(subject), None) => subject,
+        (None, Some(sha)) => sha,
+        (None, None) => "an unknown commit".to_string(),
+    }
+}
+
+/// Formats the mean confidence of heuristic classifications (e.g., " (avg. confidence 65%)")
+fn confidence_suffix(confidence: Option<f64>) -> String {
+    confidence
+        .map(|c| format!(" (avg. confidence {:.0}%)", c * 100.0))
+        .unwrap_or_default()
+}
+
+/// Escapes characters with special meaning in HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::document_content::DocumentContent;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::entities::repository_contribution::RepositoryContribution;
+    use crate::domain::value_objects::comparison::Comparison;
+    use crate::domain::value_objects::period::Period;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    #[test]
+    fn outputs_report_to_html_file() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report.html");
+
+        let activity = GitHubActivity::new(100, 20, 15, 30);
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            activity,
+            vec![],
+            HashMap::new(),
+        );
+
+        let repository = HtmlOutputRepository::new();
+
+        repository
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+
+        // Verify HTML structure
+        assert!(content.contains("<!DOCTYPE html>"));
+        assert!(content.contains("<title>Annual Report 2024</title>"));
+        assert!(content.contains("<h1>Annual Report 2024</h1>"));
+        assert!(content.contains("<h2>個人</h2>"));
+        assert!(content.contains("<h3>Organization Activity Summary</h3>"));
+        assert!(content.contains("Total Commits: <span class=\"stat\">100</span>"));
+        assert!(content.contains("<h3>Your Activity</h3>"));
+        assert!(content.contains("Your Commits: <span class=\"stat\">0</span>"));
+        // No theme summary, so 0
+    }
+
+    fn trend() -> TrendReport {
+        let report = |year: u32, themes: Vec<(CommitTheme, u32)>| {
+            Report::new(
+                year,
+                "個人".to_string(),
+                NaiveDate::from_ymd_opt(year as i32, 4, 1).expect("Invalid date"),
+                NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).expect("Invalid date"),
+                GitHubActivity::new(100, 20, 15, 30),
+                vec![],
+                themes.into_iter().collect(),
+            )
+        };
+        TrendReport::from_reports(
+            "個人".to_string(),
+            &[
+                report(2023, vec![(CommitTheme::Feat, 3)]),
+                report(2024, vec![(CommitTheme::Feat, 1), (CommitTheme::Fix, 2)]),
+            ],
+        )
+    }
+
+    #[test]
+    fn outputs_trend_with_line_chart() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("trend.html");
+
+        HtmlOutputRepository::new()
+            .output_trend(&trend(), &output_path)
+            .expect("Failed to output trend");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<title>Trend Report FY2023-FY2024</title>"));
+        assert!(content.contains("<polyline"));
+        assert!(content.contains(
+            "<tr><td>FY2024</td><td>2024-04-01 to 2025-03-31</td><td>3</td><td>0</td><td>0</td><td>30</td><td>+0 / -0</td></tr>"
+        ));
+        assert!(content.contains("<tr><td>FY2024</td><td>1</td><td>2</td></tr>"));
+        assert!(content.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn outputs_html_report_with_documents() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_with_docs.html");
+
+        let activity = GitHubActivity::new(100, 20, 15, 30);
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+
+        let documents = vec![
+            DocumentContent::new("doc1.md".to_string(), "Content 1".to_string()),
+            DocumentContent::new("doc2.md".to_string(), "Content 2".to_string()),
+        ];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            activity,
+            documents,
+            HashMap::new(),
+        );
+
+        let repository = HtmlOutputRepository::new();
+
+        repository
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+
+        assert!(content.contains("<h3>Local Documents</h3>"));
+        assert!(content.contains("<li>doc1.md</li>"));
+        assert!(content.contains("<li>doc2.md</li>"));
+    }
+
+    #[test]
+    fn outputs_html_contributions_by_repository() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_contributions.html");
+
+        let activity = GitHubActivity::new(100, 20, 15, 30);
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            activity,
+            vec![],
+            HashMap::new(),
+        )
+        .with_contributions(vec![
+            RepositoryContribution::new("rust-lang/rust".to_string(), 7, 2, 1, 3),
+            RepositoryContribution::new("org/<app>&co".to_string(), 1, 0, 0, 0),
+        ]);
+
+        let repository = HtmlOutputRepository::new();
+
+        repository
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+
+        assert!(content.contains("<h3>Contributions by Repository</h3>"));
+        assert!(content
+            .contains("<tr><td>rust-lang/rust</td><td>7</td><td>2</td><td>1</td><td>3</td></tr>"));
+        assert!(content.contains("<td>org/&lt;app&gt;&amp;co</td>"));
+    }
+
+    #[test]
+    fn outputs_html_pull_request_summary() {
+        use crate::domain::entities::pull_request::PullRequest;
+        use crate::domain::value_objects::pull_request_summary::PullRequestSummary;
+        use chrono::{Duration, TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_pull_requests.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let created_at = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        let pull_requests = vec![PullRequest::new(
+            42,
+            "feat: add <report>".to_string(),
+            "connect0459/<nenpo>".to_string(),
+            "connect0459".to_string(),
+            created_at,
+        )
+        .with_lifecycle(Some(created_at + Duration::hours(30)), None)
+        .with_changes(120, 30)];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(100, 20, 15, 30),
+            vec![],
+            HashMap::new(),
+        )
+        .with_pull_request_summary(PullRequestSummary::from_pull_requests(
+            &pull_requests,
+            Period::utc(from, to),
+        ));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Pull Requests</h3>"));
+        assert!(content.contains("Median Cycle Time: <span class=\"stat\">30.0 hours</span>"));
+        assert!(content
+            .contains("<li>connect0459/&lt;nenpo&gt;#42 feat: add &lt;report&gt; (+120/-30)</li>"));
+    }
+
+    #[test]
+    fn outputs_html_review_summary() {
+        use crate::domain::entities::review::Review;
+        use crate::domain::value_objects::review_state::ReviewState;
+        use crate::domain::value_objects::review_summary::ReviewSummary;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_reviews.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let reviews = vec![Review::new(
+            "connect0459/<nenpo>".to_string(),
+            42,
+            "octocat".to_string(),
+            ReviewState::ChangesRequested,
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            3,
+        )];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(100, 20, 15, 1),
+            vec![],
+            HashMap::new(),
+        )
+        .with_review_summary(ReviewSummary::from_reviews(&reviews));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Code Reviews</h3>"));
+        assert!(content.contains("Changes Requested: <span class=\"stat\">1</span>"));
+        assert!(content.contains("<h4>Pull Requests Reviewed by Author</h4>"));
+        assert!(content.contains("<li>octocat: <span class=\"stat\">1</span></li>"));
+        assert!(
+            content.contains("<li>connect0459/&lt;nenpo&gt;: <span class=\"stat\">1</span></li>")
+        );
+    }
+
+    #[test]
+    fn outputs_issue_summary() {
+        use crate::domain::entities::issue::Issue;
+        use crate::domain::value_objects::issue_state::IssueState;
+        use crate::domain::value_objects::issue_summary::IssueSummary;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_issues.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let issues = vec![Issue::new(
+            7,
+            "Crash on startup".to_string(),
+            "connect0459/nenpo".to_string(),
+            "connect0459".to_string(),
+            IssueState::Open,
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            None,
+        )
+        .with_labels(vec!["good first issue".to_string()])];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(100, 20, 1, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_issue_summary(IssueSummary::from_issues(
+            &issues,
+            Period::utc(from, to),
+            None,
+        ));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Issues</h3>"));
+        assert!(content.contains("Opened: <span class=\"stat\">1</span>"));
+        assert!(
+            content.contains("<tr><td>good first issue</td><td>1</td><td>0</td><td>0%</td></tr>")
+        );
+    }
+
+    #[test]
+    fn outputs_releases_with_commit_themes() {
+        use crate::domain::value_objects::commit_theme::CommitTheme;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_releases.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let release = Release::new(
+            "connect0459/nenpo".to_string(),
+            "Spring release".to_string(),
+            "v1.0.0".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 3, 10, 0, 0).unwrap(),
+            true,
+        )
+        .with_themes(HashMap::from([
+            (CommitTheme::Feat, 2),
+            (CommitTheme::Fix, 1),
+        ]));
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_releases(vec![release]);
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Releases</h3>"));
+        assert!(content.contains(
+            "<li>2024-05-03 connect0459/nenpo <span class=\"stat\">Spring release</span> (v1.0.0) [prerelease] — feat: 2, fix: 1</li>"
+        ));
+    }
+
+    #[test]
+    fn outputs_languages() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::entities::repository_languages::{LanguageSize, RepositoryLanguages};
+        use crate::domain::value_objects::language_share::LanguageShare;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_languages.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let repositories = vec![
+            RepositoryLanguages::new(
+                "connect0459/nenpo".to_string(),
+                vec![LanguageSize::new("Rust".to_string(), 1000)],
+            ),
+            RepositoryLanguages::new(
+                "connect0459/web".to_string(),
+                vec![LanguageSize::new("TypeScript".to_string(), 1000)],
+            ),
+        ];
+        let commits: Vec<Commit> = ["nenpo", "nenpo", "nenpo", "web"]
+            .iter()
+            .map(|repo| {
+                Commit::new(
+                    "abc123".to_string(),
+                    "feat: add feature".to_string(),
+                    "connect0459".to_string(),
+                    Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+                    format!("connect0459/{}", repo),
+                )
+            })
+            .collect();
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(4, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_languages(LanguageShare::from_commits(&repositories, &commits));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Languages</h3>"));
+        assert!(content.contains("<li>Rust: <span class=\"stat\">75.0%</span> (3.0 commits)</li>"));
+    }
+
+    #[test]
+    fn outputs_classification_summary() {
+        use crate::domain::value_objects::classification_summary::ClassificationSummary;
+        use crate::domain::value_objects::commit_classifier::CommitClassifier;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_classification.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let classifier = CommitClassifier::default().with_heuristics(true);
+        let classifications: Vec<_> = ["feat: add export", "Fix typo", "Update form", "WIP"]
+            .iter()
+            .map(|message| classifier.classify_with_method(message))
+            .collect();
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(4, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_classification_summary(ClassificationSummary::from_classifications(
+            &classifications,
+        ));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h4>Classification</h4>"));
+        assert!(content.contains("Conventional Commits: <span class=\"stat\">1</span>"));
+        assert!(content.contains("Heuristic: <span class=\"stat\">2</span> (avg. confidence 65%)"));
+        assert!(content.contains("Unclassified: <span class=\"stat\">1</span>"));
+    }
+
+    #[test]
+    fn outputs_reverts() {
+        use crate::domain::entities::commit::Commit;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_reverts.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commit = |sha: &str, message: &str, day: u32| {
+            Commit::new(
+                sha.to_string(),
+                message.to_string(),
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, day, 12, 0, 0).unwrap(),
+                "connect0459/nenpo".to_string(),
+            )
+        };
+        let commits = vec![
+            commit("aaaaaaa1111", "feat: add export", 1),
+            commit("bbbbbbb2222", "Revert \"feat: add export\"", 2),
+            commit(
+                "ccccccc3333",
+                "Undo change\n\nThis reverts commit 0123456789abcdef.",
+                3,
+            ),
+        ];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_reverts(Revert::detect(&commits));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h4>Reverts</h4>"));
+        assert!(content.contains(
+            "<li>2024-05-02 connect0459/nenpo <code>bbbbbbb</code> reverted &quot;feat: add export&quot; (<code>aaaaaaa</code>)</li>"
+        ));
+        assert!(content.contains(
+            "<li>2024-05-03 connect0459/nenpo <code>ccccccc</code> reverted <code>0123456</code></li>"
+        ));
+    }
+
+    #[test]
+    fn outputs_timeline_bar_chart() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::services::fiscal_calendar::FiscalCalendar;
+        use crate::domain::value_objects::activity_timeline::ActivityTimeline;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_timeline.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commit = |month: u32| {
+            Commit::new(
+                "abc123".to_string(),
+                "feat: add export".to_string(),
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, month, 2, 12, 0, 0).unwrap(),
+                "connect0459/nenpo".to_string(),
+            )
+        };
+        let commits = vec![commit(4), commit(4), commit(5)];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_timeline(ActivityTimeline::from_activity(
+            &commits,
+            &[],
+            &[],
+            Period::utc(from, to),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        ));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Timeline</h3>"));
+        assert!(content.contains(
+            "<tr><td>2024-04</td><td class=\"bar-cell\"><div class=\"bar\" style=\"width: 100.0%\"></div></td><td>2</td><td>0</td><td>0</td></tr>"
+        ));
+        assert!(content.contains(
+            "<tr><td>2024-05</td><td class=\"bar-cell\"><div class=\"bar\" style=\"width: 50.0%\"></div></td><td>1</td><td>0</td><td>0</td></tr>"
+        ));
+        assert!(content.contains("<h4>Weekly</h4>"));
+    }
+
+    #[test]
+    fn outputs_contribution_calendar_as_inline_svg() {
+        use crate::domain::value_objects::contribution_calendar::{
+            ContributionCalendar, DailyContribution,
+        };
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_calendar.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let counts = vec![DailyContribution::new(from, 3)];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_contribution_calendar(ContributionCalendar::from_daily_counts(
+            &counts,
+            Period::utc(from, to),
+            to,
+        ));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Contribution Calendar</h3>"));
+        assert!(content.contains("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(content.contains("<title>2024-04-01: 3 contributions</title>"));
+        assert!(content.contains("<li>Longest Streak: <span class=\"stat\">1</span> days</li>"));
+        assert!(content.contains(
+            "<li>Most Active Day: 2024-04-01 (<span class=\"stat\">3</span> contributions)</li>"
+        ));
+    }
+
+    fn compared_report() -> Report {
+        let report = |year: u32, activity: GitHubActivity, themes: Vec<(CommitTheme, u32)>| {
+            Report::new(
+                year,
+                "個人".to_string(),
+                NaiveDate::from_ymd_opt(year as i32, 4, 1).expect("Invalid date"),
+                NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).expect("Invalid date"),
+                activity,
+                vec![],
+                themes.into_iter().collect(),
+            )
+        };
+        let current = report(
+            2024,
+            GitHubActivity::new(120, 10, 0, 3),
+            vec![(CommitTheme::Feat, 2)],
+        );
+        let previous = report(
+            2023,
+            GitHubActivity::new(100, 10, 0, 4),
+            vec![(CommitTheme::Feat, 1), (CommitTheme::Fix, 1)],
+        );
+        let comparison = Comparison::between(&current, &previous);
+        current.with_comparison(comparison)
+    }
+
+    #[test]
+    fn outputs_comparison_with_indicators() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_comparison.html");
+
+        HtmlOutputRepository::new()
+            .output(&compared_report(), &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Compared with FY2023 (2023-04-01 to 2024-03-31)</h3>"));
+        assert!(content.contains(
+            "<tr><td>Total Commits</td><td>120</td><td>100</td><td class=\"up\">▲ +20 (+20.0%)</td></tr>"
+        ));
+        assert!(content.contains(
+            "<tr><td>Total Reviews</td><td>3</td><td>4</td><td class=\"down\">▼ -1 (-25.0%)</td></tr>"
+        ));
+        assert!(content.contains("<tr><th>Theme</th><th>Current</th>"));
+    }
+
+    #[test]
+    fn outputs_working_hours_histograms() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::commit_time_distribution::CommitTimeDistribution;
+        use crate::domain::value_objects::working_hours::WorkingHours;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_working_hours.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        // 10:00 on Wednesday 2024-05-01 in Tokyo
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add export".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 1, 0, 0).unwrap(),
+            "connect0459/nenpo".to_string(),
+        )];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(1, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_commit_time_distribution(CommitTimeDistribution::from_commits(
+            &commits,
+            chrono_tz::Asia::Tokyo,
+            WorkingHours::default(),
+        ));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Working Hours</h3>"));
+        assert!(content.contains("<li>Timezone: Asia/Tokyo</li>"));
+        assert!(content.contains(
+            "<li>Outside Working Hours: <span class=\"stat\">0.0%</span> (0 of 1 commits)</li>"
+        ));
+        assert!(content.contains(
+            "<tr><td>10:00</td><td class=\"bar-cell\"><div class=\"bar\" style=\"width: 100.0%\"></div></td><td>1</td></tr>"
+        ));
+        assert!(content.contains(
+            "<tr><td>Wed</td><td class=\"bar-cell\"><div class=\"bar\" style=\"width: 100.0%\"></div></td><td>1</td></tr>"
+        ));
+    }
+
+    #[test]
+    fn outputs_activity_breakdowns() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::activity_breakdown::ActivityBreakdown;
+        use crate::domain::value_objects::commit_classifier::CommitClassifier;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_breakdowns.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add export".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            "connect0459/nenpo".to_string(),
+        )
+        .with_changes(100, 10)];
+        let classifier = CommitClassifier::default();
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(1, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_breakdowns(
+            ActivityBreakdown::by_organization(&commits, &[], &classifier),
+            ActivityBreakdown::by_repository(&commits, &[], &classifier),
+        );
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h3>Activity by Organization</h3>"));
+        assert!(content.contains(
+            "<tr><td>connect0459</td><td>1</td><td>0</td><td>+100 / -10</td><td>feat: 1</td></tr>"
+        ));
+        assert!(content.contains("<h3>Activity by Repository</h3>"));
+        assert!(content.contains(
+            "<tr><td>connect0459/nenpo</td><td>1</td><td>0</td><td>+100 / -10</td><td>feat: 1</td></tr>"
+        ));
+    }
+
+    #[test]
+    fn outputs_breaking_changes_and_scopes() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::conventional_commit::{BreakingChange, ScopeCount};
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_breaking.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commits = vec![Commit::new(
+            "abcdef1234567".to_string(),
+            "feat(api)!: drop v1\n\nBREAKING CHANGE: v1 endpoints\nare removed".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            "connect0459/nenpo".to_string(),
+        )];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(1, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_breaking_changes(BreakingChange::from_commits(&commits))
+        .with_scope_summary(ScopeCount::from_commits(&commits));
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h4>Commit Scopes</h4>"));
+        assert!(content.contains("<li>api: <span class=\"stat\">1</span></li>"));
+        assert!(content.contains("<h4>Breaking Changes</h4>"));
+        assert!(content.contains(
+            "<li>2024-05-01 connect0459/nenpo (api) <code>abcdef1</code>: v1 endpoints are removed</li>"
+        ));
+    }
+
+    #[test]
+    fn outputs_excluded_commit_counts() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::commit_filter::CommitFilter;
+        use crate::domain::value_objects::repository_mirror::RepositoryMirror;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_excluded.html");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commits: Vec<Commit> = [
+            ("renovate[bot]", 1),
+            ("dependabot[bot]", 1),
+            ("github-actions[bot]", 1),
+            ("connect0459", 2),
+            ("connect0459", 2),
+        ]
+        .iter()
+        .map(|(login, parents)| {
+            Commit::new(
+                "abc123".to_string(),
+                "chore: update".to_string(),
+                login.to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+                "connect0459/nenpo".to_string(),
+            )
+            .with_author_login(Some(login.to_string()))
+            .with_parent_count(*parents)
+        })
+        .collect();
+        let (_, excluded) = CommitFilter::new(true, vec![], true)
+            .expect("Failed to create filter")
+            .apply(commits);
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(5, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_excluded_commits(excluded)
+        .with_duplicate_commits(4)
+        .with_repository_mirrors(vec![RepositoryMirror::new(
+            "test-org/app".to_string(),
+            vec!["connect0459/app".to_string(), "mirror/app".to_string()],
+            4,
+        )]);
+
+        HtmlOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("<h4>Excluded Commits</h4>"));
+        assert!(content.contains("Bot Commits: <span class=\"stat\">3</span>"));
+        assert!(content.contains("Merge Commits: <span class=\"stat\">2</span>"));
+        assert!(content.contains("Duplicate Commits: <span class=\"stat\">4</span>"));
+        assert!(content.contains(
+            "<li>test-org/app (also in connect0459/app, mirror/app): <span class=\"stat\">4</span></li>"
+        ));
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use crate::domain::entities::report::Report;
+use crate::domain::entities::trend_report::TrendReport;
 use crate::domain::repositories::output_repository::OutputRepository;
 use anyhow::Result;
 use std::path::Path;
@@ -21,12 +22,20 @@ impl OutputRepository for JsonOutputRepository {
         std::fs::write(path, json)?;
         Ok(())
     }
+
+    fn output_trend(&self, trend: &TrendReport, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(trend)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::value_objects::comparison::Comparison;
+    use crate::domain::value_objects::period::Period;
     use chrono::NaiveDate;
     use std::collections::HashMap;
     use tempfile::TempDir;
@@ -64,6 +73,33 @@ mod tests {
         assert!(content.contains("\"commits\": 100"));
     }
 
+    #[test]
+    fn outputs_trend_to_json() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("trend.json");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date"),
+            NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date"),
+            GitHubActivity::new(100, 20, 15, 30),
+            vec![],
+            HashMap::new(),
+        );
+        let trend = TrendReport::from_reports("個人".to_string(), &[report]);
+
+        JsonOutputRepository::new()
+            .output_trend(&trend, &output_path)
+            .expect("Failed to output trend");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        let json: serde_json::Value = serde_json::from_str(&content).expect("Invalid JSON");
+        assert_eq!(json["department_name"], "個人");
+        assert_eq!(json["years"][0]["year"], 2024);
+        assert_eq!(json["years"][0]["pull_requests"], 0);
+        assert_eq!(json["years"][0]["merged_pull_requests"], 0);
+    }
+
     #[test]
     fn loads_output_json() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -97,4 +133,252 @@ mod tests {
         assert_eq!(deserialized_report.department_name(), "個人");
         assert_eq!(deserialized_report.github_activity().commits(), 100);
     }
+
+    #[test]
+    fn outputs_releases_to_json() {
+        use crate::domain::entities::release::Release;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_releases.json");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_releases(vec![Release::new(
+            "connect0459/nenpo".to_string(),
+            "v1.0.0".to_string(),
+            "v1.0.0".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 3, 10, 0, 0).unwrap(),
+            false,
+        )]);
+
+        JsonOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("\"releases\""));
+        assert!(content.contains("\"tag_name\": \"v1.0.0\""));
+        assert!(content.contains("\"is_prerelease\": false"));
+    }
+
+    #[test]
+    fn outputs_languages_to_json() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::entities::repository_languages::{LanguageSize, RepositoryLanguages};
+        use crate::domain::value_objects::language_share::LanguageShare;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_languages.json");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let repositories = vec![RepositoryLanguages::new(
+            "connect0459/nenpo".to_string(),
+            vec![LanguageSize::new("Rust".to_string(), 1000)],
+        )];
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add feature".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            "connect0459/nenpo".to_string(),
+        )];
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(1, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_languages(LanguageShare::from_commits(&repositories, &commits));
+
+        JsonOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("\"languages\""));
+        assert!(content.contains("\"name\": \"Rust\""));
+        assert!(content.contains("\"percentage\": 100.0"));
+    }
+
+    #[test]
+    fn outputs_activity_breakdowns_to_json() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::activity_breakdown::ActivityBreakdown;
+        use crate::domain::value_objects::commit_classifier::CommitClassifier;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_breakdowns.json");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add feature".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            "connect0459/nenpo".to_string(),
+        )
+        .with_changes(100, 10)];
+        let classifier = CommitClassifier::default();
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(1, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_breakdowns(
+            ActivityBreakdown::by_organization(&commits, &[], &classifier),
+            ActivityBreakdown::by_repository(&commits, &[], &classifier),
+        );
+
+        JsonOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        let json: serde_json::Value = serde_json::from_str(&content).expect("Invalid JSON");
+        let repository = &json["repository_breakdown"][0];
+        assert_eq!(repository["name"], "connect0459/nenpo");
+        assert_eq!(repository["additions"], 100);
+        assert_eq!(repository["themes"]["feat"], 1);
+        assert_eq!(json["organization_breakdown"][0]["name"], "connect0459");
+    }
+
+    #[test]
+    fn outputs_timeline_to_json() {
+        use crate::domain::services::fiscal_calendar::FiscalCalendar;
+        use crate::domain::value_objects::activity_timeline::ActivityTimeline;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_timeline.json");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(0, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_timeline(ActivityTimeline::from_activity(
+            &[],
+            &[],
+            &[],
+            Period::utc(from, to),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        ));
+
+        JsonOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        let json: serde_json::Value = serde_json::from_str(&content).expect("Invalid JSON");
+        let months = json["timeline"]["monthly"]
+            .as_array()
+            .expect("monthly array");
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0]["label"], "2024-04");
+        assert_eq!(months[0]["from"], "2024-04-01");
+        assert_eq!(months[0]["to"], "2024-04-30");
+        assert_eq!(months[0]["commits"], 0);
+        assert_eq!(json["timeline"]["weekly"][0]["label"], "2024-W14");
+    }
+
+    #[test]
+    fn outputs_comparison_to_json() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_comparison.json");
+        let report = |year: u32, commits: u32| {
+            Report::new(
+                year,
+                "個人".to_string(),
+                NaiveDate::from_ymd_opt(year as i32, 4, 1).expect("Invalid date"),
+                NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).expect("Invalid date"),
+                GitHubActivity::new(commits, 0, 0, 0),
+                vec![],
+                HashMap::new(),
+            )
+        };
+        let current = report(2024, 150);
+        let comparison = Comparison::between(&current, &report(2023, 100));
+
+        JsonOutputRepository::new()
+            .output(&current.with_comparison(comparison), &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        let json: serde_json::Value = serde_json::from_str(&content).expect("Invalid JSON");
+        let commits = &json["comparison"]["metrics"][0];
+        assert_eq!(json["comparison"]["previous_label"], "FY2023");
+        assert_eq!(commits["label"], "Total Commits");
+        assert_eq!(commits["change"], 50);
+        assert_eq!(commits["percent_change"], 50.0);
+        assert!(json["comparison"]["metrics"][2]["percent_change"].is_null());
+    }
+
+    #[test]
+    fn outputs_contribution_calendar_to_json() {
+        use crate::domain::value_objects::contribution_calendar::{
+            ContributionCalendar, DailyContribution,
+        };
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_calendar.json");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_contribution_calendar(ContributionCalendar::from_daily_counts(
+            &[DailyContribution::new(from, 3)],
+            Period::utc(from, to),
+            to,
+        ));
+
+        JsonOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        let json: serde_json::Value = serde_json::from_str(&content).expect("Invalid JSON");
+        let calendar = &json["contribution_calendar"];
+        assert_eq!(calendar["days"]["2024-04-01"], 3);
+        assert_eq!(calendar["days"]["2024-04-02"], 0);
+        assert_eq!(calendar["longest_streak"], 1);
+        assert_eq!(calendar["most_active_day"]["date"], "2024-04-01");
+
+        let loaded: Report = serde_json::from_str(&content).expect("Failed to load report");
+        assert_eq!(loaded.contribution_calendar().total(), 3);
+    }
 }
@@ -1,7 +1,15 @@
+use crate::domain::entities::release::Release;
 use crate::domain::entities::report::Report;
+use crate::domain::entities::trend_report::TrendReport;
 use crate::domain::repositories::output_repository::OutputRepository;
+use crate::domain::value_objects::commit_theme::CommitTheme;
+use crate::domain::value_objects::commit_time_distribution::WEEKDAY_LABELS;
+use crate::domain::value_objects::period_kind::PeriodKind;
+use crate::domain::value_objects::revert::Revert;
+use crate::infrastructure::output::heatmap_svg::render_heatmap_svg;
 use anyhow::Result;
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Markdown output repository
 #[allow(dead_code)] // Temporarily allowed during TDD implementation
@@ -21,7 +29,7 @@ impl OutputRepository for MarkdownOutputRepository {
         let your_commits_count: usize = report.theme_summary().values().map(|&v| v as usize).sum();
 
         let mut content = format!(
-            r#"# Annual Report {}
+            r#"# {}
 
 ## {}
 
@@ -29,6 +37,7 @@ impl OutputRepository for MarkdownOutputRepository {
 
 - From: {}
 - To: {}
+- Timezone: {}
 
 ### Organization Activity Summary
 
@@ -41,10 +50,11 @@ impl OutputRepository for MarkdownOutputRepository {
 
 - Your Commits: {}
 "#,
-            report.year(),
+            report_title(report),
             report.department_name(),
             report.period_from(),
             report.period_to(),
+            report.timezone(),
             report.github_activity().commits(),
             report.github_activity().pull_requests(),
             report.github_activity().issues(),
@@ -56,10 +66,390 @@ impl OutputRepository for MarkdownOutputRepository {
         if !report.theme_summary().is_empty() {
             content.push_str("\n#### Commit Themes\n\n");
             let mut themes: Vec<_> = report.theme_summary().iter().collect();
-            themes.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+            // Sort by count descending, then by theme order
+            themes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.sort_order().cmp(&b.0.sort_order())));
 
             for (theme, count) in themes {
-                content.push_str(&format!("- {}: {}\n", theme.short_name(), count));
+                content.push_str(&format!("- {}: {}\n", theme.label(), count));
+            }
+        }
+
+        // Classification (only show if any commit was classified)
+        let classification = report.classification_summary();
+        if classification.total() > 0 {
+            content.push_str("\n#### Classification\n\n");
+            content.push_str(&format!(
+                "- Conventional Commits: {}\n",
+                classification.convention()
+            ));
+            content.push_str(&format!("- Custom Rules: {}\n", classification.rule()));
+            content.push_str(&format!("- Gitmoji: {}\n", classification.gitmoji()));
+            content.push_str(&format!(
+                "- Heuristic: {}{}\n",
+                classification.heuristic(),
+                confidence_suffix(classification.heuristic_confidence())
+            ));
+            content.push_str(&format!(
+                "- Unclassified: {}\n",
+                classification.unclassified()
+            ));
+        }
+
+        // Commit Scopes (only show if commits used Conventional Commits scopes)
+        if !report.scope_summary().is_empty() {
+            content.push_str("\n#### Commit Scopes\n\n");
+            for scope in report.scope_summary() {
+                content.push_str(&format!("- {}: {}\n", scope.scope(), scope.count()));
+            }
+        }
+
+        // Breaking Changes (only show if any commit was marked as breaking)
+        if !report.breaking_changes().is_empty() {
+            content.push_str("\n#### Breaking Changes\n\n");
+            for change in report.breaking_changes() {
+                let scope = change
+                    .scope()
+                    .map(|scope| format!(" ({})", scope))
+                    .unwrap_or_default();
+                content.push_str(&format!(
+                    "- {} {}{} `{}`: {}\n",
+                    report.local_date(change.committed_date()),
+                    change.repository(),
+                    scope,
+                    change.short_sha(),
+                    flatten_note(change.note())
+                ));
+            }
+        }
+
+        // Reverts (only show if any commit was reverted)
+        if !report.reverts().is_empty() {
+            content.push_str("\n#### Reverts\n\n");
+            for revert in report.reverts() {
+                content.push_str(&format!(
+                    "- {} {} `{}` reverted {}\n",
+                    report.local_date(revert.committed_date()),
+                    revert.repository(),
+                    revert.short_sha(),
+                    describe_reverted(revert)
+                ));
+            }
+        }
+
+        // Excluded Commits (only show if filtering or deduplication dropped anything)
+        let excluded = report.excluded_commits();
+        if excluded.total() > 0 || report.duplicate_commits() > 0 {
+            content.push_str("\n#### Excluded Commits\n\n");
+            content.push_str(&format!("- Bot Commits: {}\n", excluded.bots()));
+            content.push_str(&format!("- Merge Commits: {}\n", excluded.merges()));
+            content.push_str(&format!("- Reverted Commits: {}\n", excluded.reverts()));
+            content.push_str(&format!(
+                "- Duplicate Commits: {}\n",
+                report.duplicate_commits()
+            ));
+            for mirror in report.repository_mirrors() {
+                content.push_str(&format!(
+                    "  - {} (also in {}): {}\n",
+                    mirror.repository(),
+                    mirror.mirrors().join(", "),
+                    mirror.commits()
+                ));
+            }
+        }
+
+        // Languages (only show if the user's commits could be attributed to languages)
+        if !report.languages().is_empty() {
+            content.push_str("\n### Languages\n\n");
+            for language in report.languages() {
+                content.push_str(&format!(
+                    "- {}: {:.1}% ({:.1} commits)\n",
+                    language.name(),
+                    language.percentage(),
+                    language.weighted_commits()
+                ));
+            }
+        }
+
+        // Pull Requests (only show if there is pull request activity)
+        if let Some(summary) = report
+            .pull_request_summary()
+            .filter(|s| s.authored() > 0 || s.merged() > 0)
+        {
+            content.push_str("\n### Pull Requests\n\n");
+            content.push_str(&format!("- Authored: {}\n", summary.authored()));
+            content.push_str(&format!("- Merged: {}\n", summary.merged()));
+            content.push_str(&format!(
+                "- Median Cycle Time: {}\n",
+                format_hours(summary.median_cycle_time_hours())
+            ));
+            content.push_str(&format!(
+                "- P90 Cycle Time: {}\n",
+                format_hours(summary.p90_cycle_time_hours())
+            ));
+            content.push_str(&format!(
+                "- Median Time to First Review: {}\n",
+                format_hours(summary.median_time_to_first_review_hours())
+            ));
+
+            if !summary.largest().is_empty() {
+                content.push_str("\n#### Largest Pull Requests\n\n");
+                for pr in summary.largest() {
+                    content.push_str(&format!(
+                        "- {}#{} {} (+{}/-{})\n",
+                        pr.repository(),
+                        pr.number(),
+                        pr.title(),
+                        pr.additions(),
+                        pr.deletions()
+                    ));
+                }
+            }
+        }
+
+        // Code Reviews (only show if the user submitted reviews)
+        if let Some(summary) = report.review_summary().filter(|s| s.total() > 0) {
+            content.push_str("\n### Code Reviews\n\n");
+            content.push_str(&format!("- Reviews Given: {}\n", summary.total()));
+            content.push_str(&format!("- Approved: {}\n", summary.approved()));
+            content.push_str(&format!(
+                "- Changes Requested: {}\n",
+                summary.changes_requested()
+            ));
+            content.push_str(&format!("- Commented: {}\n", summary.commented()));
+            content.push_str(&format!("- Review Comments: {}\n", summary.comments()));
+            content.push_str(&format!(
+                "- Pull Requests Reviewed: {}\n",
+                summary.pull_requests_reviewed()
+            ));
+            content.push_str(&format!(
+                "- Median Review Turnaround: {}\n",
+                format_hours(summary.median_turnaround_hours())
+            ));
+
+            content.push_str("\n#### Pull Requests Reviewed by Repository\n\n");
+            for count in summary.by_repository() {
+                content.push_str(&format!("- {}: {}\n", count.name(), count.pull_requests()));
+            }
+
+            content.push_str("\n#### Pull Requests Reviewed by Author\n\n");
+            for count in summary.by_author() {
+                content.push_str(&format!("- {}: {}\n", count.name(), count.pull_requests()));
+            }
+        }
+
+        // Issues (only show if the user was involved in issues)
+        if let Some(summary) = report.issue_summary().filter(|s| s.involved() > 0) {
+            content.push_str("\n### Issues\n\n");
+            content.push_str(&format!("- Opened: {}\n", summary.opened()));
+            content.push_str(&format!("- Closed: {}\n", summary.closed()));
+            content.push_str(&format!("- Commented: {}\n", summary.commented()));
+            content.push_str(&format!(
+                "- Median Time to Close: {}\n",
+                format_hours(summary.median_time_to_close_hours())
+            ));
+
+            for (title, column, groups) in [
+                ("Issues by Label", "Label", summary.by_label()),
+                (
+                    "Issues by Repository",
+                    "Repository",
+                    summary.by_repository(),
+                ),
+            ] {
+                content.push_str(&format!("\n#### {}\n\n", title));
+                content.push_str(&format!("| {} | Open | Closed | Closed Ratio |\n", column));
+                content.push_str("| :--- | ---: | ---: | ---: |\n");
+                for group in groups {
+                    content.push_str(&format!(
+                        "| {} | {} | {} | {:.0}% |\n",
+                        group.name(),
+                        group.open(),
+                        group.closed(),
+                        group.closed_ratio() * 100.0
+                    ));
+                }
+            }
+        }
+
+        // Releases (only show if anything was shipped)
+        if !report.releases().is_empty() {
+            content.push_str("\n### Releases\n\n");
+            for release in report.releases() {
+                content.push_str(&format!(
+                    "- {} {} {}{}",
+                    report.local_date(release.published_at()),
+                    release.repository(),
+                    release.name(),
+                    release_suffix(release)
+                ));
+                if !release.themes().is_empty() {
+                    content.push_str(&format!(" — {}", format_themes(release.themes())));
+                }
+                content.push('\n');
+            }
+        }
+
+        // Timeline (only show if the report covers a period)
+        if !report.timeline().is_empty() {
+            content.push_str("\n### Timeline\n");
+            for (title, column, buckets) in [
+                ("Monthly", "Month", report.timeline().monthly()),
+                ("Weekly", "Week", report.timeline().weekly()),
+            ] {
+                content.push_str(&format!("\n#### {}\n\n", title));
+                content.push_str(&format!(
+                    "| {} | Commits | Pull Requests | Issues |\n",
+                    column
+                ));
+                content.push_str("| :--- | ---: | ---: | ---: |\n");
+                for bucket in buckets {
+                    content.push_str(&format!(
+                        "| {} | {} | {} | {} |\n",
+                        bucket.label(),
+                        bucket.commits(),
+                        bucket.pull_requests(),
+                        bucket.issues(),
+                    ));
+                }
+            }
+        }
+
+        // Contribution Calendar (only show if the report covers a period)
+        let calendar = report.contribution_calendar();
+        if !calendar.is_empty() {
+            // Markdown cannot inline SVG reliably, so the heatmap is written next to the report;
+            // the angle brackets let the link hold file names with spaces
+            let heatmap_path = heatmap_path(path);
+            std::fs::write(&heatmap_path, render_heatmap_svg(calendar))?;
+            content.push_str("\n### Contribution Calendar\n\n");
+            content.push_str(&format!(
+                "![Contribution calendar](<{}>)\n\n",
+                heatmap_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+            ));
+            content.push_str(&format!("- Total Contributions: {}\n", calendar.total()));
+            content.push_str(&format!(
+                "- Longest Streak: {} days\n",
+                calendar.longest_streak()
+            ));
+            content.push_str(&format!(
+                "- Current Streak: {} days\n",
+                calendar.current_streak()
+            ));
+            if let Some(day) = calendar.most_active_day() {
+                content.push_str(&format!(
+                    "- Most Active Day: {} ({} contributions)\n",
+                    day.date(),
+                    day.count()
+                ));
+            }
+        }
+
+        // Working Hours (only show if there are commits to place in time)
+        let distribution = report.commit_time_distribution();
+        if let Some(percentage) = distribution.outside_working_hours_percentage() {
+            let working_hours = distribution.working_hours();
+            content.push_str("\n### Working Hours\n\n");
+            content.push_str(&format!("- Timezone: {}\n", distribution.timezone()));
+            content.push_str(&format!(
+                "- Working Hours: {:02}:00-{:02}:00, Mon-Fri\n",
+                working_hours.start_hour(),
+                working_hours.end_hour()
+            ));
+            content.push_str(&format!(
+                "- Outside Working Hours: {:.1}% ({} of {} commits)\n",
+                percentage,
+                distribution.outside_working_hours(),
+                distribution.total()
+            ));
+
+            content.push_str("\n#### Commits by Hour\n\n");
+            content.push_str("| Hour | Commits |\n");
+            content.push_str("| :--- | ---: |\n");
+            for (hour, count) in distribution.by_hour().iter().enumerate() {
+                content.push_str(&format!("| {:02}:00 | {} |\n", hour, count));
+            }
+
+            content.push_str("\n#### Commits by Weekday\n\n");
+            content.push_str("| Weekday | Commits |\n");
+            content.push_str("| :--- | ---: |\n");
+            for (weekday, count) in WEEKDAY_LABELS.iter().zip(distribution.by_weekday()) {
+                content.push_str(&format!("| {} | {} |\n", weekday, count));
+            }
+        }
+
+        // Activity by Organization / Repository (only show if there is any activity)
+        for (title, breakdown) in [
+            ("Activity by Organization", report.organization_breakdown()),
+            ("Activity by Repository", report.repository_breakdown()),
+        ] {
+            if breakdown.is_empty() {
+                continue;
+            }
+            content.push_str(&format!("\n### {}\n\n", title));
+            content.push_str("| Name | Commits | Pull Requests | Churn | Themes |\n");
+            content.push_str("| :--- | ---: | ---: | ---: | :--- |\n");
+            for entry in breakdown {
+                content.push_str(&format!(
+                    "| {} | {} | {} | +{} / -{} | {} |\n",
+                    entry.name(),
+                    entry.commits(),
+                    entry.pull_requests(),
+                    entry.additions(),
+                    entry.deletions(),
+                    format_themes(entry.themes()),
+                ));
+            }
+        }
+
+        // Contributions by Repository (only show in contributions source mode)
+        if !report.contributions().is_empty() {
+            content.push_str("\n### Contributions by Repository\n\n");
+            content.push_str("| Repository | Commits | Pull Requests | Issues | Reviews |\n");
+            content.push_str("| :--- | ---: | ---: | ---: | ---: |\n");
+            for contribution in report.contributions() {
+                content.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    contribution.repository(),
+                    contribution.commits(),
+                    contribution.pull_requests(),
+                    contribution.issues(),
+                    contribution.reviews(),
+                ));
+            }
+        }
+
+        // Comparison with the previous fiscal year (only show if requested)
+        if let Some(comparison) = report.comparison() {
+            content.push_str(&format!(
+                "\n### Compared with {} ({} to {})\n\n",
+                comparison.previous_label(),
+                comparison.previous_from(),
+                comparison.previous_to()
+            ));
+            for (column, deltas) in [
+                ("Metric", comparison.metrics()),
+                ("Theme", comparison.themes()),
+            ] {
+                if deltas.is_empty() {
+                    continue;
+                }
+                content.push_str(&format!(
+                    "| {} | Current | Previous | Change |\n| :--- | ---: | ---: | ---: |\n",
+                    column
+                ));
+                for delta in deltas {
+                    content.push_str(&format!(
+                        "| {} | {} | {} | {} |\n",
+                        delta.label(),
+                        delta.current(),
+                        delta.previous(),
+                        delta.format_change()
+                    ));
+                }
+                content.push('\n');
             }
         }
 
@@ -74,6 +464,133 @@ impl OutputRepository for MarkdownOutputRepository {
         std::fs::write(path, content)?;
         Ok(())
     }
+
+    fn output_trend(&self, trend: &TrendReport, path: &Path) -> Result<()> {
+        let mut content = format!(
+            "# {}\n\n## {}\n\n### Year by Year\n\n",
+            trend_title(trend),
+            trend.department_name()
+        );
+        content.push_str(
+            "| Fiscal Year | Period | Commits | Pull Requests | Merged | Reviews | Churn |\n",
+        );
+        content.push_str("| :--- | :--- | ---: | ---: | ---: | ---: | ---: |\n");
+        for year in trend.years() {
+            content.push_str(&format!(
+                "| FY{} | {} to {} | {} | {} | {} | {} | +{} / -{} |\n",
+                year.year(),
+                year.period_from(),
+                year.period_to(),
+                year.commits(),
+                year.pull_requests(),
+                year.merged_pull_requests(),
+                year.reviews(),
+                year.additions(),
+                year.deletions(),
+            ));
+        }
+
+        // Commit Themes (only show if any year has commits)
+        let themes = trend.themes();
+        if !themes.is_empty() {
+            content.push_str("\n### Commit Themes\n\n| Fiscal Year |");
+            for theme in &themes {
+                content.push_str(&format!(" {} |", theme.label()));
+            }
+            content.push_str("\n| :--- |");
+            content.push_str(&" ---: |".repeat(themes.len()));
+            content.push('\n');
+            for year in trend.years() {
+                content.push_str(&format!("| FY{} |", year.year()));
+                for theme in &themes {
+                    let count = year.themes().get(theme).copied().unwrap_or(0);
+                    content.push_str(&format!(" {} |", count));
+                }
+                content.push('\n');
+            }
+        }
+
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+/// Returns the trend report title (e.g., "Trend Report FY2021-FY2025")
+fn trend_title(trend: &TrendReport) -> String {
+    match (trend.first_year(), trend.last_year()) {
+        (Some(from), Some(to)) => format!("Trend Report FY{}-FY{}", from, to),
+        _ => "Trend Report".to_string(),
+    }
+}
+
+/// Returns the report title: "Annual Report 2024" for a fiscal year, "Report FY2024-Q3" otherwise
+fn report_title(report: &Report) -> String {
+    match report.period_kind() {
+        PeriodKind::FiscalYear => format!("Annual Report {}", report.year()),
+        _ => format!("Report {}", report.period_label()),
+    }
+}
+
+/// Formats a duration in hours for display
+fn format_hours(hours: Option<f64>) -> String {
+    hours
+        .map(|h| format!("{:.1} hours", h))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Formats the tag (when it differs from the name) and prerelease marker of a release
+fn release_suffix(release: &Release) -> String {
+    let mut suffix = String::new();
+    if release.name() != release.tag_name() {
+        suffix.push_str(&format!(" ({})", release.tag_name()));
+    }
+    if release.is_prerelease() {
+        suffix.push_str(" [prerelease]");
+    }
+    suffix
+}
+
+/// Returns the path of the heatmap SVG written next to the report (e.g., "report-x-2024-heatmap.svg")
+fn heatmap_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}-heatmap.svg", stem))
+}
+
+/// Formats commit theme counts, largest first (e.g., "feat: 3, fix: 1")
+fn format_themes(themes: &HashMap<CommitTheme, u32>) -> String {
+    let mut themes: Vec<_> = themes.iter().collect();
+    themes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.sort_order().cmp(&b.0.sort_order())));
+    themes
+        .iter()
+        .map(|(theme, count)| format!("{}: {}", theme.label(), count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Joins a multi-line breaking change note into a single line
+fn flatten_note(note: &str) -> String {
+    note.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Describes the target of a revert (e.g., "\"feat: add export\" (`abc1234`)")
+fn describe_reverted(revert: &Revert) -> String {
+    let subject = revert
+        .reverted_subject()
+        .map(|subject| format!("\"{}\"", subject));
+    let sha = revert.reverted_short_sha().map(|sha| format!("`{}`", sha));
+    match (subject, sha) {
+        (Some(subject), Some(sha)) => format!("{} ({})", subject, sha),
+        (Some(subject), None) => subject,
+        (None, Some(sha)) => sha,
+        (None, None) => "an unknown commit".to_string(),
+    }
+}
+
+/// Formats the mean confidence of heuristic classifications (e.g., " (avg. confidence 65%)")
+fn confidence_suffix(confidence: Option<f64>) -> String {
+    confidence
+        .map(|c| format!(" (avg. confidence {:.0}%)", c * 100.0))
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -81,6 +598,9 @@ mod tests {
     use super::*;
     use crate::domain::entities::document_content::DocumentContent;
     use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::entities::repository_contribution::RepositoryContribution;
+    use crate::domain::value_objects::comparison::Comparison;
+    use crate::domain::value_objects::period::Period;
     use chrono::NaiveDate;
     use std::collections::HashMap;
     use tempfile::TempDir;
@@ -118,6 +638,47 @@ mod tests {
         assert!(content.contains("Your Commits: 0")); // No theme summary, so 0
     }
 
+    fn trend() -> TrendReport {
+        let report = |year: u32, themes: Vec<(CommitTheme, u32)>| {
+            Report::new(
+                year,
+                "個人".to_string(),
+                NaiveDate::from_ymd_opt(year as i32, 4, 1).expect("Invalid date"),
+                NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).expect("Invalid date"),
+                GitHubActivity::new(100, 20, 15, 30),
+                vec![],
+                themes.into_iter().collect(),
+            )
+        };
+        TrendReport::from_reports(
+            "個人".to_string(),
+            &[
+                report(2023, vec![(CommitTheme::Feat, 3)]),
+                report(2024, vec![(CommitTheme::Feat, 1), (CommitTheme::Fix, 2)]),
+            ],
+        )
+    }
+
+    #[test]
+    fn outputs_trend_tables_by_fiscal_year() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("trend.md");
+
+        MarkdownOutputRepository::new()
+            .output_trend(&trend(), &output_path)
+            .expect("Failed to output trend");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("# Trend Report FY2023-FY2024"));
+        assert!(content.contains("## 個人"));
+        assert!(
+            content.contains("| FY2023 | 2023-04-01 to 2024-03-31 | 3 | 0 | 0 | 30 | +0 / -0 |")
+        );
+        assert!(content.contains("| Fiscal Year | feat | fix |"));
+        assert!(content.contains("| FY2023 | 3 | 0 |"));
+        assert!(content.contains("| FY2024 | 1 | 2 |"));
+    }
+
     #[test]
     fn outputs_report_with_documents() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -155,6 +716,848 @@ mod tests {
         assert!(content.contains("- doc2.md"));
     }
 
+    #[test]
+    fn outputs_contributions_by_repository() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_contributions.md");
+
+        let activity = GitHubActivity::new(100, 20, 15, 30);
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            activity,
+            vec![],
+            HashMap::new(),
+        )
+        .with_contributions(vec![RepositoryContribution::new(
+            "rust-lang/rust".to_string(),
+            7,
+            2,
+            1,
+            3,
+        )]);
+
+        let repository = MarkdownOutputRepository::new();
+
+        repository
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Contributions by Repository"));
+        assert!(content.contains("| rust-lang/rust | 7 | 2 | 1 | 3 |"));
+    }
+
+    #[test]
+    fn outputs_pull_request_summary() {
+        use crate::domain::entities::pull_request::PullRequest;
+        use crate::domain::value_objects::pull_request_summary::PullRequestSummary;
+        use chrono::{Duration, TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_pull_requests.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let created_at = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        let pull_requests = vec![PullRequest::new(
+            42,
+            "feat: add report".to_string(),
+            "connect0459/nenpo".to_string(),
+            "connect0459".to_string(),
+            created_at,
+        )
+        .with_lifecycle(Some(created_at + Duration::hours(30)), None)
+        .with_changes(120, 30)];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(100, 20, 15, 30),
+            vec![],
+            HashMap::new(),
+        )
+        .with_pull_request_summary(PullRequestSummary::from_pull_requests(
+            &pull_requests,
+            Period::utc(from, to),
+        ));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Pull Requests"));
+        assert!(content.contains("- Merged: 1"));
+        assert!(content.contains("- Median Cycle Time: 30.0 hours"));
+        assert!(content.contains("- Median Time to First Review: -"));
+        assert!(content.contains("- connect0459/nenpo#42 feat: add report (+120/-30)"));
+    }
+
+    #[test]
+    fn outputs_review_summary() {
+        use crate::domain::entities::review::Review;
+        use crate::domain::value_objects::review_state::ReviewState;
+        use crate::domain::value_objects::review_summary::ReviewSummary;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_reviews.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let reviews = vec![Review::new(
+            "connect0459/nenpo".to_string(),
+            42,
+            "octocat".to_string(),
+            ReviewState::Approved,
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            3,
+        )];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(100, 20, 15, 1),
+            vec![],
+            HashMap::new(),
+        )
+        .with_review_summary(ReviewSummary::from_reviews(&reviews));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Code Reviews"));
+        assert!(content.contains("- Approved: 1"));
+        assert!(content.contains("- Review Comments: 3"));
+        assert!(content.contains("- connect0459/nenpo: 1"));
+        assert!(content.contains("- octocat: 1"));
+    }
+
+    #[test]
+    fn outputs_issue_summary() {
+        use crate::domain::entities::issue::Issue;
+        use crate::domain::value_objects::issue_state::IssueState;
+        use crate::domain::value_objects::issue_summary::IssueSummary;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_issues.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let issues = vec![Issue::new(
+            7,
+            "Crash on startup".to_string(),
+            "connect0459/nenpo".to_string(),
+            "connect0459".to_string(),
+            IssueState::Closed,
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap()),
+        )
+        .with_labels(vec!["bug".to_string()])];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(100, 20, 1, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_issue_summary(IssueSummary::from_issues(
+            &issues,
+            Period::utc(from, to),
+            None,
+        ));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Issues"));
+        assert!(content.contains("- Opened: 1"));
+        assert!(content.contains("- Median Time to Close: 12.0 hours"));
+        assert!(content.contains("| bug | 0 | 1 | 100% |"));
+        assert!(content.contains("| connect0459/nenpo | 0 | 1 | 100% |"));
+    }
+
+    #[test]
+    fn outputs_releases_with_commit_themes() {
+        use crate::domain::value_objects::commit_theme::CommitTheme;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_releases.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let release = Release::new(
+            "connect0459/nenpo".to_string(),
+            "Spring release".to_string(),
+            "v1.0.0".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 3, 10, 0, 0).unwrap(),
+            true,
+        )
+        .with_themes(HashMap::from([
+            (CommitTheme::Feat, 2),
+            (CommitTheme::Fix, 1),
+        ]));
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_releases(vec![release]);
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Releases"));
+        assert!(content.contains(
+            "- 2024-05-03 connect0459/nenpo Spring release (v1.0.0) [prerelease] — feat: 2, fix: 1"
+        ));
+    }
+
+    fn compared_report() -> Report {
+        let report = |year: u32, activity: GitHubActivity, themes: Vec<(CommitTheme, u32)>| {
+            Report::new(
+                year,
+                "個人".to_string(),
+                NaiveDate::from_ymd_opt(year as i32, 4, 1).expect("Invalid date"),
+                NaiveDate::from_ymd_opt(year as i32 + 1, 3, 31).expect("Invalid date"),
+                activity,
+                vec![],
+                themes.into_iter().collect(),
+            )
+        };
+        let current = report(
+            2024,
+            GitHubActivity::new(120, 10, 0, 3),
+            vec![(CommitTheme::Feat, 2)],
+        );
+        let previous = report(
+            2023,
+            GitHubActivity::new(100, 10, 0, 4),
+            vec![(CommitTheme::Feat, 1), (CommitTheme::Fix, 1)],
+        );
+        let comparison = Comparison::between(&current, &previous);
+        current.with_comparison(comparison)
+    }
+
+    #[test]
+    fn outputs_comparison_with_previous_year() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_comparison.md");
+
+        MarkdownOutputRepository::new()
+            .output(&compared_report(), &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Compared with FY2023 (2023-04-01 to 2024-03-31)"));
+        assert!(content.contains("| Total Commits | 120 | 100 | ▲ +20 (+20.0%) |"));
+        assert!(content.contains("| Total Reviews | 3 | 4 | ▼ -1 (-25.0%) |"));
+        assert!(content.contains("| Pull Requests Authored | 0 | 0 | ±0 |"));
+        assert!(content.contains("| Total Issues | 0 | 0 | ±0 |"));
+        assert!(content.contains("| feat | 2 | 1 | ▲ +1 (+100.0%) |"));
+        assert!(content.contains("| fix | 0 | 1 | ▼ -1 (-100.0%) |"));
+    }
+
+    #[test]
+    fn titles_report_with_period_label() {
+        use crate::domain::value_objects::period_kind::PeriodKind;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_quarter.md");
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            NaiveDate::from_ymd_opt(2024, 10, 1).expect("Invalid date"),
+            NaiveDate::from_ymd_opt(2024, 12, 31).expect("Invalid date"),
+            GitHubActivity::new(0, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_period(PeriodKind::Quarter { quarter: 3 }, "FY2024-Q3".to_string());
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.starts_with("# Report FY2024-Q3\n"));
+    }
+
+    #[test]
+    fn outputs_dates_in_report_timezone() {
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_timezone.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        // 2024-05-02 20:00 UTC is already May 3rd in Tokyo
+        let release = Release::new(
+            "connect0459/nenpo".to_string(),
+            "Spring release".to_string(),
+            "v1.0.0".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 2, 20, 0, 0).unwrap(),
+            false,
+        );
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(0, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_releases(vec![release])
+        .with_timezone(chrono_tz::Asia::Tokyo);
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("- To: 2025-03-31\n- Timezone: Asia/Tokyo"));
+        assert!(content.contains("- 2024-05-03 connect0459/nenpo Spring release (v1.0.0)"));
+    }
+
+    #[test]
+    fn outputs_languages() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::entities::repository_languages::{LanguageSize, RepositoryLanguages};
+        use crate::domain::value_objects::language_share::LanguageShare;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_languages.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let repositories = vec![
+            RepositoryLanguages::new(
+                "connect0459/nenpo".to_string(),
+                vec![LanguageSize::new("Rust".to_string(), 1000)],
+            ),
+            RepositoryLanguages::new(
+                "connect0459/web".to_string(),
+                vec![LanguageSize::new("TypeScript".to_string(), 1000)],
+            ),
+        ];
+        let commits: Vec<Commit> = ["nenpo", "nenpo", "nenpo", "web"]
+            .iter()
+            .map(|repo| {
+                Commit::new(
+                    "abc123".to_string(),
+                    "feat: add feature".to_string(),
+                    "connect0459".to_string(),
+                    Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+                    format!("connect0459/{}", repo),
+                )
+            })
+            .collect();
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(4, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_languages(LanguageShare::from_commits(&repositories, &commits));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Languages"));
+        assert!(content.contains("- Rust: 75.0% (3.0 commits)"));
+        assert!(content.contains("- TypeScript: 25.0% (1.0 commits)"));
+    }
+
+    #[test]
+    fn outputs_custom_theme_display_names() {
+        use crate::domain::value_objects::commit_theme::CustomTheme;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_custom_themes.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let mut theme_summary = HashMap::new();
+        theme_summary.insert(
+            CommitTheme::Custom(CustomTheme::new(
+                "ticket".to_string(),
+                "Ticket Work".to_string(),
+                0,
+            )),
+            2,
+        );
+        theme_summary.insert(CommitTheme::Fix, 2);
+        theme_summary.insert(CommitTheme::Feat, 5);
+
+        let report = Report::new(
+            2024,
+            "Corporate".to_string(),
+            from,
+            to,
+            GitHubActivity::new(9, 0, 0, 0),
+            vec![],
+            theme_summary,
+        );
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("- feat: 5\n- fix: 2\n- Ticket Work: 2\n"));
+    }
+
+    #[test]
+    fn outputs_classification_summary() {
+        use crate::domain::value_objects::classification_summary::ClassificationSummary;
+        use crate::domain::value_objects::commit_classifier::CommitClassifier;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_classification.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let classifier = CommitClassifier::default().with_heuristics(true);
+        let classifications: Vec<_> = ["feat: add export", "Fix typo", "Update form", "WIP"]
+            .iter()
+            .map(|message| classifier.classify_with_method(message))
+            .collect();
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(4, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_classification_summary(ClassificationSummary::from_classifications(
+            &classifications,
+        ));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("#### Classification"));
+        assert!(content.contains("- Conventional Commits: 1\n"));
+        assert!(content.contains("- Heuristic: 2 (avg. confidence 65%)\n"));
+        assert!(content.contains("- Unclassified: 1\n"));
+    }
+
+    #[test]
+    fn outputs_reverts() {
+        use crate::domain::entities::commit::Commit;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_reverts.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commit = |sha: &str, message: &str, day: u32| {
+            Commit::new(
+                sha.to_string(),
+                message.to_string(),
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, day, 12, 0, 0).unwrap(),
+                "connect0459/nenpo".to_string(),
+            )
+        };
+        let commits = vec![
+            commit("aaaaaaa1111", "feat: add export", 1),
+            commit("bbbbbbb2222", "Revert \"feat: add export\"", 2),
+            commit(
+                "ccccccc3333",
+                "Undo change\n\nThis reverts commit 0123456789abcdef.",
+                3,
+            ),
+        ];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_reverts(Revert::detect(&commits));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("#### Reverts"));
+        assert!(content.contains(
+            "- 2024-05-02 connect0459/nenpo `bbbbbbb` reverted \"feat: add export\" (`aaaaaaa`)"
+        ));
+        assert!(content.contains("- 2024-05-03 connect0459/nenpo `ccccccc` reverted `0123456`"));
+    }
+
+    #[test]
+    fn outputs_timeline() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::services::fiscal_calendar::FiscalCalendar;
+        use crate::domain::value_objects::activity_timeline::ActivityTimeline;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_timeline.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commits = vec![Commit::new(
+            "abc123".to_string(),
+            "feat: add export".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 4, 2, 12, 0, 0).unwrap(),
+            "connect0459/nenpo".to_string(),
+        )];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(1, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_timeline(ActivityTimeline::from_activity(
+            &commits,
+            &[],
+            &[],
+            Period::utc(from, to),
+            &FiscalCalendar::new(4, 1).unwrap(),
+            None,
+        ));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Timeline"));
+        assert!(content.contains("| Month | Commits | Pull Requests | Issues |"));
+        assert!(content.contains("| 2024-04 | 1 | 0 | 0 |"));
+        assert!(content.contains("| 2025-03 | 0 | 0 | 0 |"));
+        assert!(content.contains("| Week | Commits | Pull Requests | Issues |"));
+        assert!(content.contains("| 2024-W14 | 1 | 0 | 0 |"));
+    }
+
+    #[test]
+    fn outputs_contribution_calendar_with_heatmap_file() {
+        use crate::domain::value_objects::contribution_calendar::{
+            ContributionCalendar, DailyContribution,
+        };
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("report-Personal Projects-2024.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let day = |d: u32| NaiveDate::from_ymd_opt(2024, 4, d).expect("Invalid date");
+        let counts = vec![
+            DailyContribution::new(day(1), 2),
+            DailyContribution::new(day(2), 5),
+            DailyContribution::new(day(3), 1),
+        ];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(8, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_contribution_calendar(ContributionCalendar::from_daily_counts(
+            &counts,
+            Period::utc(from, to),
+            to,
+        ));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Contribution Calendar"));
+        assert!(content
+            .contains("![Contribution calendar](<report-Personal Projects-2024-heatmap.svg>)"));
+        assert!(content.contains("- Total Contributions: 8"));
+        assert!(content.contains("- Longest Streak: 3 days"));
+        assert!(content.contains("- Current Streak: 0 days"));
+        assert!(content.contains("- Most Active Day: 2024-04-02 (5 contributions)"));
+
+        let svg = std::fs::read_to_string(
+            temp_dir
+                .path()
+                .join("report-Personal Projects-2024-heatmap.svg"),
+        )
+        .expect("Failed to read heatmap file");
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<rect").count(), 365);
+    }
+
+    #[test]
+    fn outputs_working_hours_distribution() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::commit_time_distribution::CommitTimeDistribution;
+        use crate::domain::value_objects::working_hours::WorkingHours;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_working_hours.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        // 10:00 and 22:00 on Wednesday 2024-05-01 in Tokyo
+        let commit = |hour: u32| {
+            Commit::new(
+                "abc123".to_string(),
+                "feat: add export".to_string(),
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 1, hour, 0, 0).unwrap(),
+                "connect0459/nenpo".to_string(),
+            )
+        };
+        let commits = vec![commit(1), commit(13)];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(2, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_commit_time_distribution(CommitTimeDistribution::from_commits(
+            &commits,
+            chrono_tz::Asia::Tokyo,
+            WorkingHours::new(10, 19).expect("Valid working hours"),
+        ));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Working Hours"));
+        assert!(content.contains("- Timezone: Asia/Tokyo"));
+        assert!(content.contains("- Working Hours: 10:00-19:00, Mon-Fri"));
+        assert!(content.contains("- Outside Working Hours: 50.0% (1 of 2 commits)"));
+        assert!(content.contains("| 10:00 | 1 |"));
+        assert!(content.contains("| 22:00 | 1 |"));
+        assert!(content.contains("| Wed | 2 |"));
+    }
+
+    #[test]
+    fn outputs_activity_breakdowns() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::activity_breakdown::ActivityBreakdown;
+        use crate::domain::value_objects::commit_classifier::CommitClassifier;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_breakdowns.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commit = |message: &str, repository: &str, additions: u32, deletions: u32| {
+            Commit::new(
+                "abc123".to_string(),
+                message.to_string(),
+                "connect0459".to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+                repository.to_string(),
+            )
+            .with_changes(additions, deletions)
+        };
+        let commits = vec![
+            commit("feat: add export", "connect0459/nenpo", 100, 10),
+            commit("fix: handle timeout", "connect0459/nenpo", 5, 5),
+            commit("docs: update README", "org/docs", 20, 0),
+        ];
+        let classifier = CommitClassifier::default();
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(3, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_breakdowns(
+            ActivityBreakdown::by_organization(&commits, &[], &classifier),
+            ActivityBreakdown::by_repository(&commits, &[], &classifier),
+        );
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("### Activity by Organization"));
+        assert!(content.contains("| connect0459 | 2 | 0 | +105 / -15 | feat: 1, fix: 1 |"));
+        assert!(content.contains("### Activity by Repository"));
+        assert!(content.contains("| connect0459/nenpo | 2 | 0 | +105 / -15 | feat: 1, fix: 1 |"));
+        assert!(content.contains("| org/docs | 1 | 0 | +20 / -0 | docs: 1 |"));
+    }
+
+    #[test]
+    fn outputs_breaking_changes_and_scopes() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::conventional_commit::{BreakingChange, ScopeCount};
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_breaking.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commits = vec![Commit::new(
+            "abcdef1234567".to_string(),
+            "feat(api)!: drop v1\n\nBREAKING CHANGE: v1 endpoints\nare removed".to_string(),
+            "connect0459".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+            "connect0459/nenpo".to_string(),
+        )];
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(1, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_breaking_changes(BreakingChange::from_commits(&commits))
+        .with_scope_summary(ScopeCount::from_commits(&commits));
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("#### Commit Scopes"));
+        assert!(content.contains("- api: 1"));
+        assert!(content.contains("#### Breaking Changes"));
+        assert!(content
+            .contains("- 2024-05-01 connect0459/nenpo (api) `abcdef1`: v1 endpoints are removed"));
+    }
+
+    #[test]
+    fn outputs_excluded_commit_counts() {
+        use crate::domain::entities::commit::Commit;
+        use crate::domain::value_objects::commit_filter::CommitFilter;
+        use crate::domain::value_objects::repository_mirror::RepositoryMirror;
+        use chrono::{TimeZone, Utc};
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let output_path = temp_dir.path().join("test_report_excluded.md");
+
+        let from = NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date");
+        let to = NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date");
+        let commits: Vec<Commit> = [
+            ("renovate[bot]", 1),
+            ("dependabot[bot]", 1),
+            ("github-actions[bot]", 1),
+            ("connect0459", 2),
+            ("connect0459", 2),
+        ]
+        .iter()
+        .map(|(login, parents)| {
+            Commit::new(
+                "abc123".to_string(),
+                "chore: update".to_string(),
+                login.to_string(),
+                Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap(),
+                "connect0459/nenpo".to_string(),
+            )
+            .with_author_login(Some(login.to_string()))
+            .with_parent_count(*parents)
+        })
+        .collect();
+        let (_, excluded) = CommitFilter::new(true, vec![], true)
+            .expect("Failed to create filter")
+            .apply(commits);
+
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            from,
+            to,
+            GitHubActivity::new(5, 0, 0, 0),
+            vec![],
+            HashMap::new(),
+        )
+        .with_excluded_commits(excluded)
+        .with_duplicate_commits(4)
+        .with_repository_mirrors(vec![RepositoryMirror::new(
+            "test-org/app".to_string(),
+            vec!["connect0459/app".to_string(), "mirror/app".to_string()],
+            4,
+        )]);
+
+        MarkdownOutputRepository::new()
+            .output(&report, &output_path)
+            .expect("Failed to output report");
+
+        let content = std::fs::read_to_string(&output_path).expect("Failed to read output file");
+        assert!(content.contains("#### Excluded Commits"));
+        assert!(content.contains("- Bot Commits: 3"));
+        assert!(content.contains("- Merge Commits: 2"));
+        assert!(content.contains("- Duplicate Commits: 4"));
+        assert!(content.contains("  - test-org/app (also in connect0459/app, mirror/app): 4"));
+    }
+
     #[test]
     fn does_not_show_local_documents_section_when_no_documents() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -1,3 +1,5 @@
+pub mod heatmap_svg;
 pub mod html_output_repository;
 pub mod json_output_repository;
 pub mod markdown_output_repository;
+pub mod trend_chart_svg;
@@ -0,0 +1,148 @@
+use crate::domain::entities::trend_report::{TrendReport, TrendYear};
+
+/// Width of the chart in pixels
+const WIDTH: u32 = 600;
+/// Height of the chart in pixels
+const HEIGHT: u32 = 240;
+/// Space left of the plot for the axis label in pixels
+const LEFT_MARGIN: u32 = 40;
+/// Space right of the plot in pixels
+const RIGHT_MARGIN: u32 = 20;
+/// Space above the plot for the legend in pixels
+const TOP_MARGIN: u32 = 25;
+/// Space below the plot for year labels in pixels
+const BOTTOM_MARGIN: u32 = 25;
+/// A plotted series: its label, color and value per year
+type Series = (&'static str, &'static str, fn(&TrendYear) -> u32);
+/// Plotted series with their colors
+const SERIES: [Series; 3] = [
+    ("Commits", "#007bff", TrendYear::commits),
+    ("Pull Requests", "#28a745", TrendYear::pull_requests),
+    ("Reviews", "#fd7e14", TrendYear::reviews),
+];
+
+/// Renders commits, pull requests and reviews per fiscal year as a line chart
+pub fn render_trend_chart_svg(trend: &TrendReport) -> String {
+    let years = trend.years();
+    if years.is_empty() {
+        return String::new();
+    }
+    let max = years
+        .iter()
+        .flat_map(|year| SERIES.iter().map(move |(_, _, value)| value(year)))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let plot_width = WIDTH - LEFT_MARGIN - RIGHT_MARGIN;
+    let plot_height = HEIGHT - TOP_MARGIN - BOTTOM_MARGIN;
+    let bottom = TOP_MARGIN + plot_height;
+    // A single year is drawn in the middle of the plot
+    let x = |index: usize| match years.len() {
+        1 => LEFT_MARGIN + plot_width / 2,
+        len => LEFT_MARGIN + plot_width * index as u32 / (len as u32 - 1),
+    };
+    let y = |value: u32| bottom - plot_height * value / max;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" font-family=\"sans-serif\" font-size=\"10\">\n",
+        WIDTH, HEIGHT, WIDTH, HEIGHT
+    );
+    svg.push_str(&format!(
+        "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#ccc\"/>\n",
+        LEFT_MARGIN,
+        bottom,
+        WIDTH - RIGHT_MARGIN,
+        bottom
+    ));
+    svg.push_str(&format!(
+        "  <text x=\"{}\" y=\"{}\" text-anchor=\"end\" fill=\"#767676\">{}</text>\n",
+        LEFT_MARGIN - 5,
+        TOP_MARGIN + 4,
+        max
+    ));
+    for (index, year) in years.iter().enumerate() {
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" fill=\"#767676\">FY{}</text>\n",
+            x(index),
+            HEIGHT - 8,
+            year.year()
+        ));
+    }
+    for (series, (label, color, value)) in SERIES.iter().enumerate() {
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"12\" fill=\"{}\">{}</text>\n",
+            LEFT_MARGIN + series as u32 * 110,
+            color,
+            label
+        ));
+        let points: Vec<String> = years
+            .iter()
+            .enumerate()
+            .map(|(index, year)| format!("{},{}", x(index), y(value(year))))
+            .collect();
+        svg.push_str(&format!(
+            "  <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>\n",
+            points.join(" "),
+            color
+        ));
+        for (index, year) in years.iter().enumerate() {
+            svg.push_str(&format!(
+                "  <circle cx=\"{}\" cy=\"{}\" r=\"3\" fill=\"{}\"><title>FY{} {}: {}</title></circle>\n",
+                x(index),
+                y(value(year)),
+                color,
+                year.year(),
+                label,
+                value(year)
+            ));
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::entities::report::Report;
+    use crate::domain::value_objects::commit_theme::CommitTheme;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+
+    fn report(year: u32, commits: u32, pull_requests: u32) -> Report {
+        Report::new(
+            year,
+            "Personal".to_string(),
+            NaiveDate::from_ymd_opt(year as i32, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(year as i32, 12, 31).unwrap(),
+            GitHubActivity::new(0, pull_requests, 0, 0),
+            vec![],
+            HashMap::from([(CommitTheme::Feat, commits)]),
+        )
+    }
+
+    #[test]
+    fn plots_one_point_per_year_for_each_series() {
+        let trend = TrendReport::from_reports(
+            "Personal".to_string(),
+            &[report(2023, 10, 5), report(2024, 20, 0)],
+        );
+
+        let svg = render_trend_chart_svg(&trend);
+
+        assert_eq!(svg.matches("<polyline").count(), 3);
+        assert_eq!(svg.matches("<circle").count(), 6);
+        // Commits rise from half of the plot height to the top
+        assert!(svg.contains("<polyline points=\"40,120 580,25\" fill=\"none\" stroke=\"#007bff\""));
+        assert!(svg.contains("<title>FY2024 Commits: 20</title>"));
+        assert!(svg.contains(">FY2023</text>"));
+    }
+
+    #[test]
+    fn renders_nothing_for_empty_trend() {
+        let trend = TrendReport::from_reports("Personal".to_string(), &[]);
+
+        assert!(render_trend_chart_svg(&trend).is_empty());
+    }
+}
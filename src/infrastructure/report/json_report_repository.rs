@@ -0,0 +1,114 @@
+use crate::domain::entities::report::Report;
+use crate::domain::repositories::report_repository::ReportRepository;
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Repository for loading reports written by the JSON output
+pub struct JsonReportRepository;
+
+impl JsonReportRepository {
+    /// Creates a new JsonReportRepository instance
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ReportRepository for JsonReportRepository {
+    fn load(&self, path: &Path) -> Result<Report> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read report file: {}", path.display()))?;
+        let report: Report = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse JSON report: {}", path.display()))?;
+        Ok(report.restore_custom_themes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::repositories::output_repository::OutputRepository;
+    use crate::domain::value_objects::commit_theme::CommitTheme;
+    use crate::infrastructure::output::json_output_repository::JsonOutputRepository;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    #[test]
+    fn loads_report_written_by_json_output() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("report.json");
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date"),
+            NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date"),
+            GitHubActivity::new(100, 20, 15, 30),
+            vec![],
+            HashMap::from([(CommitTheme::Feat, 3)]),
+        );
+        JsonOutputRepository::new()
+            .output(&report, &path)
+            .expect("Failed to output report");
+
+        let loaded = JsonReportRepository::new()
+            .load(&path)
+            .expect("Failed to load report");
+
+        assert_eq!(loaded.department_name(), "個人");
+        assert_eq!(loaded.period_label(), "FY2024");
+        assert_eq!(loaded.github_activity().reviews(), 30);
+        assert_eq!(loaded.theme_summary().get(&CommitTheme::Feat), Some(&3));
+    }
+
+    #[test]
+    fn loads_custom_categories_with_their_names_and_order() {
+        use crate::domain::value_objects::commit_theme::CustomTheme;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("report.json");
+        let custom = |key: &str, name: &str, order| {
+            CommitTheme::Custom(CustomTheme::new(key.to_string(), name.to_string(), order))
+        };
+        let report = Report::new(
+            2024,
+            "個人".to_string(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).expect("Invalid date"),
+            NaiveDate::from_ymd_opt(2025, 3, 31).expect("Invalid date"),
+            GitHubActivity::new(0, 0, 0, 0),
+            vec![],
+            HashMap::from([
+                (custom("zeta", "Customer Support", 0), 1),
+                (custom("alpha", "Ticket Work", 1), 2),
+            ]),
+        );
+        JsonOutputRepository::new()
+            .output(&report, &path)
+            .expect("Failed to output report");
+
+        let loaded = JsonReportRepository::new()
+            .load(&path)
+            .expect("Failed to load report");
+
+        let mut themes: Vec<_> = loaded.theme_summary().keys().collect();
+        themes.sort_by_key(|theme| theme.sort_order());
+        let labels: Vec<_> = themes.iter().map(|theme| theme.label()).collect();
+        assert_eq!(labels, vec!["Customer Support", "Ticket Work"]);
+    }
+
+    #[test]
+    fn returns_error_for_missing_or_invalid_file() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repository = JsonReportRepository::new();
+
+        let missing = repository
+            .load(&temp_dir.path().join("missing.json"))
+            .expect_err("Missing file should fail");
+        assert!(missing.to_string().contains("Failed to read report file"));
+
+        let path = temp_dir.path().join("report.md");
+        std::fs::write(&path, "# Annual Report").expect("Failed to write file");
+        let invalid = repository.load(&path).expect_err("Markdown should fail");
+        assert!(invalid.to_string().contains("Failed to parse JSON report"));
+    }
+}
@@ -3,10 +3,15 @@ mod domain;
 mod infrastructure;
 mod presentation;
 
+use application::services::report_differ::ReportDiffer;
 use application::services::report_generator::ReportGenerator;
 use clap::Parser;
+use domain::repositories::output_repository::OutputRepository;
 use domain::services::progress_reporter::StdoutProgressReporter;
+use domain::value_objects::diff_format::DiffFormat;
+use domain::value_objects::fiscal_year_selector::FiscalYearSelector;
 use domain::value_objects::output_format::OutputFormat;
+use domain::value_objects::period_kind::PeriodKind;
 use infrastructure::cache::FileCache;
 use infrastructure::config::toml_config_repository::TomlConfigRepository;
 use infrastructure::document::local_file_document_repository::LocalFileDocumentRepository;
@@ -14,7 +19,9 @@ use infrastructure::github::{GhCommandExecutor, GhCommandRepository};
 use infrastructure::output::html_output_repository::HtmlOutputRepository;
 use infrastructure::output::json_output_repository::JsonOutputRepository;
 use infrastructure::output::markdown_output_repository::MarkdownOutputRepository;
+use infrastructure::report::json_report_repository::JsonReportRepository;
 use presentation::cli::{Cli, Commands};
+use presentation::diff_view;
 use std::path::Path;
 use std::process;
 
@@ -25,14 +32,28 @@ fn main() {
         Commands::Generate {
             config,
             year,
+            period,
+            since,
+            until,
+            compare,
             department,
             format,
+            verbose,
         } => {
             println!("Generating annual report...");
             println!("  Config: {}", config);
-            if let Some(y) = year {
+            if let Some(y) = &year {
                 println!("  Year: {}", y);
             }
+            if let Some(p) = &period {
+                println!("  Period: {}", p);
+            }
+            if let (Some(s), Some(u)) = (since, until) {
+                println!("  Period: {} to {}", s, u);
+            }
+            if compare {
+                println!("  Compare: previous fiscal year");
+            }
             if let Some(d) = &department {
                 println!("  Department: {}", d);
             }
@@ -41,92 +62,217 @@ fn main() {
             }
             println!();
 
-            // Parse output format
-            let output_format = format
+            // Parse target fiscal year
+            let year = year
                 .as_deref()
-                .and_then(|f| OutputFormat::from_str(f).ok())
-                .unwrap_or(OutputFormat::Markdown);
-
-            // Determine output directory
-            let output_dir = Path::new("./reports");
-            if !output_dir.exists() {
-                if let Err(e) = std::fs::create_dir_all(output_dir) {
-                    eprintln!("Error: Failed to create output directory: {}", e);
+                .map_or(
+                    Ok(FiscalYearSelector::Current),
+                    FiscalYearSelector::from_str,
+                )
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
                     process::exit(1);
-                }
-            }
+                });
 
-            // Create shared repository instances
-            let config_repo = TomlConfigRepository::new();
-            let cache = FileCache::new().unwrap_or_else(|e| {
-                eprintln!(
-                    "Warning: Failed to create cache: {}. Proceeding without cache.",
-                    e
-                );
-                std::process::exit(1);
+            // Parse reporting period
+            let period_kind = match (since, until) {
+                (Some(since), Some(until)) => PeriodKind::custom(since, until),
+                _ => period
+                    .as_deref()
+                    .map_or(Ok(PeriodKind::FiscalYear), PeriodKind::from_str),
+            };
+            let period_kind = period_kind.unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                process::exit(1);
             });
-            let github_repo = GhCommandRepository::new(
-                GhCommandExecutor::new(),
-                StdoutProgressReporter::new(),
-                cache,
-            );
-            let document_repo = LocalFileDocumentRepository::new();
+
+            let output_format = parse_output_format(format.as_deref());
+            let output_dir = prepare_output_dir();
 
             // Generate reports based on format
             let result = match output_format {
-                OutputFormat::Markdown => {
-                    let output_repo = MarkdownOutputRepository::new();
-                    let generator =
-                        ReportGenerator::new(config_repo, github_repo, document_repo, output_repo);
-                    generator.generate(
+                OutputFormat::Markdown => build_generator(MarkdownOutputRepository::new(), verbose)
+                    .with_comparison(compare)
+                    .generate(
                         Path::new(&config),
                         year,
+                        period_kind,
                         department.as_deref(),
                         output_dir,
                         "md",
-                    )
-                }
-                OutputFormat::Json => {
-                    let output_repo = JsonOutputRepository::new();
-                    let generator =
-                        ReportGenerator::new(config_repo, github_repo, document_repo, output_repo);
-                    generator.generate(
+                    ),
+                OutputFormat::Json => build_generator(JsonOutputRepository::new(), verbose)
+                    .with_comparison(compare)
+                    .generate(
                         Path::new(&config),
                         year,
+                        period_kind,
                         department.as_deref(),
                         output_dir,
                         "json",
-                    )
-                }
-                OutputFormat::Html => {
-                    let output_repo = HtmlOutputRepository::new();
-                    let generator =
-                        ReportGenerator::new(config_repo, github_repo, document_repo, output_repo);
-                    generator.generate(
+                    ),
+                OutputFormat::Html => build_generator(HtmlOutputRepository::new(), verbose)
+                    .with_comparison(compare)
+                    .generate(
                         Path::new(&config),
                         year,
+                        period_kind,
                         department.as_deref(),
                         output_dir,
                         "html",
-                    )
-                }
+                    ),
+            };
+
+            print_generated_files(result, output_dir);
+        }
+        Commands::Trend {
+            config,
+            from_year,
+            to_year,
+            department,
+            format,
+            verbose,
+        } => {
+            println!("Generating trend report...");
+            println!("  Config: {}", config);
+            println!("  Years: {} to {}", from_year, to_year);
+            if let Some(d) = &department {
+                println!("  Department: {}", d);
+            }
+            if let Some(f) = &format {
+                println!("  Format: {}", f);
+            }
+            println!();
+
+            let output_format = parse_output_format(format.as_deref());
+            let output_dir = prepare_output_dir();
+
+            // Generate trend reports based on format
+            let result = match output_format {
+                OutputFormat::Markdown => build_generator(MarkdownOutputRepository::new(), verbose)
+                    .generate_trend(
+                        Path::new(&config),
+                        from_year,
+                        to_year,
+                        department.as_deref(),
+                        output_dir,
+                        "md",
+                    ),
+                OutputFormat::Json => build_generator(JsonOutputRepository::new(), verbose)
+                    .generate_trend(
+                        Path::new(&config),
+                        from_year,
+                        to_year,
+                        department.as_deref(),
+                        output_dir,
+                        "json",
+                    ),
+                OutputFormat::Html => build_generator(HtmlOutputRepository::new(), verbose)
+                    .generate_trend(
+                        Path::new(&config),
+                        from_year,
+                        to_year,
+                        department.as_deref(),
+                        output_dir,
+                        "html",
+                    ),
             };
 
+            print_generated_files(result, output_dir);
+        }
+        Commands::Diff {
+            left,
+            right,
+            format,
+        } => {
+            let diff_format = format
+                .as_deref()
+                .map_or(Ok(DiffFormat::Terminal), DiffFormat::from_str)
+                .unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
+                    process::exit(1);
+                });
+
+            // Only the two JSON files are read; nothing is fetched from GitHub
+            let result = ReportDiffer::new(JsonReportRepository::new())
+                .diff(Path::new(&left), Path::new(&right))
+                .and_then(|diff| diff_view::render(&diff, diff_format));
             match result {
-                Ok(files) => {
-                    println!(
-                        "[SUCCESS] Successfully generated {} report(s):",
-                        files.len()
-                    );
-                    for file in files {
-                        println!("   - {}/{}", output_dir.display(), file);
-                    }
-                }
+                Ok(output) => print!("{}", output),
                 Err(e) => {
-                    eprintln!("Error: Failed to generate report: {}", e);
+                    eprintln!("Error: Failed to diff reports: {:#}", e);
                     process::exit(1);
                 }
             }
         }
     }
 }
+
+/// Report generator wired to the TOML configuration, the gh CLI and local documents
+type Generator<O> = ReportGenerator<
+    TomlConfigRepository,
+    GhCommandRepository<GhCommandExecutor, StdoutProgressReporter, FileCache>,
+    LocalFileDocumentRepository,
+    O,
+>;
+
+/// Parses the output format, falling back to Markdown
+fn parse_output_format(format: Option<&str>) -> OutputFormat {
+    format
+        .and_then(|f| OutputFormat::from_str(f).ok())
+        .unwrap_or(OutputFormat::Markdown)
+}
+
+/// Returns the output directory, creating it if needed
+fn prepare_output_dir() -> &'static Path {
+    let output_dir = Path::new("./reports");
+    if !output_dir.exists() {
+        if let Err(e) = std::fs::create_dir_all(output_dir) {
+            eprintln!("Error: Failed to create output directory: {}", e);
+            process::exit(1);
+        }
+    }
+    output_dir
+}
+
+/// Creates a report generator writing through the given output repository
+fn build_generator<O: OutputRepository>(output_repo: O, verbose: bool) -> Generator<O> {
+    let cache = FileCache::new().unwrap_or_else(|e| {
+        eprintln!(
+            "Warning: Failed to create cache: {}. Proceeding without cache.",
+            e
+        );
+        std::process::exit(1);
+    });
+    let github_repo = GhCommandRepository::new(
+        GhCommandExecutor::new(),
+        StdoutProgressReporter::new().with_verbose(verbose),
+        cache,
+    );
+    ReportGenerator::new(
+        TomlConfigRepository::new(),
+        github_repo,
+        LocalFileDocumentRepository::new(),
+        output_repo,
+    )
+    .with_progress_reporter(StdoutProgressReporter::new())
+}
+
+/// Prints the generated files, or the error and exits
+fn print_generated_files(result: anyhow::Result<Vec<String>>, output_dir: &Path) {
+    match result {
+        Ok(files) => {
+            println!(
+                "[SUCCESS] Successfully generated {} report(s):",
+                files.len()
+            );
+            for file in files {
+                println!("   - {}/{}", output_dir.display(), file);
+            }
+        }
+        Err(e) => {
+            eprintln!("Error: Failed to generate report: {}", e);
+            process::exit(1);
+        }
+    }
+}
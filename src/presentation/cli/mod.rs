@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
@@ -16,9 +17,51 @@ pub enum Commands {
         #[arg(long, default_value = "./nenpou.toml")]
         config: String,
 
-        /// Target year
+        /// Target fiscal year: a year such as 2024, current or last (default: current)
         #[arg(long)]
-        year: Option<u32>,
+        year: Option<String>,
+
+        /// Part of the fiscal year to report on (year, Q1-Q4, H1, H2, month=YYYY-MM or last-quarter)
+        #[arg(long, conflicts_with_all = ["since", "until"])]
+        period: Option<String>,
+
+        /// First day of a custom period (YYYY-MM-DD)
+        #[arg(long, requires = "until")]
+        since: Option<NaiveDate>,
+
+        /// Last day of a custom period (YYYY-MM-DD)
+        #[arg(long, requires = "since")]
+        until: Option<NaiveDate>,
+
+        /// Compare with the same period of the previous fiscal year
+        #[arg(long)]
+        compare: bool,
+
+        /// Specific department name
+        #[arg(long)]
+        department: Option<String>,
+
+        /// Output format (markdown, json, html)
+        #[arg(long)]
+        format: Option<String>,
+
+        /// Show details such as repositories skipped by repository filters
+        #[arg(long)]
+        verbose: bool,
+    },
+    /// Generate a year-by-year trend report across fiscal years
+    Trend {
+        /// Configuration file path
+        #[arg(long, default_value = "./nenpou.toml")]
+        config: String,
+
+        /// First fiscal year of the trend
+        #[arg(long)]
+        from_year: u32,
+
+        /// Last fiscal year of the trend
+        #[arg(long)]
+        to_year: u32,
 
         /// Specific department name
         #[arg(long)]
@@ -27,5 +70,21 @@ pub enum Commands {
         /// Output format (markdown, json, html)
         #[arg(long)]
         format: Option<String>,
+
+        /// Show details such as repositories skipped by repository filters
+        #[arg(long)]
+        verbose: bool,
+    },
+    /// Compare two previously generated JSON reports without fetching from GitHub
+    Diff {
+        /// JSON report shown on the left (the baseline)
+        left: String,
+
+        /// JSON report shown on the right, compared against the left
+        right: String,
+
+        /// Output format (terminal, markdown, json)
+        #[arg(long)]
+        format: Option<String>,
     },
 }
@@ -0,0 +1,181 @@
+use crate::domain::value_objects::comparison::Delta;
+use crate::domain::value_objects::diff_format::DiffFormat;
+use crate::domain::value_objects::report_diff::{DiffSide, ReportDiff};
+use anyhow::Result;
+
+/// Renders a report diff for printing
+pub fn render(diff: &ReportDiff, format: DiffFormat) -> Result<String> {
+    match format {
+        DiffFormat::Terminal => Ok(render_terminal(diff)),
+        DiffFormat::Markdown => Ok(render_markdown(diff)),
+        DiffFormat::Json => Ok(serde_json::to_string_pretty(diff)?),
+    }
+}
+
+/// Describes one side of the diff (e.g., "a.json: Personal, FY2023 (2023-04-01 to 2024-03-31)")
+fn describe(side: &DiffSide) -> String {
+    format!(
+        "{}: {}, {} ({} to {})",
+        side.source(),
+        side.department_name(),
+        side.period_label(),
+        side.period_from(),
+        side.period_to()
+    )
+}
+
+/// Renders the diff as aligned columns, with the left and right values side by side
+fn render_terminal(diff: &ReportDiff) -> String {
+    let mut content = format!(
+        "Left:  {}\nRight: {}\n",
+        describe(diff.left()),
+        describe(diff.right())
+    );
+
+    let width = diff
+        .metrics()
+        .iter()
+        .chain(diff.themes())
+        .map(|delta| delta.label().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Metric".len());
+    for (column, deltas) in [("Metric", diff.metrics()), ("Theme", diff.themes())] {
+        if deltas.is_empty() {
+            continue;
+        }
+        content.push_str(&format!(
+            "\n{:<width$}  {:>8}  {:>8}  Change\n",
+            column,
+            "Left",
+            "Right",
+            width = width
+        ));
+        for delta in deltas {
+            content.push_str(&format!(
+                "{:<width$}  {:>8}  {:>8}  {}\n",
+                delta.label(),
+                delta.previous(),
+                delta.current(),
+                delta.format_change(),
+                width = width
+            ));
+        }
+    }
+    content
+}
+
+/// Renders the diff as Markdown tables
+fn render_markdown(diff: &ReportDiff) -> String {
+    let mut content = format!(
+        "# Report Diff\n\n- Left: {}\n- Right: {}\n",
+        describe(diff.left()),
+        describe(diff.right())
+    );
+    for (heading, column, deltas) in [
+        ("Activity", "Metric", diff.metrics()),
+        ("Commit Themes", "Theme", diff.themes()),
+    ] {
+        if deltas.is_empty() {
+            continue;
+        }
+        content.push_str(&format!(
+            "\n## {}\n\n| {} | Left | Right | Change |\n| :--- | ---: | ---: | ---: |\n",
+            heading, column
+        ));
+        content.push_str(&markdown_rows(deltas));
+    }
+    content
+}
+
+/// Renders one Markdown table row per delta
+fn markdown_rows(deltas: &[Delta]) -> String {
+    deltas
+        .iter()
+        .map(|delta| {
+            format!(
+                "| {} | {} | {} | {} |\n",
+                delta.label(),
+                delta.previous(),
+                delta.current(),
+                delta.format_change()
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::github_activity::GitHubActivity;
+    use crate::domain::entities::report::Report;
+    use crate::domain::value_objects::commit_theme::CommitTheme;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+
+    fn diff() -> ReportDiff {
+        let report = |department: &str, activity, themes: Vec<(CommitTheme, u32)>| {
+            Report::new(
+                2024,
+                department.to_string(),
+                NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+                activity,
+                vec![],
+                themes.into_iter().collect::<HashMap<_, _>>(),
+            )
+        };
+        let left = report(
+            "Team A",
+            GitHubActivity::new(100, 10, 0, 4),
+            vec![(CommitTheme::Feat, 1), (CommitTheme::Fix, 1)],
+        );
+        let right = report(
+            "Team B",
+            GitHubActivity::new(120, 10, 2, 3),
+            vec![(CommitTheme::Feat, 2)],
+        );
+        ReportDiff::between(
+            DiffSide::new("a.json".to_string(), &left),
+            &left,
+            DiffSide::new("b.json".to_string(), &right),
+            &right,
+        )
+    }
+
+    #[test]
+    fn renders_aligned_columns_for_terminal() {
+        let output = render(&diff(), DiffFormat::Terminal).expect("Failed to render diff");
+
+        assert!(output.contains("Left:  a.json: Team A, FY2024 (2024-04-01 to 2025-03-31)"));
+        assert!(output.contains("Right: b.json: Team B, FY2024 (2024-04-01 to 2025-03-31)"));
+        assert!(output.contains("Metric                      Left     Right  Change"));
+        assert!(output.contains("Total Commits                100       120  ▲ +20 (+20.0%)"));
+        assert!(output.contains("Total Issues                   0         2  ▲ +2 (n/a)"));
+        assert!(output.contains("fix                            1         0  ▼ -1 (-100.0%)"));
+    }
+
+    #[test]
+    fn renders_markdown_tables() {
+        let output = render(&diff(), DiffFormat::Markdown).expect("Failed to render diff");
+
+        assert!(output.starts_with("# Report Diff\n"));
+        assert!(output.contains("- Left: a.json: Team A, FY2024 (2024-04-01 to 2025-03-31)"));
+        assert!(output.contains("## Activity"));
+        assert!(output.contains("| Total Reviews | 4 | 3 | ▼ -1 (-25.0%) |"));
+        assert!(output.contains("## Commit Themes"));
+        assert!(output.contains("| feat | 1 | 2 | ▲ +1 (+100.0%) |"));
+    }
+
+    #[test]
+    fn renders_json() {
+        let output = render(&diff(), DiffFormat::Json).expect("Failed to render diff");
+
+        let json: serde_json::Value = serde_json::from_str(&output).expect("Invalid JSON");
+        assert_eq!(json["left"]["source"], "a.json");
+        assert_eq!(json["right"]["department_name"], "Team B");
+        assert_eq!(json["metrics"][0]["label"], "Total Commits");
+        assert_eq!(json["metrics"][0]["change"], 20);
+        assert_eq!(json["themes"][1]["label"], "fix");
+    }
+}